@@ -0,0 +1,464 @@
+//! CLI 引数定義
+//!
+//! サブコマンドは Tauri コマンドと 1 対 1 で対応する。
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
+
+/// GitLab 月次コミット行数分析（ヘッドレス CLI）
+#[derive(Debug, Parser)]
+#[command(name = "gitlab-analyzer-cli", version)]
+pub struct Cli {
+    /// 出力形式
+    #[arg(long, value_enum, global = true, default_value_t = OutputFormat::Json)]
+    pub format: OutputFormat,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+/// 出力形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// JSON（整形済み）
+    Json,
+    /// CSV（ヘッダー付き）
+    Csv,
+}
+
+/// サブコマンド
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// GitLab からプロジェクト一覧を同期する
+    SyncProjects,
+
+    /// 指定プロジェクト/ブランチのコミットを収集する
+    Collect(CollectArgs),
+
+    /// 収集履歴のある全対象を一括収集する（中断された実行があれば再開する）
+    CollectBulk(CollectBulkArgs),
+
+    /// stats が欠損したコミットを GitLab から個別に再取得して行数を補完する
+    RepairStats(RepairStatsArgs),
+
+    /// ローカルの git リポジトリからコミットを取り込む（オフラインで実行可能）
+    ImportLocal(ImportLocalArgs),
+
+    /// 月次集計を表示する
+    Stats {
+        #[command(subcommand)]
+        view: StatsView,
+    },
+
+    /// 月次集計（と集計元コミット）をファイルに書き出す
+    Export(ExportArgs),
+
+    /// 収集済みのデータ一式をアーカイブファイルに書き出す（アクセストークンは含めない）
+    ExportArchive(ExportArchiveArgs),
+
+    /// アーカイブファイルを取り込む
+    ImportArchive(ImportArchiveArgs),
+
+    /// データベースをバックアップする（SQLite のオンラインバックアップ）
+    Backup(BackupArgs),
+
+    /// バックアップと自動スナップショットの一覧を表示する
+    Backups,
+
+    /// バックアップからデータベースを復元する
+    Restore(RestoreArgs),
+
+    /// データベースの健全性（サイズ・テーブルごとの行数・整合性・最終 VACUUM）を表示する
+    Health,
+
+    /// データベースを最適化する（VACUUM と ANALYZE）
+    Optimize(OptimizeArgs),
+
+    /// 保持期間を過ぎたコミットを削除する
+    Purge(PurgeArgs),
+}
+
+/// コミット収集の引数
+#[derive(Debug, Args)]
+pub struct CollectArgs {
+    /// プロジェクト ID
+    #[arg(long)]
+    pub project_id: i64,
+
+    /// ブランチ名
+    #[arg(long)]
+    pub branch: String,
+
+    /// 開始日時（ISO8601）
+    #[arg(long)]
+    pub since: Option<String>,
+
+    /// 終了日時（ISO8601）
+    #[arg(long)]
+    pub until: Option<String>,
+}
+
+/// 一括収集の引数
+#[derive(Debug, Args)]
+pub struct CollectBulkArgs {
+    /// 収集後に stats 欠損コミットを再取得する
+    #[arg(long)]
+    pub repair_missing_stats: bool,
+}
+
+/// stats 欠損コミット再取得の引数
+#[derive(Debug, Args)]
+pub struct RepairStatsArgs {
+    /// 対象プロジェクト ID（省略時は全プロジェクト）
+    #[arg(long)]
+    pub project_id: Option<i64>,
+
+    /// 再取得するコミット数の上限（新しい順）
+    #[arg(long)]
+    pub limit: Option<usize>,
+
+    /// 通信失敗・レート制限・サーバーエラー時の再試行回数
+    #[arg(long, default_value_t = 3)]
+    pub max_retries: u32,
+}
+
+/// ローカルリポジトリ取り込みの引数
+#[derive(Debug, Args)]
+pub struct ImportLocalArgs {
+    /// リポジトリのパス
+    pub path: std::path::PathBuf,
+
+    /// 取り込む ref（省略時は HEAD のブランチ）
+    #[arg(long = "ref")]
+    pub ref_name: Option<String>,
+
+    /// プロジェクト名（省略時はディレクトリ名、local/<名前> として保存）
+    #[arg(long)]
+    pub name: Option<String>,
+
+    /// 開始日時（ISO8601）
+    #[arg(long)]
+    pub since: Option<String>,
+
+    /// 終了日時（ISO8601）
+    #[arg(long)]
+    pub until: Option<String>,
+}
+
+/// アーカイブ書き出しの引数
+#[derive(Debug, Args)]
+pub struct ExportArchiveArgs {
+    /// 出力先ファイルパス
+    #[arg(long, short)]
+    pub output: std::path::PathBuf,
+}
+
+/// アーカイブ取り込みの引数
+#[derive(Debug, Args)]
+pub struct ImportArchiveArgs {
+    /// アーカイブファイルのパス
+    pub path: std::path::PathBuf,
+
+    /// 取り込み方法（merge: 既存を残して追加 / replace: アーカイブの内容で置き換え）
+    #[arg(long, value_enum, default_value_t = ArchiveModeArg::Merge)]
+    pub mode: ArchiveModeArg,
+}
+
+/// バックアップの引数
+#[derive(Debug, Args)]
+pub struct BackupArgs {
+    /// 出力先ファイルパス（省略時はアプリデータディレクトリの backups に作成）
+    #[arg(long, short)]
+    pub output: Option<std::path::PathBuf>,
+}
+
+/// 復元の引数
+#[derive(Debug, Args)]
+pub struct RestoreArgs {
+    /// 復元元のバックアップファイルのパス
+    pub path: std::path::PathBuf,
+}
+
+/// 最適化の引数
+#[derive(Debug, Args)]
+pub struct OptimizeArgs {
+    /// VACUUM を実行しない
+    #[arg(long)]
+    pub no_vacuum: bool,
+
+    /// ANALYZE を実行しない
+    #[arg(long)]
+    pub no_analyze: bool,
+}
+
+/// コミット削除の引数
+#[derive(Debug, Args)]
+pub struct PurgeArgs {
+    /// 保持日数（これより前のコミットを削除）
+    #[arg(long)]
+    pub keep_days: i32,
+
+    /// 削除せずに対象件数のみ表示する
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+/// アーカイブの取り込み方法
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ArchiveModeArg {
+    /// 既存データを残し、アーカイブにしかない行を追加する
+    Merge,
+    /// アーカイブに含まれるデータで置き換える
+    Replace,
+}
+
+/// 集計ビュー
+#[derive(Debug, Clone, Subcommand)]
+pub enum StatsView {
+    /// プロジェクトビュー（プロジェクト/ブランチ単位）
+    Project(ProjectViewArgs),
+
+    /// 横断ビュー（全プロジェクト）
+    Cross(CrossViewArgs),
+}
+
+/// プロジェクトビューの引数
+#[derive(Debug, Clone, Args)]
+pub struct ProjectViewArgs {
+    /// プロジェクト ID
+    #[arg(long)]
+    pub project_id: i64,
+
+    /// ブランチ名
+    #[arg(long)]
+    pub branch: String,
+
+    /// 対象年
+    #[arg(long)]
+    pub year: i32,
+
+    #[command(flatten)]
+    pub filter: StatsFilterArgs,
+}
+
+/// 横断ビューの引数
+#[derive(Debug, Clone, Args)]
+pub struct CrossViewArgs {
+    /// 対象年
+    #[arg(long)]
+    pub year: i32,
+
+    #[command(flatten)]
+    pub filter: StatsFilterArgs,
+
+    #[command(flatten)]
+    pub projects: ProjectFilterArgs,
+
+    /// ユーザー（またはチーム）ごとのプロジェクト/名前空間内訳を出力する（stats のみ）
+    #[arg(long, value_enum)]
+    pub breakdown: Option<BreakdownArg>,
+}
+
+/// 横断ビューの対象プロジェクト指定（いずれかに一致するプロジェクトを対象にする）
+#[derive(Debug, Clone, Args)]
+pub struct ProjectFilterArgs {
+    /// プロジェクトタグ（手動タグ/名前空間/トピック、複数指定可）
+    #[arg(long = "tag")]
+    pub tags: Vec<String>,
+
+    /// 名前空間（サブグループを含む、複数指定可）
+    #[arg(long = "namespace")]
+    pub namespaces: Vec<String>,
+
+    /// プロジェクト ID（複数指定可）
+    #[arg(long = "project")]
+    pub project_ids: Vec<i64>,
+}
+
+/// 集計ビュー共通の絞り込み/内訳指定
+#[derive(Debug, Clone, Args)]
+pub struct StatsFilterArgs {
+    /// フィルタするユーザーキー（複数指定可、省略時は全ユーザー）
+    #[arg(long = "user-key")]
+    pub user_keys: Vec<String>,
+
+    /// 対象カテゴリ（feat / fix など、複数指定可、省略時は全カテゴリ）
+    #[arg(long = "category")]
+    pub categories: Vec<String>,
+
+    /// ユーザーごとの合計をカテゴリ別にも出力する
+    #[arg(long)]
+    pub split_by_category: bool,
+
+    /// ボットのコミットも集計に含める（既定は除外して別枠で集計）
+    #[arg(long)]
+    pub include_bots: bool,
+
+    /// ユーザー別ではなくチーム別に集計する（チームはアプリで設定）
+    #[arg(long, conflicts_with = "by_project")]
+    pub by_team: bool,
+
+    /// ユーザー別ではなくプロジェクト別に集計する（コントリビューター数も出力）
+    #[arg(long)]
+    pub by_project: bool,
+
+    /// 集計する指標
+    #[arg(long, value_enum, default_value_t = MetricArg::TotalLines)]
+    pub metric: MetricArg,
+
+    /// 外れ値（巨大）コミットを除外または閾値で頭打ちにする（JSON 出力に対象コミットを含む）
+    #[arg(long, value_enum)]
+    pub outliers: Option<OutlierModeArg>,
+
+    /// 外れ値の閾値をパーセンタイルで指定する（省略時は IQR × 1.5）
+    #[arg(long, requires = "outliers")]
+    pub outlier_percentile: Option<f64>,
+
+    /// 稼働日あたりの値も出力する（稼働日カレンダーで週末・祝日・休暇を除く、stats のみ）
+    #[arg(long)]
+    pub per_working_day: bool,
+
+    /// 系列ごとの移動平均・回帰の傾き・季節ナイーブ予測を出力する（stats のみ）
+    #[arg(long)]
+    pub trend: bool,
+
+    /// 予測する月数（--trend 指定時、既定: 3）
+    #[arg(long, requires = "trend")]
+    pub forecast: Option<usize>,
+
+    /// 期間に振り分ける日時（省略時はアプリの集計レポート設定に従う）
+    #[arg(long, value_enum)]
+    pub date_basis: Option<DateBasisArg>,
+}
+
+/// エクスポートの引数
+#[derive(Debug, Args)]
+pub struct ExportArgs {
+    /// 出力先ファイル（拡張子 .csv / .jsonl / .xlsx から形式を判定）
+    #[arg(long, short)]
+    pub output: PathBuf,
+
+    /// 出力形式（省略時は拡張子から判定）
+    #[arg(long, value_enum)]
+    pub export_format: Option<ExportFileFormat>,
+
+    /// 集計テーブルの列（複数指定可、指定順に出力。省略時は全列）
+    #[arg(long = "summary-column", value_enum)]
+    pub summary_columns: Vec<SummaryColumnArg>,
+
+    /// 集計元コミットも出力する
+    #[arg(long)]
+    pub include_commits: bool,
+
+    /// コミットテーブルの列（複数指定可、指定順に出力。省略時は全列）
+    #[arg(long = "commit-column", value_enum, requires = "include_commits")]
+    pub commit_columns: Vec<CommitColumnArg>,
+
+    /// 作者識別子（メールアドレス）をマスクせずに出力する
+    #[arg(long)]
+    pub full_identity: bool,
+
+    #[command(subcommand)]
+    pub view: StatsView,
+}
+
+/// 集計指標
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum MetricArg {
+    /// 変更行数（追加 + 削除）
+    TotalLines,
+    /// 追加行数
+    Additions,
+    /// 削除行数
+    Deletions,
+    /// 純増行数（追加 - 削除）
+    NetLines,
+    /// コミット数
+    CommitCount,
+    /// コミットした日数
+    ActiveDays,
+    /// 平均コミットサイズ
+    AvgCommitSize,
+    /// コミットサイズの中央値
+    MedianCommitSize,
+}
+
+/// 集計に使うコミットの日時
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum DateBasisArg {
+    /// コミット日時（committed_date）
+    Committed,
+    /// 作成日時（authored_date）
+    Authored,
+}
+
+/// 外れ値コミットの扱い
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutlierModeArg {
+    /// 集計から除外する
+    Exclude,
+    /// 行数を閾値で頭打ちにする
+    Cap,
+}
+
+/// 内訳の単位
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum BreakdownArg {
+    /// プロジェクト別
+    Project,
+    /// 名前空間別
+    Namespace,
+}
+
+/// 集計テーブルの列
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SummaryColumnArg {
+    /// ユーザーキー
+    UserKey,
+    /// 表示名
+    DisplayName,
+    /// 月
+    Month,
+    /// 指標値
+    TotalLines,
+    /// stats 欠損コミット数
+    MissingCount,
+}
+
+/// コミットテーブルの列
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum CommitColumnArg {
+    /// プロジェクト ID
+    ProjectId,
+    /// ブランチ名
+    BranchName,
+    /// コミット SHA
+    Sha,
+    /// コミット日時
+    CommittedDate,
+    /// ユーザーキー
+    UserKey,
+    /// 作者名
+    AuthorName,
+    /// 追加行数
+    Additions,
+    /// 削除行数
+    Deletions,
+    /// 変更行数
+    TotalLines,
+    /// コミットメッセージ
+    Message,
+    /// カテゴリ
+    Category,
+}
+
+/// エクスポートファイル形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ExportFileFormat {
+    /// CSV（コミットは別ファイル）
+    Csv,
+    /// JSON Lines
+    Jsonl,
+    /// Excel ブック（テーブルごとにシート）
+    Xlsx,
+}
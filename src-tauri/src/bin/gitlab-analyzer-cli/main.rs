@@ -0,0 +1,701 @@
+//! GitLab 月次コミット行数分析 CLI
+//!
+//! デスクトップアプリと同じコマンド実装・ストレージを使い、
+//! ウィンドウなしで収集と集計を実行する（ビルドサーバーの cron 実行向け）。
+
+mod args;
+mod output;
+
+use args::{
+    ArchiveModeArg, BreakdownArg, Cli, Command, CommitColumnArg, CrossViewArgs, DateBasisArg,
+    ExportArgs, ExportFileFormat, MetricArg, OutlierModeArg, ProjectFilterArgs, ProjectViewArgs,
+    StatsFilterArgs, StatsView, SummaryColumnArg,
+};
+use clap::Parser;
+use gitlab_analyzer_lib::commands::{
+    backup_database_inner, collect_commits_inner, export_archive_inner, export_stats_inner,
+    get_database_health_inner, get_monthly_stats_cross_view_inner,
+    get_monthly_stats_project_view_inner, import_archive_inner, import_local_repository_inner,
+    list_database_backups_inner, optimize_database_inner, prepare_bulk_collection_start,
+    purge_old_commits_inner, repair_missing_stats_inner, restore_database_inner,
+    run_bulk_collection, sync_projects_inner, BackupDatabaseRequest, CollectCommitsRequest,
+    CrossViewStatsRequest, ExportArchiveRequest, ExportStatsRequest, ExportView,
+    ImportArchiveRequest, ImportLocalRepositoryRequest, OptimizeDatabaseRequest,
+    ProjectViewStatsRequest, PurgeOldCommitsRequest, RepairMissingStatsRequest,
+    RestoreDatabaseRequest,
+};
+use gitlab_analyzer_lib::domain::{CommitDateBasis, ProjectFilter};
+use gitlab_analyzer_lib::error::{AppError, AppResult};
+use gitlab_analyzer_lib::export::{CommitColumn, ExportFormat, IdentityMode, SummaryColumn};
+use gitlab_analyzer_lib::stats::{
+    GroupBy, Metric, MonthlyStatsResponse, OutlierHandling, OutlierMode, OutlierRule,
+    ProjectBreakdown, TrendOptions,
+};
+use gitlab_analyzer_lib::storage::{bulk_collection_repository, ArchiveImportMode};
+use gitlab_analyzer_lib::{initialize_sqlite, logging};
+use output::write_output;
+use std::path::Path;
+use std::process::ExitCode;
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    logging::init_cli_logging();
+
+    let cli = Cli::parse();
+
+    if let Err(err) = initialize_sqlite() {
+        eprintln!("データベースの初期化に失敗しました: {}", err);
+        return ExitCode::FAILURE;
+    }
+
+    match run(cli).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{}", err.user_message());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn run(cli: Cli) -> AppResult<()> {
+    let stdout = std::io::stdout().lock();
+
+    match cli.command {
+        Command::SyncProjects => {
+            let projects = sync_projects_inner().await?;
+            write_output(&projects, cli.format, stdout)
+        }
+        Command::Collect(args) => {
+            let result = collect_commits_inner(CollectCommitsRequest {
+                project_id: args.project_id,
+                branch_name: args.branch,
+                since_utc: args.since,
+                until_utc: args.until,
+            })
+            .await?;
+            write_output(&result, cli.format, stdout)
+        }
+        Command::CollectBulk(args) => {
+            let mut context = prepare_bulk_collection_start()?;
+            context.repair_missing_stats = args.repair_missing_stats;
+            let run_id = context.run_id.clone();
+
+            let triggered = run_bulk_collection(context, |progress| {
+                if let Some(target) = &progress.current_target {
+                    eprintln!(
+                        "[{}/{}] project_id={}, branch={} (success={}, failed={})",
+                        progress.completed_count,
+                        progress.total_targets,
+                        target.project_id,
+                        target.branch_name,
+                        progress.success_count,
+                        progress.failed_count
+                    );
+                }
+                if let Some(repair) = &progress.stats_repair {
+                    eprintln!(
+                        "stats 欠損コミットの再取得: repaired={}, still_missing={}, failed={}",
+                        repair.repaired_count,
+                        repair.still_missing_count,
+                        repair.failures.len()
+                    );
+                }
+            })
+            .await;
+
+            if let Some(triggered) = triggered {
+                for alert in &triggered.alerts {
+                    eprintln!("[alert] {}", alert.message);
+                }
+            }
+
+            let status = bulk_collection_repository::get_status(&run_id, true)?;
+            write_output(&status, cli.format, stdout)
+        }
+        Command::RepairStats(args) => {
+            let result = repair_missing_stats_inner(RepairMissingStatsRequest {
+                project_id: args.project_id,
+                limit: args.limit,
+                max_retries: args.max_retries,
+            })
+            .await?;
+            write_output(&result, cli.format, stdout)
+        }
+        Command::ImportLocal(args) => {
+            let result = import_local_repository_inner(ImportLocalRepositoryRequest {
+                path: args.path.to_string_lossy().into_owned(),
+                ref_name: args.ref_name,
+                project_name: args.name,
+                since_utc: args.since,
+                until_utc: args.until,
+            })?;
+            write_output(&result, cli.format, stdout)
+        }
+        Command::Stats { view } => {
+            let response = aggregate(view)?;
+            write_output(&response, cli.format, stdout)
+        }
+        Command::Export(args) => {
+            let result = export_stats_inner(export_request(args)?)?;
+            for file in &result.files {
+                eprintln!("書き出しました: {}", file);
+            }
+            write_output(&result, cli.format, stdout)
+        }
+        Command::ExportArchive(args) => {
+            let result = export_archive_inner(ExportArchiveRequest {
+                output_path: args.output.to_string_lossy().into_owned(),
+            })?;
+            eprintln!("書き出しました: {}", result.output_path);
+            write_output(&result, cli.format, stdout)
+        }
+        Command::ImportArchive(args) => {
+            let result = import_archive_inner(ImportArchiveRequest {
+                path: args.path.to_string_lossy().into_owned(),
+                mode: match args.mode {
+                    ArchiveModeArg::Merge => ArchiveImportMode::Merge,
+                    ArchiveModeArg::Replace => ArchiveImportMode::Replace,
+                },
+            })?;
+            write_output(&result, cli.format, stdout)
+        }
+        Command::Backup(args) => {
+            let result = backup_database_inner(BackupDatabaseRequest {
+                output_path: args
+                    .output
+                    .map(|output| output.to_string_lossy().into_owned()),
+            })?;
+            eprintln!("バックアップしました: {}", result.path);
+            write_output(&result, cli.format, stdout)
+        }
+        Command::Backups => {
+            let result = list_database_backups_inner()?;
+            write_output(&result, cli.format, stdout)
+        }
+        Command::Restore(args) => {
+            let result = restore_database_inner(RestoreDatabaseRequest {
+                path: args.path.to_string_lossy().into_owned(),
+            })?;
+            eprintln!("復元前の状態を保存しました: {}", result.snapshot.path);
+            write_output(&result, cli.format, stdout)
+        }
+        Command::Health => {
+            let result = get_database_health_inner()?;
+            if let Some(warning) = &result.warning {
+                eprintln!("[warning] {}", warning);
+            }
+            write_output(&result, cli.format, stdout)
+        }
+        Command::Optimize(args) => {
+            let result = optimize_database_inner(OptimizeDatabaseRequest {
+                vacuum: !args.no_vacuum,
+                analyze: !args.no_analyze,
+            })?;
+            write_output(&result, cli.format, stdout)
+        }
+        Command::Purge(args) => {
+            let result = purge_old_commits_inner(PurgeOldCommitsRequest {
+                days_to_keep: args.keep_days,
+                dry_run: args.dry_run,
+            })?;
+            if let Some(snapshot_path) = &result.snapshot_path {
+                eprintln!("削除前の状態を保存しました: {}", snapshot_path);
+            }
+            write_output(&result, cli.format, stdout)
+        }
+    }
+}
+
+/// 集計ビューのリクエスト
+enum StatsRequest {
+    Project(ProjectViewStatsRequest),
+    Cross(CrossViewStatsRequest),
+}
+
+/// 集計ビューを実行
+fn aggregate(view: StatsView) -> AppResult<MonthlyStatsResponse> {
+    match stats_request(view) {
+        StatsRequest::Project(request) => get_monthly_stats_project_view_inner(request),
+        StatsRequest::Cross(request) => get_monthly_stats_cross_view_inner(request),
+    }
+}
+
+/// 集計ビューの引数をリクエストに変換
+fn stats_request(view: StatsView) -> StatsRequest {
+    match view {
+        StatsView::Project(ProjectViewArgs {
+            project_id,
+            branch,
+            year,
+            filter,
+        }) => StatsRequest::Project(ProjectViewStatsRequest {
+            project_id,
+            branch_name: branch,
+            year,
+            group_by: group_by(&filter),
+            metric: metric(filter.metric),
+            date_basis: date_basis(&filter),
+            outliers: outlier_handling(&filter),
+            per_working_day: filter.per_working_day,
+            trend: trend_options(&filter),
+            user_keys: filter.user_keys,
+            categories: filter.categories,
+            split_by_category: filter.split_by_category,
+            exclude_bots: !filter.include_bots,
+        }),
+        StatsView::Cross(CrossViewArgs {
+            year,
+            filter,
+            projects,
+            breakdown,
+        }) => StatsRequest::Cross(CrossViewStatsRequest {
+            year,
+            group_by: group_by(&filter),
+            metric: metric(filter.metric),
+            date_basis: date_basis(&filter),
+            outliers: outlier_handling(&filter),
+            per_working_day: filter.per_working_day,
+            trend: trend_options(&filter),
+            user_keys: filter.user_keys,
+            categories: filter.categories,
+            split_by_category: filter.split_by_category,
+            exclude_bots: !filter.include_bots,
+            projects: project_filter(projects),
+            breakdown: breakdown.map(|b| match b {
+                BreakdownArg::Project => ProjectBreakdown::Project,
+                BreakdownArg::Namespace => ProjectBreakdown::Namespace,
+            }),
+        }),
+    }
+}
+
+/// 集計単位を決定（--by-team / --by-project）
+fn group_by(filter: &StatsFilterArgs) -> GroupBy {
+    if filter.by_team {
+        GroupBy::Team
+    } else if filter.by_project {
+        GroupBy::Project
+    } else {
+        GroupBy::User
+    }
+}
+
+/// 集計指標に変換
+fn metric(metric: MetricArg) -> Metric {
+    match metric {
+        MetricArg::TotalLines => Metric::TotalLines,
+        MetricArg::Additions => Metric::Additions,
+        MetricArg::Deletions => Metric::Deletions,
+        MetricArg::NetLines => Metric::NetLines,
+        MetricArg::CommitCount => Metric::CommitCount,
+        MetricArg::ActiveDays => Metric::ActiveDays,
+        MetricArg::AvgCommitSize => Metric::AvgCommitSize,
+        MetricArg::MedianCommitSize => Metric::MedianCommitSize,
+    }
+}
+
+/// 外れ値の扱いに変換（--outliers / --outlier-percentile）
+fn outlier_handling(filter: &StatsFilterArgs) -> Option<OutlierHandling> {
+    let mode = match filter.outliers? {
+        OutlierModeArg::Exclude => OutlierMode::Exclude,
+        OutlierModeArg::Cap => OutlierMode::Cap,
+    };
+    let rule = filter
+        .outlier_percentile
+        .map(|percentile| OutlierRule::Percentile { percentile })
+        .unwrap_or_default();
+    Some(OutlierHandling { rule, mode })
+}
+
+/// トレンドの計算設定に変換（--trend / --forecast）
+fn trend_options(filter: &StatsFilterArgs) -> Option<TrendOptions> {
+    filter.trend.then(|| TrendOptions {
+        forecast_periods: filter
+            .forecast
+            .unwrap_or(TrendOptions::default().forecast_periods),
+        ..Default::default()
+    })
+}
+
+/// 期間に振り分ける日時に変換（--date-basis）
+fn date_basis(filter: &StatsFilterArgs) -> Option<CommitDateBasis> {
+    filter.date_basis.map(|basis| match basis {
+        DateBasisArg::Committed => CommitDateBasis::Committed,
+        DateBasisArg::Authored => CommitDateBasis::Authored,
+    })
+}
+
+/// 横断ビューのプロジェクト絞り込み条件に変換
+fn project_filter(args: ProjectFilterArgs) -> ProjectFilter {
+    ProjectFilter {
+        tags: args.tags,
+        namespaces: args.namespaces,
+        project_ids: args.project_ids,
+    }
+}
+
+/// エクスポート引数をリクエストに変換
+fn export_request(args: ExportArgs) -> AppResult<ExportStatsRequest> {
+    let format = match args.export_format.or_else(|| format_from_extension(&args.output)) {
+        Some(ExportFileFormat::Csv) => ExportFormat::Csv,
+        Some(ExportFileFormat::Jsonl) => ExportFormat::Jsonl,
+        Some(ExportFileFormat::Xlsx) => ExportFormat::Xlsx,
+        None => {
+            return Err(AppError::Validation(
+                "出力形式を判定できません。拡張子を .csv / .jsonl / .xlsx にするか --export-format を指定してください"
+                    .to_string(),
+            ))
+        }
+    };
+
+    let (view, project_id, branch_name, year, filter, projects) = match args.view {
+        StatsView::Project(view) => (
+            ExportView::ProjectView,
+            Some(view.project_id),
+            Some(view.branch),
+            view.year,
+            view.filter,
+            ProjectFilter::default(),
+        ),
+        StatsView::Cross(view) if view.breakdown.is_some() => {
+            return Err(AppError::Validation(
+                "--breakdown は stats コマンドでのみ指定できます".to_string(),
+            ))
+        }
+        StatsView::Cross(view) => (
+            ExportView::CrossView,
+            None,
+            None,
+            view.year,
+            view.filter,
+            project_filter(view.projects),
+        ),
+    };
+
+    if filter.per_working_day || filter.trend {
+        return Err(AppError::Validation(
+            "--per-working-day / --trend は stats コマンドでのみ指定できます".to_string(),
+        ));
+    }
+
+    Ok(ExportStatsRequest {
+        view,
+        project_id,
+        branch_name,
+        year,
+        group_by: group_by(&filter),
+        metric: metric(filter.metric),
+        date_basis: date_basis(&filter),
+        outliers: outlier_handling(&filter),
+        user_keys: filter.user_keys,
+        categories: filter.categories,
+        exclude_bots: !filter.include_bots,
+        projects,
+        format,
+        output_path: args.output.to_string_lossy().into_owned(),
+        summary_columns: args
+            .summary_columns
+            .into_iter()
+            .map(summary_column)
+            .collect(),
+        include_commits: args.include_commits,
+        commit_columns: args.commit_columns.into_iter().map(commit_column).collect(),
+        identity: if args.full_identity {
+            IdentityMode::Full
+        } else {
+            IdentityMode::Masked
+        },
+    })
+}
+
+/// 集計テーブルの列に変換
+fn summary_column(column: SummaryColumnArg) -> SummaryColumn {
+    match column {
+        SummaryColumnArg::UserKey => SummaryColumn::UserKey,
+        SummaryColumnArg::DisplayName => SummaryColumn::DisplayName,
+        SummaryColumnArg::Month => SummaryColumn::Month,
+        SummaryColumnArg::TotalLines => SummaryColumn::TotalLines,
+        SummaryColumnArg::MissingCount => SummaryColumn::MissingCount,
+    }
+}
+
+/// コミットテーブルの列に変換
+fn commit_column(column: CommitColumnArg) -> CommitColumn {
+    match column {
+        CommitColumnArg::ProjectId => CommitColumn::ProjectId,
+        CommitColumnArg::BranchName => CommitColumn::BranchName,
+        CommitColumnArg::Sha => CommitColumn::Sha,
+        CommitColumnArg::CommittedDate => CommitColumn::CommittedDate,
+        CommitColumnArg::UserKey => CommitColumn::UserKey,
+        CommitColumnArg::AuthorName => CommitColumn::AuthorName,
+        CommitColumnArg::Additions => CommitColumn::Additions,
+        CommitColumnArg::Deletions => CommitColumn::Deletions,
+        CommitColumnArg::TotalLines => CommitColumn::TotalLines,
+        CommitColumnArg::Message => CommitColumn::Message,
+        CommitColumnArg::Category => CommitColumn::Category,
+    }
+}
+
+/// 出力ファイルの拡張子から形式を推定
+fn format_from_extension(path: &Path) -> Option<ExportFileFormat> {
+    match path.extension()?.to_str()?.to_ascii_lowercase().as_str() {
+        "csv" => Some(ExportFileFormat::Csv),
+        "jsonl" => Some(ExportFileFormat::Jsonl),
+        "xlsx" => Some(ExportFileFormat::Xlsx),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use args::OutputFormat;
+
+    fn parse(args: &[&str]) -> Cli {
+        Cli::try_parse_from(std::iter::once("gitlab-analyzer-cli").chain(args.iter().copied()))
+            .unwrap()
+    }
+
+    fn stats_view(cli: Cli) -> StatsView {
+        match cli.command {
+            Command::Stats { view } => view,
+            other => panic!("unexpected command: {:?}", other),
+        }
+    }
+
+    fn export_args(cli: Cli) -> ExportArgs {
+        match cli.command {
+            Command::Export(args) => args,
+            other => panic!("unexpected command: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_collect_args() {
+        let cli = parse(&[
+            "collect",
+            "--project-id",
+            "42",
+            "--branch",
+            "main",
+            "--since",
+            "2026-01-01T00:00:00Z",
+        ]);
+        let Command::Collect(args) = cli.command else {
+            panic!("unexpected command");
+        };
+        assert_eq!(args.project_id, 42);
+        assert_eq!(args.branch, "main");
+        assert_eq!(args.since.as_deref(), Some("2026-01-01T00:00:00Z"));
+        assert_eq!(args.until, None);
+
+        let cli = parse(&["collect-bulk", "--repair-missing-stats"]);
+        let Command::CollectBulk(args) = cli.command else {
+            panic!("unexpected command");
+        };
+        assert!(args.repair_missing_stats);
+        assert!(matches!(
+            parse(&["sync-projects"]).command,
+            Command::SyncProjects
+        ));
+    }
+
+    #[test]
+    fn test_stats_project_request() {
+        let cli = parse(&[
+            "--format",
+            "csv",
+            "stats",
+            "project",
+            "--project-id",
+            "7",
+            "--branch",
+            "develop",
+            "--year",
+            "2026",
+            "--by-team",
+            "--metric",
+            "commit-count",
+            "--category",
+            "feat",
+            "--category",
+            "fix",
+            "--trend",
+            "--forecast",
+            "6",
+        ]);
+        assert_eq!(cli.format, OutputFormat::Csv);
+
+        let StatsRequest::Project(request) = stats_request(stats_view(cli)) else {
+            panic!("expected project view request");
+        };
+        assert_eq!(request.project_id, 7);
+        assert_eq!(request.branch_name, "develop");
+        assert_eq!(request.year, 2026);
+        assert_eq!(request.group_by, GroupBy::Team);
+        assert_eq!(request.metric, Metric::CommitCount);
+        assert_eq!(request.categories, vec!["feat", "fix"]);
+        assert!(request.exclude_bots);
+        assert_eq!(request.date_basis, None);
+        assert_eq!(request.trend.map(|t| t.forecast_periods), Some(6));
+    }
+
+    #[test]
+    fn test_stats_cross_request() {
+        let cli = parse(&[
+            "stats",
+            "cross",
+            "--year",
+            "2025",
+            "--tag",
+            "frontend",
+            "--namespace",
+            "my-group",
+            "--project",
+            "42",
+            "--breakdown",
+            "namespace",
+            "--include-bots",
+            "--date-basis",
+            "authored",
+            "--outliers",
+            "cap",
+            "--outlier-percentile",
+            "99",
+        ]);
+
+        let StatsRequest::Cross(request) = stats_request(stats_view(cli)) else {
+            panic!("expected cross view request");
+        };
+        assert_eq!(request.year, 2025);
+        assert_eq!(request.group_by, GroupBy::User);
+        assert_eq!(
+            request.projects,
+            ProjectFilter {
+                tags: vec!["frontend".to_string()],
+                namespaces: vec!["my-group".to_string()],
+                project_ids: vec![42],
+            }
+        );
+        assert_eq!(request.breakdown, Some(ProjectBreakdown::Namespace));
+        assert!(!request.exclude_bots);
+        assert_eq!(request.date_basis, Some(CommitDateBasis::Authored));
+        assert_eq!(
+            request.outliers,
+            Some(OutlierHandling {
+                rule: OutlierRule::Percentile { percentile: 99.0 },
+                mode: OutlierMode::Cap,
+            })
+        );
+    }
+
+    #[test]
+    fn test_stats_rejects_conflicting_group_by() {
+        let result = Cli::try_parse_from([
+            "gitlab-analyzer-cli",
+            "stats",
+            "cross",
+            "--year",
+            "2026",
+            "--by-team",
+            "--by-project",
+        ]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_export_request_with_columns() {
+        let cli = parse(&[
+            "export",
+            "--output",
+            "stats-2026.CSV",
+            "--summary-column",
+            "user-key",
+            "--summary-column",
+            "total-lines",
+            "--include-commits",
+            "--commit-column",
+            "sha",
+            "--commit-column",
+            "message",
+            "cross",
+            "--year",
+            "2026",
+            "--namespace",
+            "my-group",
+        ]);
+
+        let request = export_request(export_args(cli)).unwrap();
+        assert_eq!(request.view, ExportView::CrossView);
+        assert_eq!(request.year, 2026);
+        assert_eq!(request.format, ExportFormat::Csv);
+        assert_eq!(request.output_path, "stats-2026.CSV");
+        assert_eq!(
+            request.summary_columns,
+            vec![SummaryColumn::UserKey, SummaryColumn::TotalLines]
+        );
+        assert!(request.include_commits);
+        assert_eq!(
+            request.commit_columns,
+            vec![CommitColumn::Sha, CommitColumn::Message]
+        );
+        assert_eq!(request.identity, IdentityMode::Masked);
+        assert_eq!(request.projects.namespaces, vec!["my-group"]);
+    }
+
+    #[test]
+    fn test_export_request_defaults_and_validation() {
+        let cli = parse(&[
+            "export",
+            "--output",
+            "out.dat",
+            "--export-format",
+            "xlsx",
+            "--full-identity",
+            "project",
+            "--project-id",
+            "1",
+            "--branch",
+            "main",
+            "--year",
+            "2026",
+        ]);
+        let request = export_request(export_args(cli)).unwrap();
+        assert_eq!(request.view, ExportView::ProjectView);
+        assert_eq!(request.project_id, Some(1));
+        assert_eq!(request.branch_name.as_deref(), Some("main"));
+        assert_eq!(request.format, ExportFormat::Xlsx);
+        assert_eq!(request.identity, IdentityMode::Full);
+        // 列を指定しなければ全列（空のまま渡す）
+        assert!(request.summary_columns.is_empty());
+        assert!(request.commit_columns.is_empty());
+
+        // コミット列は --include-commits と合わせて指定する
+        let result = Cli::try_parse_from([
+            "gitlab-analyzer-cli",
+            "export",
+            "--output",
+            "out.csv",
+            "--commit-column",
+            "sha",
+            "cross",
+            "--year",
+            "2026",
+        ]);
+        assert!(result.is_err());
+
+        // 拡張子から形式を判定できない場合と stats 専用の指定はエラー
+        let cli = parse(&["export", "--output", "out.dat", "cross", "--year", "2026"]);
+        assert!(matches!(
+            export_request(export_args(cli)),
+            Err(AppError::Validation(_))
+        ));
+        let cli = parse(&[
+            "export", "--output", "out.csv", "cross", "--year", "2026", "--trend",
+        ]);
+        assert!(matches!(
+            export_request(export_args(cli)),
+            Err(AppError::Validation(_))
+        ));
+    }
+}
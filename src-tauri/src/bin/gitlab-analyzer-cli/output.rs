@@ -0,0 +1,436 @@
+//! CLI 出力
+//!
+//! コマンド結果を JSON または CSV で書き出す。
+//! CSV は 1 行 1 レコードの縦持ち形式とし、表計算ソフトでのピボットを前提とする。
+
+use crate::args::OutputFormat;
+use gitlab_analyzer_lib::commands::{
+    CollectCommitsResult, ExportArchiveResult, ExportStatsResult, ImportArchiveResult,
+    ImportLocalRepositoryResult, RepairMissingStatsResult, RestoreDatabaseResult,
+};
+use gitlab_analyzer_lib::domain::Project;
+use gitlab_analyzer_lib::error::{AppError, AppResult};
+use gitlab_analyzer_lib::stats::MonthlyStatsResponse;
+use gitlab_analyzer_lib::storage::sqlite::DatabaseHealth;
+use gitlab_analyzer_lib::storage::{
+    BulkCollectionStatus, CommitPurgeResult, DatabaseBackup, DatabaseOptimizeResult,
+};
+use serde::Serialize;
+use std::io::Write;
+
+/// CSV として出力可能な結果
+pub trait CsvRows {
+    /// ヘッダー行
+    fn headers(&self) -> Vec<&'static str>;
+
+    /// データ行
+    fn rows(&self) -> Vec<Vec<String>>;
+}
+
+/// 結果を指定形式で書き出す
+pub fn write_output<T, W>(value: &T, format: OutputFormat, writer: W) -> AppResult<()>
+where
+    T: Serialize + CsvRows,
+    W: Write,
+{
+    match format {
+        OutputFormat::Json => write_json(value, writer),
+        OutputFormat::Csv => write_csv(value, writer),
+    }
+}
+
+fn write_json<T: Serialize, W: Write>(value: &T, mut writer: W) -> AppResult<()> {
+    serde_json::to_writer_pretty(&mut writer, value)
+        .map_err(|e| AppError::Internal(format!("JSON 出力失敗: {}", e)))?;
+    writeln!(writer).map_err(|e| AppError::Storage(format!("出力失敗: {}", e)))?;
+    Ok(())
+}
+
+fn write_csv<T: CsvRows, W: Write>(value: &T, writer: W) -> AppResult<()> {
+    let mut csv_writer = csv::Writer::from_writer(writer);
+
+    csv_writer
+        .write_record(value.headers())
+        .map_err(|e| AppError::Storage(format!("CSV 出力失敗: {}", e)))?;
+    for row in value.rows() {
+        csv_writer
+            .write_record(&row)
+            .map_err(|e| AppError::Storage(format!("CSV 出力失敗: {}", e)))?;
+    }
+
+    csv_writer
+        .flush()
+        .map_err(|e| AppError::Storage(format!("CSV 出力失敗: {}", e)))?;
+    Ok(())
+}
+
+impl CsvRows for Vec<Project> {
+    fn headers(&self) -> Vec<&'static str> {
+        vec!["project_id", "name", "path_with_namespace", "web_url"]
+    }
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        self.iter()
+            .map(|p| {
+                vec![
+                    p.project_id.to_string(),
+                    p.name.clone(),
+                    p.path_with_namespace.clone(),
+                    p.web_url.clone(),
+                ]
+            })
+            .collect()
+    }
+}
+
+impl CsvRows for CollectCommitsResult {
+    fn headers(&self) -> Vec<&'static str> {
+        vec![
+            "inserted_count",
+            "skipped_duplicate_count",
+            "missing_stats_count",
+            "invalid_date_count",
+        ]
+    }
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        vec![vec![
+            self.inserted_count.to_string(),
+            self.skipped_duplicate_count.to_string(),
+            self.missing_stats_count.to_string(),
+            self.invalid_date_count.to_string(),
+        ]]
+    }
+}
+
+impl CsvRows for RepairMissingStatsResult {
+    fn headers(&self) -> Vec<&'static str> {
+        vec![
+            "candidate_count",
+            "repaired_count",
+            "still_missing_count",
+            "failed_count",
+        ]
+    }
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        vec![vec![
+            self.candidate_count.to_string(),
+            self.repaired_count.to_string(),
+            self.still_missing_count.to_string(),
+            self.failures.len().to_string(),
+        ]]
+    }
+}
+
+impl CsvRows for ImportLocalRepositoryResult {
+    fn headers(&self) -> Vec<&'static str> {
+        vec![
+            "project_id",
+            "path_with_namespace",
+            "branch_name",
+            "inserted_count",
+            "skipped_duplicate_count",
+            "invalid_date_count",
+        ]
+    }
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        vec![vec![
+            self.project_id.to_string(),
+            self.path_with_namespace.clone(),
+            self.branch_name.clone(),
+            self.inserted_count.to_string(),
+            self.skipped_duplicate_count.to_string(),
+            self.invalid_date_count.to_string(),
+        ]]
+    }
+}
+
+impl CsvRows for ExportArchiveResult {
+    fn headers(&self) -> Vec<&'static str> {
+        vec!["table", "row_count"]
+    }
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        self.tables
+            .iter()
+            .map(|table| vec![table.name.clone(), table.row_count.to_string()])
+            .collect()
+    }
+}
+
+impl CsvRows for ImportArchiveResult {
+    fn headers(&self) -> Vec<&'static str> {
+        vec!["table", "row_count", "imported_count"]
+    }
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        self.tables
+            .iter()
+            .map(|table| {
+                vec![
+                    table.name.clone(),
+                    table.row_count.to_string(),
+                    table.imported_count.to_string(),
+                ]
+            })
+            .collect()
+    }
+}
+
+const BACKUP_HEADERS: [&str; 5] = [
+    "path",
+    "reason",
+    "created_at_utc",
+    "size_bytes",
+    "schema_version",
+];
+
+fn backup_row(backup: &DatabaseBackup) -> Vec<String> {
+    vec![
+        backup.path.clone(),
+        backup.reason.clone().unwrap_or_default(),
+        backup.created_at_utc.clone(),
+        backup.size_bytes.to_string(),
+        backup
+            .schema_version
+            .map(|version| version.to_string())
+            .unwrap_or_default(),
+    ]
+}
+
+impl CsvRows for DatabaseBackup {
+    fn headers(&self) -> Vec<&'static str> {
+        BACKUP_HEADERS.to_vec()
+    }
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        vec![backup_row(self)]
+    }
+}
+
+impl CsvRows for Vec<DatabaseBackup> {
+    fn headers(&self) -> Vec<&'static str> {
+        BACKUP_HEADERS.to_vec()
+    }
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        self.iter().map(backup_row).collect()
+    }
+}
+
+impl CsvRows for RestoreDatabaseResult {
+    fn headers(&self) -> Vec<&'static str> {
+        BACKUP_HEADERS.to_vec()
+    }
+
+    /// 復元元と、復元前に作成したスナップショットの 2 行
+    fn rows(&self) -> Vec<Vec<String>> {
+        vec![backup_row(&self.restored_from), backup_row(&self.snapshot)]
+    }
+}
+
+impl CsvRows for DatabaseHealth {
+    fn headers(&self) -> Vec<&'static str> {
+        vec!["item", "value"]
+    }
+
+    /// 項目ごとの 1 行（テーブルの行数は rows:<テーブル名>）
+    fn rows(&self) -> Vec<Vec<String>> {
+        let optional = |value: &Option<String>| value.clone().unwrap_or_default();
+        let mut rows = vec![
+            vec![
+                "file_size_mb".to_string(),
+                format!("{:.2}", self.file_size_mb),
+            ],
+            vec![
+                "wal_size_mb".to_string(),
+                format!("{:.2}", self.wal_size_mb),
+            ],
+            vec![
+                "is_integrity_ok".to_string(),
+                self.is_integrity_ok.to_string(),
+            ],
+            vec![
+                "last_vacuum_at_utc".to_string(),
+                optional(&self.last_vacuum_at_utc),
+            ],
+            vec![
+                "last_analyze_at_utc".to_string(),
+                optional(&self.last_analyze_at_utc),
+            ],
+        ];
+        rows.extend(
+            self.table_row_counts
+                .iter()
+                .map(|table| vec![format!("rows:{}", table.name), table.row_count.to_string()]),
+        );
+        rows
+    }
+}
+
+impl CsvRows for DatabaseOptimizeResult {
+    fn headers(&self) -> Vec<&'static str> {
+        vec!["vacuumed", "analyzed", "size_before_mb", "size_after_mb"]
+    }
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        vec![vec![
+            self.vacuumed.to_string(),
+            self.analyzed.to_string(),
+            format!("{:.2}", self.size_before_mb),
+            format!("{:.2}", self.size_after_mb),
+        ]]
+    }
+}
+
+impl CsvRows for CommitPurgeResult {
+    fn headers(&self) -> Vec<&'static str> {
+        vec![
+            "cutoff_utc",
+            "dry_run",
+            "matched_count",
+            "deleted_count",
+            "snapshot_path",
+        ]
+    }
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        vec![vec![
+            self.cutoff_utc.clone(),
+            self.dry_run.to_string(),
+            self.matched_count.to_string(),
+            self.deleted_count.to_string(),
+            self.snapshot_path.clone().unwrap_or_default(),
+        ]]
+    }
+}
+
+impl CsvRows for ExportStatsResult {
+    fn headers(&self) -> Vec<&'static str> {
+        vec!["file", "summary_row_count", "commit_row_count"]
+    }
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        self.files
+            .iter()
+            .map(|file| {
+                vec![
+                    file.clone(),
+                    self.summary_row_count.to_string(),
+                    self.commit_row_count.to_string(),
+                ]
+            })
+            .collect()
+    }
+}
+
+impl CsvRows for BulkCollectionStatus {
+    fn headers(&self) -> Vec<&'static str> {
+        vec![
+            "run_id",
+            "run_status",
+            "project_id",
+            "branch_name",
+            "status",
+            "new_commits_count",
+            "error_message",
+            "processed_at",
+        ]
+    }
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        self.results
+            .iter()
+            .map(|r| {
+                vec![
+                    self.run_id.clone(),
+                    self.status.clone(),
+                    r.project_id.to_string(),
+                    r.branch_name.clone(),
+                    r.status.clone(),
+                    r.new_commits_count
+                        .map(|c| c.to_string())
+                        .unwrap_or_default(),
+                    r.error_message.clone().unwrap_or_default(),
+                    r.processed_at.clone().unwrap_or_default(),
+                ]
+            })
+            .collect()
+    }
+}
+
+impl CsvRows for MonthlyStatsResponse {
+    fn headers(&self) -> Vec<&'static str> {
+        vec![
+            "user_key",
+            "display_name",
+            "month",
+            self.metric.column_name(),
+            "missing_count",
+        ]
+    }
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        let mut rows = Vec::new();
+        for series in &self.series {
+            for (index, month) in self.months.iter().enumerate() {
+                rows.push(vec![
+                    series.user_key.clone(),
+                    series.display_name.clone(),
+                    month.to_string(),
+                    series.totals.get(index).copied().unwrap_or(0).to_string(),
+                    series
+                        .missing_counts
+                        .get(index)
+                        .copied()
+                        .unwrap_or(0)
+                        .to_string(),
+                ]);
+            }
+        }
+        rows
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gitlab_analyzer_lib::stats::{Metric, UserMonthlySeries};
+
+    #[test]
+    fn test_monthly_stats_csv_is_long_format() {
+        let response = MonthlyStatsResponse {
+            months: (1..=12).collect(),
+            metric: Metric::default(),
+            series: vec![UserMonthlySeries {
+                user_key: "alice@example.com".to_string(),
+                display_name: "Alice".to_string(),
+                totals: vec![10; 12],
+                missing_counts: vec![0; 12],
+                category_totals: None,
+                project_totals: None,
+                member_counts: None,
+                contributor_count: None,
+                per_working_day: None,
+            }],
+            excluded_bots: None,
+            outliers: None,
+            working_days: None,
+            trend: None,
+        };
+
+        let mut buffer = Vec::new();
+        write_output(&response, OutputFormat::Csv, &mut buffer).unwrap();
+        let text = String::from_utf8(buffer).unwrap();
+        let lines: Vec<_> = text.lines().collect();
+
+        assert_eq!(lines.len(), 13); // ヘッダー + 12ヶ月
+        assert_eq!(
+            lines[0],
+            "user_key,display_name,month,total_lines,missing_count"
+        );
+        assert_eq!(lines[1], "alice@example.com,Alice,1,10,0");
+    }
+}
@@ -0,0 +1,17 @@
+//! コマンド: アラートルール取得
+//!
+//! 一括収集後に評価するアラートルールを返す。
+
+use crate::error::AppResult;
+use crate::stats::AlertRule;
+use crate::storage;
+
+/// アラートルールを取得
+#[tauri::command]
+pub fn get_alert_rules() -> Result<Vec<AlertRule>, String> {
+    get_alert_rules_inner().map_err(|e| e.user_message())
+}
+
+pub fn get_alert_rules_inner() -> AppResult<Vec<AlertRule>> {
+    storage::get_alert_rules()
+}
@@ -0,0 +1,29 @@
+//! コマンド: アラートルール保存
+//!
+//! アラートルールを検証して保存する。
+
+use crate::error::AppResult;
+use crate::stats::AlertRule;
+use crate::storage;
+use serde::Deserialize;
+use tracing::info;
+
+/// アラートルール保存リクエスト
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetAlertRulesRequest {
+    /// アラートルール
+    pub rules: Vec<AlertRule>,
+}
+
+/// アラートルールを保存
+#[tauri::command]
+pub fn set_alert_rules(request: SetAlertRulesRequest) -> Result<(), String> {
+    set_alert_rules_inner(request).map_err(|e| e.user_message())
+}
+
+pub fn set_alert_rules_inner(request: SetAlertRulesRequest) -> AppResult<()> {
+    info!("アラートルール保存: {} 件", request.rules.len());
+
+    storage::save_alert_rules(&request.rules)
+}
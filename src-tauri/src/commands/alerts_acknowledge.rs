@@ -0,0 +1,46 @@
+//! コマンド: アラート確認
+//!
+//! アラートを確認済みにし、通知バッジから外す。
+
+use crate::error::AppResult;
+use crate::storage;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+/// アラート確認リクエスト
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AcknowledgeAlertsRequest {
+    /// 確認済みにするアラート ID（省略時は未確認のすべて）
+    pub ids: Option<Vec<i64>>,
+}
+
+/// アラート確認の結果
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AcknowledgeAlertsResult {
+    /// 確認済みにした件数
+    pub acknowledged_count: usize,
+    /// 残りの未確認件数
+    pub unacknowledged_count: i64,
+}
+
+/// アラートを確認済みにする
+#[tauri::command]
+pub fn acknowledge_alerts(
+    request: AcknowledgeAlertsRequest,
+) -> Result<AcknowledgeAlertsResult, String> {
+    acknowledge_alerts_inner(request).map_err(|e| e.user_message())
+}
+
+pub fn acknowledge_alerts_inner(
+    request: AcknowledgeAlertsRequest,
+) -> AppResult<AcknowledgeAlertsResult> {
+    let acknowledged_count = storage::acknowledge_alerts(request.ids.as_deref())?;
+    info!("アラート確認: {} 件", acknowledged_count);
+
+    Ok(AcknowledgeAlertsResult {
+        acknowledged_count,
+        unacknowledged_count: storage::count_unacknowledged_alerts()?,
+    })
+}
@@ -0,0 +1,114 @@
+//! コマンド: アラート評価
+//!
+//! 保存済みコミットに対してアラートルールを評価し、新たに発火したアラートを記録する。
+//! 一括収集の完了時にも呼び出される。
+
+use crate::error::{AppError, AppResult};
+use crate::stats::{evaluate_alert_rules, last_completed_month, AggregateOptions};
+use crate::storage::{self, Alert, CommitRepository, ProjectRepository};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+/// 発火したアラートの通知イベント名
+pub const ALERTS_TRIGGERED_EVENT: &str = "alerts-triggered";
+
+/// アラート評価リクエスト
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvaluateAlertsRequest {
+    /// 評価する年（month と併せて指定。省略時は直近の完了月）
+    pub year: Option<i32>,
+    /// 評価する月（1-12）
+    pub month: Option<u32>,
+}
+
+/// 新たに発火したアラート（`alerts-triggered` イベントのペイロード）
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlertsTriggered {
+    /// 評価のきっかけになった一括収集の実行 ID
+    pub run_id: Option<String>,
+    /// 新たに記録したアラート
+    pub alerts: Vec<Alert>,
+    /// 未確認のアラート件数（通知バッジ用）
+    pub unacknowledged_count: i64,
+}
+
+/// アラートルールを評価
+#[tauri::command]
+pub fn evaluate_alerts(request: EvaluateAlertsRequest) -> Result<AlertsTriggered, String> {
+    evaluate_alerts_inner(request).map_err(|e| e.user_message())
+}
+
+pub fn evaluate_alerts_inner(request: EvaluateAlertsRequest) -> AppResult<AlertsTriggered> {
+    let month = match (request.year, request.month) {
+        (Some(year), Some(month)) if (1..=12).contains(&month) => (year, month),
+        (None, None) => last_completed_month(chrono::Utc::now().date_naive()),
+        _ => {
+            return Err(AppError::Validation(
+                "評価する年月は year と month（1-12）を併せて指定してください".to_string(),
+            ))
+        }
+    };
+
+    evaluate_and_record(None, month)
+}
+
+/// 一括収集の完了後にアラートルールを評価（直近の完了月）
+pub fn evaluate_alerts_after_run(run_id: &str) -> AppResult<AlertsTriggered> {
+    evaluate_and_record(
+        Some(run_id),
+        last_completed_month(chrono::Utc::now().date_naive()),
+    )
+}
+
+fn evaluate_and_record(
+    run_id: Option<&str>,
+    (year, month): (i32, u32),
+) -> AppResult<AlertsTriggered> {
+    let rules = storage::get_alert_rules()?;
+
+    let alerts = if rules.iter().any(|rule| rule.enabled) {
+        // 前月比のために対象月の前月初から、巨大コミットのために現在までを取得する
+        let target = NaiveDate::from_ymd_opt(year, month, 1).ok_or_else(|| {
+            AppError::Validation(format!("評価する年月が不正です: {}-{}", year, month))
+        })?;
+        let (from_year, from_month) = last_completed_month(target);
+        let from = NaiveDate::from_ymd_opt(from_year, from_month, 1).unwrap_or(target);
+        let until = chrono::Utc::now()
+            .date_naive()
+            .succ_opt()
+            .unwrap_or(NaiveDate::MAX);
+        let date_basis = storage::get_reporting_settings()?.date_basis;
+        let commits = CommitRepository::find_by_date_range(from, until.max(from), date_basis)?;
+
+        let options = AggregateOptions {
+            teams: storage::list_teams()?,
+            projects: ProjectRepository::find_all()?
+                .into_iter()
+                .map(|p| (p.project_id, p))
+                .collect(),
+            ..Default::default()
+        };
+
+        let occurrences = evaluate_alert_rules(&rules, &commits, &options, year, month)?;
+        storage::record_alerts(run_id, &occurrences)?
+    } else {
+        Vec::new()
+    };
+
+    info!(
+        "アラート評価: {}-{:02}, ルール数={}, 新規アラート={}",
+        year,
+        month,
+        rules.len(),
+        alerts.len()
+    );
+
+    Ok(AlertsTriggered {
+        run_id: run_id.map(str::to_string),
+        alerts,
+        unacknowledged_count: storage::count_unacknowledged_alerts()?,
+    })
+}
@@ -0,0 +1,41 @@
+//! コマンド: アラート一覧
+//!
+//! 記録済みのアラートを新しい順に返す。
+
+use crate::error::AppResult;
+use crate::storage::{self, Alert};
+use serde::{Deserialize, Serialize};
+
+/// アラート一覧リクエスト
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListAlertsRequest {
+    /// 未確認のアラートのみ返すか
+    #[serde(default)]
+    pub unacknowledged_only: bool,
+    /// 最大件数（省略時は全件）
+    pub limit: Option<usize>,
+}
+
+/// アラート一覧
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlertList {
+    /// アラート（新しい順）
+    pub alerts: Vec<Alert>,
+    /// 未確認のアラート件数（通知バッジ用）
+    pub unacknowledged_count: i64,
+}
+
+/// アラート一覧を取得
+#[tauri::command]
+pub fn list_alerts(request: ListAlertsRequest) -> Result<AlertList, String> {
+    list_alerts_inner(request).map_err(|e| e.user_message())
+}
+
+pub fn list_alerts_inner(request: ListAlertsRequest) -> AppResult<AlertList> {
+    Ok(AlertList {
+        alerts: storage::list_alerts(request.unacknowledged_only, request.limit)?,
+        unacknowledged_count: storage::count_unacknowledged_alerts()?,
+    })
+}
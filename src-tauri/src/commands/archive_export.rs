@@ -0,0 +1,74 @@
+//! コマンド: データアーカイブの書き出し
+//!
+//! 収集済みのデータ一式を、別の端末に移行・共有できるアーカイブファイル（JSON）に書き出す。
+//! 接続設定（アクセストークン）は含めない。
+
+use crate::error::{AppError, AppResult};
+use crate::storage::{self, ArchiveTableSummary};
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use tracing::info;
+
+/// アーカイブ書き出しリクエスト
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportArchiveRequest {
+    /// 出力先ファイルパス
+    pub output_path: String,
+}
+
+/// アーカイブ書き出し結果
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportArchiveResult {
+    /// 出力先ファイルパス
+    pub output_path: String,
+    /// アーカイブのスキーマバージョン
+    pub schema_version: u32,
+    /// テーブルごとの行数
+    pub tables: Vec<ArchiveTableSummary>,
+}
+
+/// データアーカイブを書き出す
+#[tauri::command]
+pub fn export_archive(request: ExportArchiveRequest) -> Result<ExportArchiveResult, String> {
+    export_archive_inner(request).map_err(|e| e.user_message())
+}
+
+pub fn export_archive_inner(request: ExportArchiveRequest) -> AppResult<ExportArchiveResult> {
+    info!("アーカイブ書き出し開始: path={}", request.output_path);
+
+    let archive = storage::export_archive()?;
+
+    let file = File::create(&request.output_path)
+        .map_err(|e| AppError::Storage(format!("アーカイブファイル作成失敗: {}", e)))?;
+    let mut writer = BufWriter::new(file);
+    serde_json::to_writer(&mut writer, &archive)
+        .map_err(|e| AppError::Storage(format!("アーカイブ書き込み失敗: {}", e)))?;
+    writer
+        .flush()
+        .map_err(|e| AppError::Storage(format!("アーカイブ書き込み失敗: {}", e)))?;
+
+    let tables: Vec<ArchiveTableSummary> = archive
+        .tables
+        .iter()
+        .map(|table| ArchiveTableSummary {
+            name: table.name.clone(),
+            row_count: table.rows.len(),
+            imported_count: table.rows.len(),
+        })
+        .collect();
+
+    info!(
+        "アーカイブ書き出し完了: schema_version={}, rows={}",
+        archive.schema.version,
+        tables.iter().map(|t| t.row_count).sum::<usize>()
+    );
+
+    Ok(ExportArchiveResult {
+        output_path: request.output_path,
+        schema_version: archive.schema.version,
+        tables,
+    })
+}
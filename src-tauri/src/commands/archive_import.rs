@@ -0,0 +1,85 @@
+//! コマンド: データアーカイブの取り込み
+//!
+//! 別の端末で書き出したアーカイブファイルを取り込む。
+//! スキーマバージョンが一致しないアーカイブは取り込まない。
+//! 置き換えで取り込む場合は、事前にデータベースの自動スナップショットを作成する。
+
+use crate::error::{AppError, AppResult};
+use crate::storage::{self, ArchiveImportMode, ArchiveTableSummary, DataArchive, SnapshotReason};
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::BufReader;
+use tracing::info;
+
+/// アーカイブ取り込みリクエスト
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportArchiveRequest {
+    /// アーカイブファイルのパス
+    pub path: String,
+    /// 取り込み方法（merge: 既存を残して追加 / replace: 置き換え、既定: merge）
+    #[serde(default)]
+    pub mode: ArchiveImportMode,
+}
+
+/// アーカイブ取り込み結果
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportArchiveResult {
+    /// 取り込み方法
+    pub mode: ArchiveImportMode,
+    /// アーカイブのスキーマバージョン
+    pub schema_version: u32,
+    /// アーカイブの書き出し日時（UTC）
+    pub exported_at_utc: String,
+    /// テーブルごとの行数と取り込み件数
+    pub tables: Vec<ArchiveTableSummary>,
+    /// 置き換え前に作成したスナップショットのパス
+    pub snapshot_path: Option<String>,
+}
+
+/// データアーカイブを取り込む
+#[tauri::command]
+pub fn import_archive(request: ImportArchiveRequest) -> Result<ImportArchiveResult, String> {
+    import_archive_inner(request).map_err(|e| e.user_message())
+}
+
+pub fn import_archive_inner(request: ImportArchiveRequest) -> AppResult<ImportArchiveResult> {
+    info!(
+        "アーカイブ取り込み開始: path={}, mode={:?}",
+        request.path, request.mode
+    );
+
+    let file = File::open(&request.path).map_err(|e| {
+        AppError::Validation(format!(
+            "アーカイブファイルを読み込めません（{}）: {}",
+            request.path, e
+        ))
+    })?;
+    let archive: DataArchive = serde_json::from_reader(BufReader::new(file))
+        .map_err(|e| AppError::Validation(format!("アーカイブを解釈できません: {}", e)))?;
+
+    // 置き換えは既存データを削除するため、取り込めることを確認してから退避しておく
+    let snapshot_path = if request.mode == ArchiveImportMode::Replace {
+        storage::validate_archive(&archive)?;
+        Some(storage::create_snapshot(SnapshotReason::PreImport)?.path)
+    } else {
+        None
+    };
+
+    let tables = storage::import_archive(&archive, request.mode)?;
+
+    info!(
+        "アーカイブ取り込み完了: exported_at={}, imported_rows={}",
+        archive.exported_at_utc,
+        tables.iter().map(|t| t.imported_count).sum::<usize>()
+    );
+
+    Ok(ImportArchiveResult {
+        mode: request.mode,
+        schema_version: archive.schema.version,
+        exported_at_utc: archive.exported_at_utc,
+        tables,
+        snapshot_path,
+    })
+}
@@ -0,0 +1,31 @@
+//! コマンド: ボット判定設定取得
+//!
+//! ボット判定設定と GitLab で bot と判定済みのユーザーを返す。
+
+use crate::domain::BotConfig;
+use crate::error::AppResult;
+use crate::storage::{self, GitLabBotUser};
+use serde::Serialize;
+
+/// ボット判定設定取得レスポンス
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BotConfigResponse {
+    /// ボット判定設定
+    pub config: BotConfig,
+    /// GitLab で bot と判定済みのユーザー（sync_bot_users で更新）
+    pub gitlab_bot_users: Vec<GitLabBotUser>,
+}
+
+/// ボット判定設定を取得
+#[tauri::command]
+pub fn get_bot_config() -> Result<BotConfigResponse, String> {
+    get_bot_config_inner().map_err(|e| e.user_message())
+}
+
+pub fn get_bot_config_inner() -> AppResult<BotConfigResponse> {
+    Ok(BotConfigResponse {
+        config: storage::get_bot_config()?,
+        gitlab_bot_users: storage::list_gitlab_bot_users()?,
+    })
+}
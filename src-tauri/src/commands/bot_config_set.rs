@@ -0,0 +1,47 @@
+//! コマンド: ボット判定設定保存
+//!
+//! 設定を保存し、保存済みコミットの is_bot を再判定する。
+
+use crate::domain::BotConfig;
+use crate::error::AppResult;
+use crate::storage;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+/// ボット判定設定保存リクエスト
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetBotConfigRequest {
+    /// ボット判定設定
+    pub config: BotConfig,
+}
+
+/// ボット判定設定保存結果
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetBotConfigResult {
+    /// ボット判定が変わったコミット数
+    pub reclassified_count: usize,
+}
+
+/// ボット判定設定を保存
+#[tauri::command]
+pub fn set_bot_config(request: SetBotConfigRequest) -> Result<SetBotConfigResult, String> {
+    set_bot_config_inner(request).map_err(|e| e.user_message())
+}
+
+pub fn set_bot_config_inner(request: SetBotConfigRequest) -> AppResult<SetBotConfigResult> {
+    info!(
+        "ボット判定設定保存: exact_emails={}, name_patterns={}, email_patterns={}, use_gitlab_bot_flag={}",
+        request.config.exact_emails.len(),
+        request.config.name_patterns.len(),
+        request.config.email_patterns.len(),
+        request.config.use_gitlab_bot_flag
+    );
+
+    let reclassified_count = storage::save_bot_config(&request.config)?;
+
+    info!("ボット判定が変わったコミット数: {}", reclassified_count);
+
+    Ok(SetBotConfigResult { reclassified_count })
+}
@@ -0,0 +1,163 @@
+//! コマンド: GitLab ボットユーザー同期
+//!
+//! 保存済みコミットの作者を GitLab の users API で照会し、`bot` フラグが立っている
+//! ユーザー（プロジェクト/グループアクセストークン等）を記録して is_bot を再判定する。
+
+use crate::error::{AppError, AppResult};
+use crate::gitlab::{GitLabClient, GitLabUser};
+use crate::logging::mask_email;
+use crate::storage::{self, ConnectionRepository, GitLabBotUser};
+use serde::Serialize;
+use tracing::{info, warn};
+
+/// ボットユーザー同期結果
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncBotUsersResult {
+    /// 照会した作者数
+    pub checked_count: usize,
+    /// GitLab で bot と判定されたユーザー
+    pub bot_users: Vec<GitLabBotUser>,
+    /// 照会に失敗した作者数（該当なしは含まない）
+    pub failed_count: usize,
+    /// ボット判定が変わったコミット数
+    pub reclassified_count: usize,
+}
+
+/// GitLab のボットユーザーを同期
+#[tauri::command]
+pub async fn sync_bot_users() -> Result<SyncBotUsersResult, String> {
+    sync_bot_users_inner().await.map_err(|e| e.user_message())
+}
+
+pub async fn sync_bot_users_inner() -> AppResult<SyncBotUsersResult> {
+    let connection = ConnectionRepository::get()?.ok_or(AppError::ConnectionNotConfigured)?;
+    let client = GitLabClient::new(&connection.base_url, &connection.access_token)?;
+
+    let authors = storage::list_commit_authors()?;
+    info!("ボットユーザー同期開始: authors={}", authors.len());
+
+    let mut lookups = Vec::with_capacity(authors.len());
+    for (author_name, author_email) in &authors {
+        lookups.push(lookup_user(&client, author_name, author_email).await);
+    }
+    let BotUserLookups {
+        bot_users,
+        failed_user_keys,
+    } = collect_bot_users(&authors, lookups);
+    let failed_count = failed_user_keys.len();
+
+    // 一時的な照会失敗でボット判定が外れないよう、失敗した作者は保存済みの判定を残す
+    let reclassified_count = storage::replace_gitlab_bot_users(&bot_users, &failed_user_keys)?;
+
+    info!(
+        "ボットユーザー同期完了: bots={}, failed={}, reclassified={}",
+        bot_users.len(),
+        failed_count,
+        reclassified_count
+    );
+
+    Ok(SyncBotUsersResult {
+        checked_count: authors.len(),
+        bot_users,
+        failed_count,
+        reclassified_count,
+    })
+}
+
+/// 作者ごとの照会結果の集計
+#[derive(Debug, Default)]
+pub(crate) struct BotUserLookups {
+    /// GitLab で bot と判定されたユーザー
+    pub bot_users: Vec<GitLabBotUser>,
+    /// 照会に失敗した作者のユーザーキー
+    pub failed_user_keys: Vec<String>,
+}
+
+/// 作者ごとの照会結果（`authors` と同じ順）を bot ユーザーと照会失敗に振り分ける
+pub(crate) fn collect_bot_users(
+    authors: &[(String, String)],
+    lookups: Vec<AppResult<Option<GitLabUser>>>,
+) -> BotUserLookups {
+    let mut result = BotUserLookups::default();
+
+    for ((author_name, author_email), lookup) in authors.iter().zip(lookups) {
+        match lookup {
+            Ok(Some(user)) if user.bot => {
+                info!(
+                    "GitLab ボットユーザー: username={}, email={}",
+                    user.username,
+                    mask_email(author_email)
+                );
+                result.bot_users.push(GitLabBotUser {
+                    user_key: user_key(author_name, author_email).to_string(),
+                    gitlab_user_id: user.id,
+                    username: user.username,
+                });
+            }
+            Ok(_) => {}
+            Err(e) => {
+                warn!(
+                    "ユーザー照会失敗: email={}, error={}",
+                    mask_email(author_email),
+                    e
+                );
+                result
+                    .failed_user_keys
+                    .push(user_key(author_name, author_email).to_string());
+            }
+        }
+    }
+
+    result
+}
+
+/// 作者に対応する GitLab ユーザーを検索
+///
+/// メールアドレスでの検索は管理者トークンでしか一致しないため、見つからなければ名前で再検索する。
+async fn lookup_user(
+    client: &GitLabClient,
+    author_name: &str,
+    author_email: &str,
+) -> AppResult<Option<GitLabUser>> {
+    if !author_email.is_empty() {
+        let users = client.search_users(author_email).await?;
+        if let Some(user) = find_matching_user(users, author_name, author_email) {
+            return Ok(Some(user));
+        }
+    }
+
+    let users = client.search_users(author_name).await?;
+    Ok(find_matching_user(users, author_name, author_email))
+}
+
+/// 検索結果から作者に一致するユーザーを選ぶ（メール一致を優先、次に名前/ユーザー名の完全一致）
+pub(crate) fn find_matching_user(
+    users: Vec<GitLabUser>,
+    author_name: &str,
+    author_email: &str,
+) -> Option<GitLabUser> {
+    let email_matches = |user: &GitLabUser| {
+        !author_email.is_empty()
+            && [&user.email, &user.public_email]
+                .into_iter()
+                .flatten()
+                .any(|email| email.eq_ignore_ascii_case(author_email))
+    };
+
+    if let Some(index) = users.iter().position(email_matches) {
+        return users.into_iter().nth(index);
+    }
+
+    users
+        .into_iter()
+        .find(|user| user.name == author_name || user.username == author_name)
+}
+
+fn user_key<'a>(author_name: &'a str, author_email: &'a str) -> &'a str {
+    if author_email.is_empty() {
+        author_name
+    } else {
+        author_email
+    }
+}
@@ -0,0 +1,72 @@
+//! sync_bot_users のテスト
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::bot_users_sync::{collect_bot_users, find_matching_user};
+    use crate::error::AppError;
+    use crate::gitlab::GitLabUser;
+
+    fn user(id: i64, username: &str, name: &str, email: Option<&str>, bot: bool) -> GitLabUser {
+        GitLabUser {
+            id,
+            username: username.to_string(),
+            name: name.to_string(),
+            bot,
+            public_email: email.map(|e| e.to_string()),
+            email: None,
+        }
+    }
+
+    #[test]
+    fn test_email_match_takes_precedence() {
+        let users = vec![
+            user(1, "release", "Release Bot", None, false),
+            user(
+                2,
+                "project_7_bot",
+                "Release Bot",
+                Some("Bot@Example.com"),
+                true,
+            ),
+        ];
+
+        let matched = find_matching_user(users, "Release Bot", "bot@example.com").unwrap();
+        assert_eq!(matched.id, 2);
+        assert!(matched.bot);
+    }
+
+    #[test]
+    fn test_falls_back_to_name_and_rejects_partial_matches() {
+        let users = vec![
+            user(1, "alice2", "Alice Smith", None, false),
+            user(2, "alice", "Alice", None, false),
+        ];
+
+        let matched = find_matching_user(users.clone(), "Alice", "").unwrap();
+        assert_eq!(matched.id, 2);
+
+        assert!(find_matching_user(users, "Ali", "").is_none());
+    }
+
+    #[test]
+    fn test_failed_lookup_is_reported_separately() {
+        let authors = vec![
+            ("Release".to_string(), "svc-release@example.com".to_string()),
+            ("Deploy".to_string(), String::new()),
+            ("alice".to_string(), "alice@example.com".to_string()),
+        ];
+        let lookups = vec![
+            Err(AppError::from_gitlab_status(503, "Service Unavailable")),
+            Ok(Some(user(3, "project_1_bot", "Deploy", None, true))),
+            Ok(None),
+        ];
+
+        let result = collect_bot_users(&authors, lookups);
+
+        // 照会に失敗した作者は bot ユーザーから外さず、保存済みの判定を残す対象にする
+        assert_eq!(result.failed_user_keys, vec!["svc-release@example.com"]);
+        assert_eq!(result.bot_users.len(), 1);
+        assert_eq!(result.bot_users[0].user_key, "Deploy");
+        assert_eq!(result.bot_users[0].gitlab_user_id, 3);
+    }
+}
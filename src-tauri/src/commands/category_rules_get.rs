@@ -0,0 +1,39 @@
+//! コマンド: コミット分類ルール取得
+//!
+//! ユーザー定義の分類ルールと組み込みカテゴリを返す。
+
+use crate::domain::{CategoryRule, CATEGORY_OTHER, CONVENTIONAL_CATEGORIES};
+use crate::error::AppResult;
+use crate::storage;
+use serde::Serialize;
+
+/// 分類ルール取得レスポンス
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CategoryRulesResponse {
+    /// ユーザー定義ルール（評価順）
+    pub rules: Vec<CategoryRule>,
+    /// 組み込みカテゴリ（Conventional Commits + other）
+    pub builtin_categories: Vec<String>,
+}
+
+/// 分類ルールを取得
+#[tauri::command]
+pub fn get_category_rules() -> Result<CategoryRulesResponse, String> {
+    get_category_rules_inner().map_err(|e| e.user_message())
+}
+
+pub fn get_category_rules_inner() -> AppResult<CategoryRulesResponse> {
+    let rules = storage::list_category_rules()?;
+
+    let builtin_categories = CONVENTIONAL_CATEGORIES
+        .iter()
+        .chain(std::iter::once(&CATEGORY_OTHER))
+        .map(|c| c.to_string())
+        .collect();
+
+    Ok(CategoryRulesResponse {
+        rules,
+        builtin_categories,
+    })
+}
@@ -0,0 +1,50 @@
+//! コマンド: コミット分類ルール保存
+//!
+//! ルール一覧を置き換え、保存済みコミットを新しいルールで再分類する。
+
+use crate::domain::CategoryRule;
+use crate::error::AppResult;
+use crate::storage;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+/// 分類ルール保存リクエスト
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetCategoryRulesRequest {
+    /// ルール一覧（先頭から評価、空配列で全削除）
+    pub rules: Vec<CategoryRule>,
+}
+
+/// 分類ルール保存結果
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetCategoryRulesResult {
+    /// 保存したルール数
+    pub rule_count: usize,
+    /// カテゴリが変わったコミット数
+    pub reclassified_count: usize,
+}
+
+/// 分類ルールを保存
+#[tauri::command]
+pub fn set_category_rules(
+    request: SetCategoryRulesRequest,
+) -> Result<SetCategoryRulesResult, String> {
+    set_category_rules_inner(request).map_err(|e| e.user_message())
+}
+
+pub fn set_category_rules_inner(
+    request: SetCategoryRulesRequest,
+) -> AppResult<SetCategoryRulesResult> {
+    info!("分類ルール保存: rules={}", request.rules.len());
+
+    let reclassified_count = storage::replace_category_rules(&request.rules)?;
+
+    info!("再分類したコミット数: {}", reclassified_count);
+
+    Ok(SetCategoryRulesResult {
+        rule_count: request.rules.len(),
+        reclassified_count,
+    })
+}
@@ -1,6 +1,7 @@
 //! コマンド: ブランチ単位コミット削除
 //!
 //! 指定プロジェクト/ブランチの収集済みコミットを物理削除する。
+//! 削除前にデータベースの自動スナップショットを作成する。
 
 use crate::commands::commits_branch_delete_impact::AffectedView;
 use crate::error::{AppError, AppResult};
@@ -30,6 +31,8 @@ pub struct DeleteBranchResponse {
     pub deleted_count: i64,
     /// 影響を受けたビュー
     pub affected_views: Vec<AffectedView>,
+    /// 削除前に作成したスナップショットのパス
+    pub snapshot_path: Option<String>,
     /// ステータス
     pub status: String,
     /// メッセージ（省略可能）
@@ -77,12 +80,14 @@ fn delete_branch_commits_inner(request: DeleteBranchRequest) -> AppResult<Delete
             branch_name: request.branch_name,
             deleted_count: 0,
             affected_views: Vec::new(),
+            snapshot_path: None,
             status: "no_commits".to_string(),
             message: Some("削除対象のコミットがありません。".to_string()),
         });
     }
 
-    // 物理削除（FR-008: 復元不可）
+    // 物理削除（FR-008: 復元不可。誤削除に備えてスナップショットから復元できるようにする）
+    let snapshot = storage::create_snapshot(storage::SnapshotReason::PreDeleteBranch)?;
     let deleted_count =
         storage::CommitRepository::delete_by_branch(request.project_id, &request.branch_name)?;
 
@@ -98,6 +103,7 @@ fn delete_branch_commits_inner(request: DeleteBranchRequest) -> AppResult<Delete
         branch_name: request.branch_name,
         deleted_count,
         affected_views,
+        snapshot_path: Some(snapshot.path),
         status: "deleted".to_string(),
         message: None,
     })
@@ -2,12 +2,12 @@
 //!
 //! 指定プロジェクト/ブランチ/期間のコミットを収集してローカルに保存する。
 
-use crate::domain::Commit;
+use crate::domain::{is_local_project_id, Commit};
 use crate::error::{AppError, AppResult};
 use crate::gitlab::GitLabClient;
 use crate::storage::{CommitRepository, ConnectionRepository};
 use serde::{Deserialize, Serialize};
-use tracing::info;
+use tracing::{info, warn};
 
 /// コミット収集リクエスト
 #[derive(Debug, Deserialize)]
@@ -33,6 +33,10 @@ pub struct CollectCommitsResult {
     pub skipped_duplicate_count: usize,
     /// stats 欠損件数
     pub missing_stats_count: usize,
+    /// 日時が解釈できなかった件数（日時不正として保存）
+    pub invalid_date_count: usize,
+    /// 日時が解釈できなかったコミットの SHA
+    pub invalid_date_shas: Vec<String>,
 }
 
 /// コミットを収集
@@ -45,9 +49,16 @@ pub async fn collect_commits(
         .map_err(|e| e.user_message())
 }
 
-pub(crate) async fn collect_commits_inner(
+pub async fn collect_commits_inner(
     request: CollectCommitsRequest,
 ) -> AppResult<CollectCommitsResult> {
+    if is_local_project_id(request.project_id) {
+        return Err(AppError::Validation(format!(
+            "ローカルリポジトリから取り込んだプロジェクトは GitLab から収集できません（再取り込みしてください）: {}",
+            request.project_id
+        )));
+    }
+
     // 接続設定を取得
     let connection = ConnectionRepository::get()?.ok_or(AppError::ConnectionNotConfigured)?;
 
@@ -69,10 +80,29 @@ pub(crate) async fn collect_commits_inner(
 
     info!("取得したコミット数: {}", gitlab_commits.len());
 
-    // ドメインモデルに変換
+    // ドメインモデルに変換（日時が解釈できないコミットは 1 件ずつ警告する）
+    let mut invalid_date_shas = Vec::new();
     let commits: Vec<Commit> = gitlab_commits
         .into_iter()
-        .map(|c| Commit::from_gitlab(request.project_id, &request.branch_name, c))
+        .map(|c| {
+            let raw_committed_date = c.committed_date.clone();
+            let raw_authored_date = c.authored_date.clone();
+            let commit = Commit::from_gitlab(request.project_id, &request.branch_name, c);
+            if commit.date_invalid {
+                warn!(
+                    "コミット日時を解釈できません: project_id={}, sha={}, value={:?}",
+                    request.project_id, commit.sha, raw_committed_date
+                );
+                invalid_date_shas.push(commit.sha.clone());
+            } else if raw_authored_date.is_some() && commit.authored_date_utc.is_none() {
+                // 作成日時のみ不正な場合はコミット日時で代用されるため、警告に留める
+                warn!(
+                    "作成日時を解釈できません: project_id={}, sha={}, value={:?}",
+                    request.project_id, commit.sha, raw_authored_date
+                );
+            }
+            commit
+        })
         .collect();
 
     // stats 欠損件数をカウント
@@ -82,13 +112,24 @@ pub(crate) async fn collect_commits_inner(
     let upsert_result = CommitRepository::bulk_upsert(commits)?;
 
     info!(
-        "コミット収集完了: inserted={}, skipped={}, missing_stats={}",
-        upsert_result.inserted, upsert_result.skipped, missing_stats_count
+        "コミット収集完了: inserted={}, skipped={}, missing_stats={}, invalid_date={}",
+        upsert_result.inserted,
+        upsert_result.skipped,
+        missing_stats_count,
+        invalid_date_shas.len()
     );
+    if !invalid_date_shas.is_empty() {
+        warn!(
+            "日時を解釈できないコミットが {} 件あります（日時不正として保存し、期間集計から除外）",
+            invalid_date_shas.len()
+        );
+    }
 
     Ok(CollectCommitsResult {
         inserted_count: upsert_result.inserted,
         skipped_duplicate_count: upsert_result.skipped,
         missing_stats_count,
+        invalid_date_count: invalid_date_shas.len(),
+        invalid_date_shas,
     })
 }
@@ -2,7 +2,13 @@
 //!
 //! 収集履歴のある全対象を順次処理し、結果を保存する。
 
+use crate::commands::alerts_evaluate::{
+    evaluate_alerts_after_run, AlertsTriggered, ALERTS_TRIGGERED_EVENT,
+};
 use crate::commands::commits_collect::{collect_commits_inner, CollectCommitsRequest};
+use crate::commands::commits_repair_stats::{
+    repair_missing_stats_inner, RepairMissingStatsRequest, RepairMissingStatsResult,
+};
 use crate::error::{AppError, AppResult};
 use crate::storage::bulk_collection_repository::{
     get_collection_targets_with_connection, get_latest_resumable_run_with_connection,
@@ -29,34 +35,53 @@ pub struct BulkCollectionStarted {
 /// 進捗イベントのペイロード
 #[derive(Debug, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
-struct BulkCollectionProgress {
+pub struct BulkCollectionProgress {
     pub run_id: String,
     pub total_targets: i64,
     pub completed_count: i64,
     pub success_count: i64,
     pub failed_count: i64,
     pub current_target: Option<TargetInfo>,
+    /// stats 欠損コミットの再取得結果（後処理を実行した場合の完了通知のみ）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stats_repair: Option<RepairMissingStatsResult>,
 }
 
 /// 現在処理中の対象
 #[derive(Debug, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
-struct TargetInfo {
+pub struct TargetInfo {
     pub project_id: i64,
     pub branch_name: String,
 }
 
+/// 一括収集開始のリクエスト
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectBulkRequest {
+    /// 収集後に stats 欠損コミットを再取得するか
+    #[serde(default)]
+    pub repair_missing_stats: bool,
+}
+
 /// 失敗対象再試行のリクエスト
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RetryFailedRequest {
     pub run_id: String,
+    /// 収集後に stats 欠損コミットを再取得するか
+    #[serde(default)]
+    pub repair_missing_stats: bool,
 }
 
 /// 一括収集を開始
 #[tauri::command]
-pub async fn collect_commits_bulk(app: AppHandle) -> Result<BulkCollectionStarted, String> {
-    let context = prepare_bulk_collection_start().map_err(|e| e.user_message())?;
+pub async fn collect_commits_bulk(
+    app: AppHandle,
+    request: Option<CollectBulkRequest>,
+) -> Result<BulkCollectionStarted, String> {
+    let mut context = prepare_bulk_collection_start().map_err(|e| e.user_message())?;
+    context.repair_missing_stats = request.unwrap_or_default().repair_missing_stats;
 
     CANCEL_FLAG.store(false, Ordering::SeqCst);
 
@@ -123,6 +148,7 @@ pub async fn retry_failed_targets(
         completed_count: 0,
         success_count: 0,
         failed_count: 0,
+        repair_missing_stats: request.repair_missing_stats,
     };
 
     tokio::spawn(async move {
@@ -135,16 +161,20 @@ pub async fn retry_failed_targets(
     })
 }
 
-pub(crate) struct BulkCollectionStartContext {
-    pub(crate) run_id: String,
-    pub(crate) targets: Vec<(i64, String)>,
-    pub(crate) total_targets: i64,
-    pub(crate) completed_count: i64,
-    pub(crate) success_count: i64,
-    pub(crate) failed_count: i64,
+/// 一括収集の開始コンテキスト（新規または再開）
+pub struct BulkCollectionStartContext {
+    pub run_id: String,
+    pub targets: Vec<(i64, String)>,
+    pub total_targets: i64,
+    pub completed_count: i64,
+    pub success_count: i64,
+    pub failed_count: i64,
+    /// 収集後に stats 欠損コミットを再取得するか
+    pub repair_missing_stats: bool,
 }
 
-fn prepare_bulk_collection_start() -> AppResult<BulkCollectionStartContext> {
+/// 一括収集の開始準備（再開可能な実行があれば再開する）
+pub fn prepare_bulk_collection_start() -> AppResult<BulkCollectionStartContext> {
     let mut conn =
         DatabaseConnection::create_connection().map_err(|e| AppError::Storage(e.to_string()))?;
     prepare_bulk_collection_start_with_connection(&mut conn)
@@ -175,6 +205,7 @@ pub(crate) fn prepare_bulk_collection_start_with_connection(
             completed_count: status.completed_count,
             success_count: status.success_count,
             failed_count: status.failed_count,
+            repair_missing_stats: false,
         });
     }
 
@@ -194,10 +225,44 @@ pub(crate) fn prepare_bulk_collection_start_with_connection(
         completed_count: 0,
         success_count: 0,
         failed_count: 0,
+        repair_missing_stats: false,
     })
 }
 
 async fn process_bulk_collection(app: AppHandle, context: BulkCollectionStartContext) {
+    let triggered = run_bulk_collection(context, |payload| {
+        let is_final = payload.current_target.is_none();
+        if let Err(err) = app.emit("bulk-collection-progress", payload) {
+            if is_final {
+                warn!("完了通知の送信に失敗: {}", err);
+            } else {
+                warn!("進捗イベント送信に失敗: {}", err);
+            }
+        }
+    })
+    .await;
+
+    if let Some(triggered) = triggered.filter(|t| !t.alerts.is_empty()) {
+        if let Err(err) = app.emit(ALERTS_TRIGGERED_EVENT, triggered) {
+            warn!("アラート通知の送信に失敗: {}", err);
+        }
+    }
+}
+
+/// 一括収集の本体
+///
+/// 対象を順次収集し、対象ごとおよび完了時に `on_progress` を呼び出す。
+/// 完了時のペイロードは `current_target` が `None` になる。
+/// `repair_missing_stats` 指定時は全対象の収集後に stats 欠損コミットを再取得する。
+/// 完了後にアラートルールを評価し、その結果を返す（キャンセル時や評価失敗時は `None`）。
+/// Tauri のイベント送信に依存しないため、CLI からも利用できる。
+pub async fn run_bulk_collection<F>(
+    context: BulkCollectionStartContext,
+    mut on_progress: F,
+) -> Option<AlertsTriggered>
+where
+    F: FnMut(BulkCollectionProgress),
+{
     info!(
         run_id = %context.run_id,
         total_targets = context.total_targets,
@@ -213,7 +278,7 @@ async fn process_bulk_collection(app: AppHandle, context: BulkCollectionStartCon
             if let Err(err) = bulk_collection_repository::cancel_run(&context.run_id) {
                 warn!("キャンセル状態の更新に失敗: {}", err);
             }
-            return;
+            return None;
         }
 
         // チェックポイント取得（最後のコミット時刻）
@@ -269,16 +334,27 @@ async fn process_bulk_collection(app: AppHandle, context: BulkCollectionStartCon
                 project_id,
                 branch_name: branch_name.clone(),
             }),
+            stats_repair: None,
         };
 
-        if let Err(err) = app.emit("bulk-collection-progress", payload) {
-            warn!("進捗イベント送信に失敗: {}", err);
-        }
+        on_progress(payload);
 
         // NOTE: GitLab API の rate limit 対策として100ms待機する
         tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
     }
 
+    let stats_repair = if context.repair_missing_stats {
+        match repair_missing_stats_inner(RepairMissingStatsRequest::default()).await {
+            Ok(result) => Some(result),
+            Err(err) => {
+                warn!("stats 欠損コミットの再取得に失敗: {}", err);
+                None
+            }
+        }
+    } else {
+        None
+    };
+
     let completed_ok = match bulk_collection_repository::complete_run(&context.run_id) {
         Ok(()) => true,
         Err(err) => {
@@ -295,10 +371,17 @@ async fn process_bulk_collection(app: AppHandle, context: BulkCollectionStartCon
             success_count,
             failed_count,
             current_target: None,
+            stats_repair,
         };
 
-        if let Err(err) = app.emit("bulk-collection-progress", payload) {
-            warn!("完了通知の送信に失敗: {}", err);
+        on_progress(payload);
+    }
+
+    match evaluate_alerts_after_run(&context.run_id) {
+        Ok(triggered) => Some(triggered),
+        Err(err) => {
+            warn!("アラート評価に失敗: {}", err);
+            None
         }
     }
 }
@@ -0,0 +1,323 @@
+//! コマンド: コミット明細一覧（ドリルダウン）
+//!
+//! 集計セル（ユーザー × 月など）を構成するコミットをページ単位で返す。
+
+use crate::domain::{Commit, CommitDateBasis, Project};
+use crate::error::{AppError, AppResult};
+use crate::storage::reporting_settings_repository::get_reporting_settings_with_connection;
+use crate::storage::sqlite::commit_repository::{CommitFilter, CommitSortColumn};
+use crate::storage::{sqlite, CommitRepository};
+use chrono::{TimeZone, Utc};
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tracing::info;
+
+/// 1 ページあたりの既定件数
+const DEFAULT_PER_PAGE: u32 = 50;
+
+/// 1 ページあたりの最大件数
+const MAX_PER_PAGE: u32 = 200;
+
+/// 並び替え列
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CommitDetailSortBy {
+    /// コミット日時
+    #[default]
+    CommittedDate,
+    /// 追加行数
+    Additions,
+    /// 削除行数
+    Deletions,
+    /// 合計行数（追加 + 削除）
+    TotalLines,
+}
+
+impl From<CommitDetailSortBy> for CommitSortColumn {
+    fn from(value: CommitDetailSortBy) -> Self {
+        match value {
+            CommitDetailSortBy::CommittedDate => Self::CommittedDate,
+            CommitDetailSortBy::Additions => Self::Additions,
+            CommitDetailSortBy::Deletions => Self::Deletions,
+            CommitDetailSortBy::TotalLines => Self::TotalLines,
+        }
+    }
+}
+
+/// 並び順
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    Asc,
+    #[default]
+    Desc,
+}
+
+/// コミット明細一覧リクエスト
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListCommitsDetailRequest {
+    /// プロジェクト ID（未指定なら全プロジェクト横断）
+    #[serde(default)]
+    pub project_id: Option<i64>,
+    /// ブランチ名（未指定なら全ブランチ）
+    #[serde(default)]
+    pub branch_name: Option<String>,
+    /// ユーザーキー（未指定なら全ユーザー）
+    #[serde(default)]
+    pub user_key: Option<String>,
+    /// 対象年
+    pub year: i32,
+    /// 対象月（1-12、未指定なら年全体）
+    #[serde(default)]
+    pub month: Option<u32>,
+    /// 期間に振り分ける日時（省略時は集計レポート設定に従う）
+    #[serde(default)]
+    pub date_basis: Option<CommitDateBasis>,
+    /// ボットのコミットを除外するか（集計ビューと同じく既定は除外）
+    #[serde(default = "default_exclude_bots")]
+    pub exclude_bots: bool,
+    /// 対象カテゴリ（空の場合は全カテゴリ）
+    #[serde(default)]
+    pub categories: Vec<String>,
+    /// 並び替え列
+    #[serde(default)]
+    pub sort_by: CommitDetailSortBy,
+    /// 並び順（既定: 降順）
+    #[serde(default)]
+    pub sort_order: SortOrder,
+    /// メッセージの部分一致フィルタ
+    #[serde(default)]
+    pub message_contains: Option<String>,
+    /// 合計行数の下限
+    #[serde(default)]
+    pub min_total_lines: Option<i64>,
+    /// ページ番号（1 始まり）
+    #[serde(default = "default_page")]
+    pub page: u32,
+    /// 1 ページあたりの件数（最大 200）
+    #[serde(default = "default_per_page")]
+    pub per_page: u32,
+}
+
+fn default_exclude_bots() -> bool {
+    true
+}
+
+fn default_page() -> u32 {
+    1
+}
+
+fn default_per_page() -> u32 {
+    DEFAULT_PER_PAGE
+}
+
+/// コミット明細
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitDetailItem {
+    /// プロジェクト ID
+    pub project_id: i64,
+    /// ブランチ名
+    pub branch_name: String,
+    /// コミット SHA
+    pub sha: String,
+    /// コミットメッセージ
+    pub message: String,
+    /// コミット日時（UTC, RFC3339）
+    pub committed_date: String,
+    /// 作者名
+    pub author_name: String,
+    /// ユーザーキー
+    pub user_key: String,
+    /// 日時が解釈できなかったか（committed_date は UNIX エポック）
+    pub date_invalid: bool,
+    /// 追加行数
+    pub additions: i64,
+    /// 削除行数
+    pub deletions: i64,
+    /// 合計行数
+    pub total_lines: i64,
+    /// GitLab 上のコミット URL（プロジェクト未同期なら None）
+    pub web_url: Option<String>,
+}
+
+/// コミット明細一覧レスポンス
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitDetailPage {
+    /// 明細
+    pub items: Vec<CommitDetailItem>,
+    /// 条件に一致する総件数
+    pub total_count: i64,
+    /// ページ番号（1 始まり）
+    pub page: u32,
+    /// 1 ページあたりの件数
+    pub per_page: u32,
+    /// 総ページ数
+    pub total_pages: u32,
+}
+
+/// コミット明細一覧を取得
+#[tauri::command]
+pub fn list_commits_detail(request: ListCommitsDetailRequest) -> Result<CommitDetailPage, String> {
+    list_commits_detail_inner(request).map_err(|e| e.user_message())
+}
+
+/// コミット明細一覧取得の内部実装
+pub fn list_commits_detail_inner(request: ListCommitsDetailRequest) -> AppResult<CommitDetailPage> {
+    let conn = sqlite::DatabaseConnection::create_connection()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    list_commits_detail_with_connection(&conn, request)
+}
+
+/// 接続指定版（テスト用）
+pub(crate) fn list_commits_detail_with_connection(
+    conn: &Connection,
+    request: ListCommitsDetailRequest,
+) -> AppResult<CommitDetailPage> {
+    info!(
+        "コミット明細取得: project_id={:?}, branch={:?}, year={}, month={:?}, page={}",
+        request.project_id, request.branch_name, request.year, request.month, request.page
+    );
+
+    validate_request(&request)?;
+
+    // 集計ビューと同じ日時で期間に振り分ける
+    let date_basis = match request.date_basis {
+        Some(basis) => basis,
+        None => get_reporting_settings_with_connection(conn)?.date_basis,
+    };
+    let filter = build_filter(&request, date_basis)?;
+    let limit = request.per_page as i64;
+    let offset = (request.page as i64 - 1) * limit;
+
+    let (commits, total_count) = CommitRepository::find_page_with_connection(
+        conn,
+        &filter,
+        request.sort_by.into(),
+        matches!(request.sort_order, SortOrder::Desc),
+        limit,
+        offset,
+    )?;
+
+    // コミット URL 生成用のプロジェクト
+    let projects: HashMap<i64, Project> = sqlite::ProjectRepository::list_projects(conn)
+        .map_err(|e| AppError::Storage(e.to_string()))?
+        .into_iter()
+        .map(|p| {
+            let project = Project {
+                project_id: p.project_id as i64,
+                name: p.name,
+                path_with_namespace: p.path_with_namespace,
+                web_url: p.web_url,
+            };
+            (project.project_id, project)
+        })
+        .collect();
+
+    let items = commits.into_iter().map(|c| to_item(c, &projects)).collect();
+
+    let total_pages = ((total_count + limit - 1) / limit) as u32;
+
+    Ok(CommitDetailPage {
+        items,
+        total_count,
+        page: request.page,
+        per_page: request.per_page,
+        total_pages,
+    })
+}
+
+fn validate_request(request: &ListCommitsDetailRequest) -> AppResult<()> {
+    if let Some(month) = request.month {
+        if !(1..=12).contains(&month) {
+            return Err(AppError::Validation(format!(
+                "month は 1〜12 で指定してください: {}",
+                month
+            )));
+        }
+    }
+    if request.page == 0 {
+        return Err(AppError::Validation(
+            "page は 1 以上で指定してください".to_string(),
+        ));
+    }
+    if request.per_page == 0 || request.per_page > MAX_PER_PAGE {
+        return Err(AppError::Validation(format!(
+            "perPage は 1〜{} で指定してください: {}",
+            MAX_PER_PAGE, request.per_page
+        )));
+    }
+    Ok(())
+}
+
+/// リクエストから絞り込み条件を組み立てる（期間は UTC の年/月境界）
+fn build_filter(
+    request: &ListCommitsDetailRequest,
+    date_basis: CommitDateBasis,
+) -> AppResult<CommitFilter> {
+    let (since, until) = match request.month {
+        Some(12) => (
+            Utc.with_ymd_and_hms(request.year, 12, 1, 0, 0, 0),
+            Utc.with_ymd_and_hms(request.year + 1, 1, 1, 0, 0, 0),
+        ),
+        Some(month) => (
+            Utc.with_ymd_and_hms(request.year, month, 1, 0, 0, 0),
+            Utc.with_ymd_and_hms(request.year, month + 1, 1, 0, 0, 0),
+        ),
+        None => (
+            Utc.with_ymd_and_hms(request.year, 1, 1, 0, 0, 0),
+            Utc.with_ymd_and_hms(request.year + 1, 1, 1, 0, 0, 0),
+        ),
+    };
+    let (Some(since), Some(until)) = (since.single(), until.single()) else {
+        return Err(AppError::Validation(format!(
+            "不正な年が指定されました: {}",
+            request.year
+        )));
+    };
+
+    Ok(CommitFilter {
+        project_id: request.project_id.map(|id| id as i32),
+        branch_name: request.branch_name.clone(),
+        user_key: request.user_key.clone(),
+        since_utc: Some(since.to_rfc3339()),
+        until_utc: Some(until.to_rfc3339()),
+        use_authored_date: date_basis == CommitDateBasis::Authored,
+        exclude_bots: request.exclude_bots,
+        categories: request.categories.clone(),
+        message_contains: request
+            .message_contains
+            .clone()
+            .filter(|keyword| !keyword.trim().is_empty()),
+        min_total_lines: request.min_total_lines,
+        author: None,
+    })
+}
+
+fn to_item(commit: Commit, projects: &HashMap<i64, Project>) -> CommitDetailItem {
+    // 集計の系列と同じユーザーキー（絞り込み条件もメールアドレスが空なら作者名で比較する）
+    let user_key = commit.user_key();
+    let total_lines = commit.total_lines();
+    let web_url = projects
+        .get(&commit.project_id)
+        .map(|project| project.commit_url(&commit.sha));
+
+    CommitDetailItem {
+        project_id: commit.project_id,
+        branch_name: commit.branch_name,
+        sha: commit.sha,
+        message: commit.message,
+        committed_date: commit.committed_date_utc.to_rfc3339(),
+        author_name: commit.author_name,
+        user_key,
+        date_invalid: commit.date_invalid,
+        additions: commit.additions,
+        deletions: commit.deletions,
+        total_lines,
+        web_url,
+    }
+}
@@ -0,0 +1,212 @@
+//! コミット明細一覧コマンドのテスト
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::commits_detail_list::{
+        list_commits_detail_with_connection, CommitDetailSortBy, ListCommitsDetailRequest,
+        SortOrder,
+    };
+    use crate::domain::CommitDateBasis;
+    use crate::error::AppError;
+    use crate::stats::{aggregate_project_view, AggregateOptions};
+    use crate::storage::sqlite::run_migrations;
+    use crate::storage::CommitRepository;
+    use chrono::NaiveDate;
+    use rusqlite::Connection;
+
+    fn create_test_connection() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        run_migrations(&conn).unwrap();
+        conn.execute(
+            "INSERT INTO projects (project_id, name, path_with_namespace, web_url, last_sync_time_utc)
+             VALUES (1, 'project-a', 'group/project-a', 'https://gitlab.example.com/group/project-a', '2026-02-01T00:00:00Z'),
+                    (2, 'project-b', 'group/project-b', 'https://gitlab.example.com/group/project-b/', '2026-02-01T00:00:00Z')",
+            [],
+        )
+        .unwrap();
+
+        conn.execute_batch(
+            "INSERT INTO commits (project_id, branch_name, sha, message, author_name, author_email, committed_date_utc, additions, deletions) VALUES
+             (1, 'main', 'a1', 'feat: add login', 'alice', 'alice@example.com', '2026-01-10T09:00:00+00:00', 10, 2),
+             (1, 'main', 'a2', 'fix: login bug', 'alice', 'alice@example.com', '2026-01-20T09:00:00+00:00', 3, 1),
+             (1, 'main', 'a3', 'docs: readme', 'alice', 'alice@example.com', '2026-02-01T00:00:00+00:00', 1, 0),
+             (1, 'main', 'b1', 'refactor: Login form', 'bob', '', '2026-01-15T09:00:00+00:00', 50, 40),
+             (2, 'dev', 'c1', 'chore: deps', 'alice', 'alice@example.com', '2026-01-05T09:00:00+00:00', 5, 5);",
+        )
+        .unwrap();
+        conn
+    }
+
+    fn request(year: i32, month: Option<u32>) -> ListCommitsDetailRequest {
+        ListCommitsDetailRequest {
+            project_id: None,
+            branch_name: None,
+            user_key: None,
+            year,
+            month,
+            date_basis: None,
+            exclude_bots: true,
+            categories: Vec::new(),
+            sort_by: CommitDetailSortBy::default(),
+            sort_order: SortOrder::default(),
+            message_contains: None,
+            min_total_lines: None,
+            page: 1,
+            per_page: 50,
+        }
+    }
+
+    #[test]
+    fn test_filters_by_user_and_month_with_links() {
+        let conn = create_test_connection();
+        let mut req = request(2026, Some(1));
+        req.project_id = Some(1);
+        req.user_key = Some("alice@example.com".to_string());
+
+        let page = list_commits_detail_with_connection(&conn, req).unwrap();
+
+        assert_eq!(page.total_count, 2);
+        let shas: Vec<&str> = page.items.iter().map(|i| i.sha.as_str()).collect();
+        assert_eq!(shas, vec!["a2", "a1"]);
+        assert_eq!(page.items[0].message, "fix: login bug");
+        assert_eq!(
+            page.items[0].web_url.as_deref(),
+            Some("https://gitlab.example.com/group/project-a/-/commit/a2")
+        );
+    }
+
+    #[test]
+    fn test_user_key_falls_back_to_author_name() {
+        let conn = create_test_connection();
+        let mut req = request(2026, None);
+        req.user_key = Some("bob".to_string());
+
+        let page = list_commits_detail_with_connection(&conn, req).unwrap();
+
+        assert_eq!(page.total_count, 1);
+        assert_eq!(page.items[0].user_key, "bob");
+    }
+
+    #[test]
+    fn test_drill_down_into_email_less_series() {
+        let conn = create_test_connection();
+        let commits: Vec<_> = CommitRepository::find_by_date_range_with_connection(
+            &conn,
+            NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2027, 1, 1).unwrap(),
+            CommitDateBasis::Committed,
+        )
+        .unwrap()
+        .into_iter()
+        .filter(|c| c.project_id == 1)
+        .collect();
+        let stats = aggregate_project_view(&commits, &[], &AggregateOptions::default());
+        let bob = stats
+            .series
+            .iter()
+            .find(|s| s.display_name == "bob")
+            .unwrap();
+        assert_eq!(bob.user_key, "bob");
+
+        // チャートの系列のユーザーキーでそのセルのコミットを取得できる
+        let mut req = request(2026, Some(1));
+        req.project_id = Some(1);
+        req.user_key = Some(bob.user_key.clone());
+        let page = list_commits_detail_with_connection(&conn, req).unwrap();
+
+        assert_eq!(page.total_count, 1);
+        assert_eq!(page.items[0].user_key, bob.user_key);
+        assert_eq!(page.items[0].total_lines, bob.totals[0]);
+    }
+
+    #[test]
+    fn test_filters_like_chart_cell() {
+        let conn = create_test_connection();
+        conn.execute_batch(
+            "INSERT INTO commits (project_id, branch_name, sha, message, author_name, author_email, committed_date_utc, authored_date_utc, additions, deletions, category, is_bot) VALUES
+             (1, 'main', 'd1', 'feat: late push', 'dave', 'dave@example.com', '2026-01-03T09:00:00+00:00', '2025-12-28T09:00:00+00:00', 4, 0, 'feat', 0),
+             (1, 'main', 'd2', 'chore(deps): bump', 'renovate[bot]', 'bot@renovateapp.com', '2026-01-04T09:00:00+00:00', NULL, 2, 2, 'chore', 1);",
+        )
+        .unwrap();
+        let shas = |req: ListCommitsDetailRequest| -> Vec<String> {
+            let page = list_commits_detail_with_connection(&conn, req).unwrap();
+            let mut shas: Vec<String> = page.items.into_iter().map(|i| i.sha).collect();
+            shas.sort();
+            shas
+        };
+
+        // 既定はコミット日時で振り分け、ボットを除外する
+        let req = request(2026, Some(1));
+        assert_eq!(shas(req), vec!["a1", "a2", "b1", "c1", "d1"]);
+
+        // 作成日時で振り分けると前年 12 月のセルに入る（作成日時が無いコミットはコミット日時）
+        let mut req = request(2026, Some(1));
+        req.date_basis = Some(CommitDateBasis::Authored);
+        assert_eq!(shas(req), vec!["a1", "a2", "b1", "c1"]);
+        let mut req = request(2025, Some(12));
+        req.date_basis = Some(CommitDateBasis::Authored);
+        assert_eq!(shas(req), vec!["d1"]);
+
+        // ボットを含める指定とカテゴリの絞り込み
+        let mut req = request(2026, Some(1));
+        req.exclude_bots = false;
+        req.categories = vec!["feat".to_string(), "chore".to_string()];
+        assert_eq!(shas(req), vec!["d1", "d2"]);
+    }
+
+    #[test]
+    fn test_sort_message_filter_and_min_lines() {
+        let conn = create_test_connection();
+        let mut req = request(2026, None);
+        req.sort_by = CommitDetailSortBy::TotalLines;
+        req.message_contains = Some("LOGIN".to_string());
+        req.min_total_lines = Some(5);
+
+        let page = list_commits_detail_with_connection(&conn, req).unwrap();
+
+        let shas: Vec<&str> = page.items.iter().map(|i| i.sha.as_str()).collect();
+        assert_eq!(shas, vec!["b1", "a1"]);
+
+        // 末尾スラッシュ付きの Web URL でもリンクが崩れない
+        let mut req = request(2026, None);
+        req.project_id = Some(2);
+        let page = list_commits_detail_with_connection(&conn, req).unwrap();
+        assert_eq!(
+            page.items[0].web_url.as_deref(),
+            Some("https://gitlab.example.com/group/project-b/-/commit/c1")
+        );
+    }
+
+    #[test]
+    fn test_pagination() {
+        let conn = create_test_connection();
+        let mut req = request(2026, None);
+        req.sort_order = SortOrder::Asc;
+        req.per_page = 2;
+        req.page = 3;
+
+        let page = list_commits_detail_with_connection(&conn, req).unwrap();
+
+        assert_eq!(page.total_count, 5);
+        assert_eq!(page.total_pages, 3);
+        assert_eq!(page.items.len(), 1);
+        assert_eq!(page.items[0].sha, "a3");
+    }
+
+    #[test]
+    fn test_rejects_invalid_paging() {
+        let conn = create_test_connection();
+        let mut req = request(2026, Some(13));
+        assert!(matches!(
+            list_commits_detail_with_connection(&conn, req),
+            Err(AppError::Validation(_))
+        ));
+
+        req = request(2026, None);
+        req.per_page = 500;
+        assert!(matches!(
+            list_commits_detail_with_connection(&conn, req),
+            Err(AppError::Validation(_))
+        ));
+    }
+}
@@ -0,0 +1,166 @@
+//! コマンド: ローカル git リポジトリからのコミット取り込み
+//!
+//! ローカルの git リポジトリ（GitLab 以外のホストやミラー）の指定 ref のコミットを
+//! `git log --numstat` で読み込み、合成プロジェクトのコミットとして保存する。
+//! git コマンドのみを使うため、オフラインで実行できる。
+
+use crate::domain::{local_project, parse_git_log_numstat, GIT_LOG_FORMAT};
+use crate::error::{AppError, AppResult};
+use crate::storage::{CommitRepository, ProjectRepository};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::process::Command;
+use tracing::{info, warn};
+
+/// ローカルリポジトリ取り込みリクエスト
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportLocalRepositoryRequest {
+    /// リポジトリのパス（作業ツリーまたはベアリポジトリ）
+    pub path: String,
+    /// 取り込む ref（ブランチ/タグ/コミット、省略時は HEAD のブランチ）
+    pub ref_name: Option<String>,
+    /// プロジェクト名（省略時はディレクトリ名）
+    pub project_name: Option<String>,
+    /// 開始日時（ISO8601、省略可能）
+    pub since_utc: Option<String>,
+    /// 終了日時（ISO8601、省略可能）
+    pub until_utc: Option<String>,
+}
+
+/// ローカルリポジトリ取り込み結果
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportLocalRepositoryResult {
+    /// 合成プロジェクト ID（負の値）
+    pub project_id: i64,
+    /// 名前空間付きパス（local/<名前>）
+    pub path_with_namespace: String,
+    /// 保存したブランチ名
+    pub branch_name: String,
+    /// 新規挿入件数
+    pub inserted_count: usize,
+    /// 重複スキップ件数
+    pub skipped_duplicate_count: usize,
+    /// 日時が解釈できなかった件数（日時不正として保存）
+    pub invalid_date_count: usize,
+}
+
+/// ローカル git リポジトリからコミットを取り込む
+#[tauri::command]
+pub fn import_local_repository(
+    request: ImportLocalRepositoryRequest,
+) -> Result<ImportLocalRepositoryResult, String> {
+    import_local_repository_inner(request).map_err(|e| e.user_message())
+}
+
+pub fn import_local_repository_inner(
+    request: ImportLocalRepositoryRequest,
+) -> AppResult<ImportLocalRepositoryResult> {
+    let path = Path::new(&request.path).canonicalize().map_err(|e| {
+        AppError::Validation(format!(
+            "リポジトリのパスを開けません（{}）: {}",
+            request.path, e
+        ))
+    })?;
+
+    // ref を省略した場合は HEAD のブランチ名で保存する
+    let ref_name = match request
+        .ref_name
+        .as_deref()
+        .map(str::trim)
+        .filter(|r| !r.is_empty())
+    {
+        Some(ref_name) => ref_name.to_string(),
+        None => run_git(&path, &["rev-parse", "--abbrev-ref", "HEAD"])?
+            .trim()
+            .to_string(),
+    };
+    if ref_name.starts_with('-') {
+        return Err(AppError::Validation(format!(
+            "ref が不正です: {}",
+            ref_name
+        )));
+    }
+
+    let project = local_project(&path, request.project_name.as_deref())?;
+
+    info!(
+        "ローカルリポジトリ取り込み開始: path={}, ref={}, project_id={}, since={:?}, until={:?}",
+        path.display(),
+        ref_name,
+        project.project_id,
+        request.since_utc,
+        request.until_utc
+    );
+
+    let mut args = vec![
+        "log".to_string(),
+        "--numstat".to_string(),
+        GIT_LOG_FORMAT.to_string(),
+    ];
+    if let Some(since) = &request.since_utc {
+        args.push(format!("--since={}", since));
+    }
+    if let Some(until) = &request.until_utc {
+        args.push(format!("--until={}", until));
+    }
+    args.push(ref_name.clone());
+    args.push("--".to_string());
+
+    let output = run_git(&path, &args)?;
+    let commits = parse_git_log_numstat(project.project_id, &ref_name, &output)?;
+
+    info!("読み込んだコミット数: {}", commits.len());
+
+    let invalid_date_count = commits.iter().filter(|c| c.date_invalid).count();
+    if invalid_date_count > 0 {
+        warn!(
+            "日時を解釈できないコミットが {} 件あります（日時不正として保存し、期間集計から除外）",
+            invalid_date_count
+        );
+    }
+
+    // 合成プロジェクトを保存してからコミットを保存（重複スキップ）
+    ProjectRepository::upsert(&project)?;
+    let upsert_result = CommitRepository::bulk_upsert(commits)?;
+
+    info!(
+        "ローカルリポジトリ取り込み完了: inserted={}, skipped={}",
+        upsert_result.inserted, upsert_result.skipped
+    );
+
+    Ok(ImportLocalRepositoryResult {
+        project_id: project.project_id,
+        path_with_namespace: project.path_with_namespace,
+        branch_name: ref_name,
+        inserted_count: upsert_result.inserted,
+        skipped_duplicate_count: upsert_result.skipped,
+        invalid_date_count,
+    })
+}
+
+/// リポジトリで git コマンドを実行し、標準出力を返す
+fn run_git<S: AsRef<std::ffi::OsStr>>(path: &Path, args: &[S]) -> AppResult<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(path)
+        .args(args)
+        .output()
+        .map_err(|e| {
+            AppError::Validation(format!(
+                "git コマンドを実行できません。git がインストールされているか確認してください: {}",
+                e
+            ))
+        })?;
+
+    if !output.status.success() {
+        return Err(AppError::Validation(format!(
+            "git コマンドが失敗しました（{}）: {}",
+            path.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
@@ -0,0 +1,36 @@
+//! コマンド: 保持期間を過ぎたコミットの削除
+//!
+//! 保持日数より前のコミットを削除する。dry run では対象件数のみ返す。
+//! 削除前にデータベースの自動スナップショットを作成し、一括収集の実行中は削除しない。
+//! 日時不正のコミットは対象外。
+
+use crate::error::AppResult;
+use crate::storage::{self, CommitPurgeResult};
+use serde::Deserialize;
+use tracing::info;
+
+/// コミット削除リクエスト
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PurgeOldCommitsRequest {
+    /// 保持日数（これより前のコミットを削除）
+    pub days_to_keep: i32,
+    /// 件数の確認のみ行う
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// 保持期間を過ぎたコミットを削除
+#[tauri::command]
+pub fn purge_old_commits(request: PurgeOldCommitsRequest) -> Result<CommitPurgeResult, String> {
+    purge_old_commits_inner(request).map_err(|e| e.user_message())
+}
+
+pub fn purge_old_commits_inner(request: PurgeOldCommitsRequest) -> AppResult<CommitPurgeResult> {
+    info!(
+        "コミット削除開始: days_to_keep={}, dry_run={}",
+        request.days_to_keep, request.dry_run
+    );
+
+    storage::purge_old_commits(request.days_to_keep, request.dry_run)
+}
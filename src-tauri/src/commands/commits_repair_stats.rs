@@ -0,0 +1,173 @@
+//! コマンド: stats 欠損コミットの再取得
+//!
+//! 一覧 API で stats が返らなかったコミットを単一コミット API で取得し直し、
+//! 保存済みの追加/削除行数を補完する。一括収集の後処理としても実行できる。
+
+use crate::error::{AppError, AppResult};
+use crate::gitlab::{GitLabClient, GitLabCommit};
+use crate::storage::{CommitRepository, ConnectionRepository};
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+/// 再試行の初回待機時間（以降は 2 倍ずつ延ばす）
+const RETRY_BASE_DELAY_MS: u64 = 500;
+
+/// stats 欠損コミット再取得リクエスト
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepairMissingStatsRequest {
+    /// 対象プロジェクト ID（省略時は全プロジェクト）
+    pub project_id: Option<i64>,
+    /// 再取得するコミット数の上限（新しい順、省略時は全件）
+    pub limit: Option<usize>,
+    /// 通信失敗・レート制限・サーバーエラー時の再試行回数（既定: 3）
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+}
+
+fn default_max_retries() -> u32 {
+    3
+}
+
+impl Default for RepairMissingStatsRequest {
+    fn default() -> Self {
+        Self {
+            project_id: None,
+            limit: None,
+            max_retries: default_max_retries(),
+        }
+    }
+}
+
+/// 再取得に失敗したコミット
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepairFailure {
+    pub project_id: i64,
+    pub sha: String,
+    pub error: String,
+}
+
+/// stats 欠損コミット再取得結果
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepairMissingStatsResult {
+    /// 再取得の対象になったコミット数
+    pub candidate_count: usize,
+    /// 行数を補完したコミット数
+    pub repaired_count: usize,
+    /// 再取得しても stats が返らなかったコミット数（欠損のまま）
+    pub still_missing_count: usize,
+    /// 再試行しても取得できなかったコミット
+    pub failures: Vec<RepairFailure>,
+}
+
+/// stats 欠損コミットを再取得
+#[tauri::command]
+pub async fn repair_missing_stats(
+    request: RepairMissingStatsRequest,
+) -> Result<RepairMissingStatsResult, String> {
+    repair_missing_stats_inner(request)
+        .await
+        .map_err(|e| e.user_message())
+}
+
+pub async fn repair_missing_stats_inner(
+    request: RepairMissingStatsRequest,
+) -> AppResult<RepairMissingStatsResult> {
+    if request.max_retries > 10 {
+        return Err(AppError::Validation(format!(
+            "再試行回数は 0〜10 で指定してください: {}",
+            request.max_retries
+        )));
+    }
+
+    let connection = ConnectionRepository::get()?.ok_or(AppError::ConnectionNotConfigured)?;
+    let client = GitLabClient::new(&connection.base_url, &connection.access_token)?;
+
+    let candidates = CommitRepository::find_stats_missing(request.project_id, request.limit)?;
+    info!(
+        "stats 欠損コミットの再取得開始: project_id={:?}, candidates={}",
+        request.project_id,
+        candidates.len()
+    );
+
+    let mut result = RepairMissingStatsResult {
+        candidate_count: candidates.len(),
+        repaired_count: 0,
+        still_missing_count: 0,
+        failures: Vec::new(),
+    };
+
+    for (project_id, sha) in candidates {
+        match fetch_commit_with_retry(&client, project_id, &sha, request.max_retries).await {
+            Ok(commit) => match commit.stats {
+                // 0/0 でも確定した行数として保存し、欠損フラグを外す
+                // （移行時に 0 行だった既存コミットは欠損扱いにしているため、ここで確定させる）
+                Some(stats) => {
+                    CommitRepository::update_stats(
+                        project_id,
+                        &sha,
+                        stats.additions,
+                        stats.deletions,
+                    )?;
+                    result.repaired_count += 1;
+                }
+                None => result.still_missing_count += 1,
+            },
+            Err(err) => {
+                warn!(
+                    "コミットの再取得に失敗: project_id={}, sha={}, error={}",
+                    project_id, sha, err
+                );
+                result.failures.push(RepairFailure {
+                    project_id,
+                    sha,
+                    error: err.user_message(),
+                });
+            }
+        }
+
+        // NOTE: GitLab API の rate limit 対策として100ms待機する
+        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+    }
+
+    info!(
+        "stats 欠損コミットの再取得完了: repaired={}, still_missing={}, failed={}",
+        result.repaired_count,
+        result.still_missing_count,
+        result.failures.len()
+    );
+
+    Ok(result)
+}
+
+/// 単一コミットを取得（通信失敗・レート制限・サーバーエラー時は待機時間を延ばしながら再試行）
+///
+/// 認証失敗や存在しないコミットなど、再試行しても回復しないエラーはそのまま返す。
+async fn fetch_commit_with_retry(
+    client: &GitLabClient,
+    project_id: i64,
+    sha: &str,
+    max_retries: u32,
+) -> AppResult<GitLabCommit> {
+    let mut attempt = 0;
+    loop {
+        match client.get_commit(project_id, sha).await {
+            Ok(commit) => return Ok(commit),
+            Err(err) if err.is_retryable() && attempt < max_retries => {
+                let delay = RETRY_BASE_DELAY_MS << attempt;
+                warn!(
+                    "コミット取得を再試行: sha={}, attempt={}, delay_ms={}, error={}",
+                    sha,
+                    attempt + 1,
+                    delay,
+                    err
+                );
+                tokio::time::sleep(tokio::time::Duration::from_millis(delay)).await;
+                attempt += 1;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
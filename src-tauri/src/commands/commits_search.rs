@@ -0,0 +1,258 @@
+//! コマンド: コミットメッセージ全文検索
+//!
+//! FTS5 索引を使ってコミットメッセージを検索し、ハイライト付きの抜粋を返す。
+
+use crate::domain::{Commit, Project};
+use crate::error::{AppError, AppResult};
+use crate::storage::sqlite::commit_repository::{
+    CommitFilter, SNIPPET_MARK_END, SNIPPET_MARK_START,
+};
+use crate::storage::{sqlite, CommitRepository};
+use chrono::{NaiveDate, TimeZone, Utc};
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tracing::info;
+
+/// 1 ページあたりの既定件数
+const DEFAULT_PER_PAGE: u32 = 50;
+
+/// 1 ページあたりの最大件数
+const MAX_PER_PAGE: u32 = 200;
+
+/// コミット検索リクエスト
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchCommitsRequest {
+    /// 検索クエリ（FTS5 構文: "billing migration" でフレーズ一致、AND/OR/NOT。日本語も部分一致で検索でき、
+    /// 3 文字未満はメッセージの部分一致で検索する）
+    pub query: String,
+    /// プロジェクト ID
+    #[serde(default)]
+    pub project_id: Option<i64>,
+    /// ブランチ名
+    #[serde(default)]
+    pub branch_name: Option<String>,
+    /// 作者（メールアドレスまたは名前）
+    #[serde(default)]
+    pub author: Option<String>,
+    /// 開始日（UTC、この日を含む）
+    #[serde(default)]
+    pub since: Option<NaiveDate>,
+    /// 終了日（UTC、この日を含む）
+    #[serde(default)]
+    pub until: Option<NaiveDate>,
+    /// ページ番号（1 始まり）
+    #[serde(default = "default_page")]
+    pub page: u32,
+    /// 1 ページあたりの件数（最大 200）
+    #[serde(default = "default_per_page")]
+    pub per_page: u32,
+}
+
+fn default_page() -> u32 {
+    1
+}
+
+fn default_per_page() -> u32 {
+    DEFAULT_PER_PAGE
+}
+
+/// 検索ヒット
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitSearchItem {
+    /// プロジェクト ID
+    pub project_id: i64,
+    /// ブランチ名
+    pub branch_name: String,
+    /// コミット SHA
+    pub sha: String,
+    /// コミットメッセージ全文
+    pub message: String,
+    /// 一致箇所を `<mark>` で囲んだ抜粋（その他の文字は HTML エスケープ済み）
+    pub snippet: String,
+    /// コミット日時（UTC, RFC3339）
+    pub committed_date: String,
+    /// 作者名
+    pub author_name: String,
+    /// ユーザーキー
+    pub user_key: String,
+    /// 日時が解釈できなかったか（committed_date は UNIX エポック）
+    pub date_invalid: bool,
+    /// 追加行数
+    pub additions: i64,
+    /// 削除行数
+    pub deletions: i64,
+    /// GitLab 上のコミット URL
+    pub web_url: Option<String>,
+}
+
+/// コミット検索レスポンス
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitSearchResult {
+    /// ヒット（関連度順）
+    pub items: Vec<CommitSearchItem>,
+    /// 総ヒット件数
+    pub total_count: i64,
+    /// ページ番号（1 始まり）
+    pub page: u32,
+    /// 1 ページあたりの件数
+    pub per_page: u32,
+}
+
+/// コミットメッセージを全文検索
+#[tauri::command]
+pub fn search_commits(request: SearchCommitsRequest) -> Result<CommitSearchResult, String> {
+    search_commits_inner(request).map_err(|e| e.user_message())
+}
+
+/// コミット検索の内部実装
+pub fn search_commits_inner(request: SearchCommitsRequest) -> AppResult<CommitSearchResult> {
+    let conn = sqlite::DatabaseConnection::create_connection()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    search_commits_with_connection(&conn, request)
+}
+
+/// 接続指定版（テスト用）
+pub(crate) fn search_commits_with_connection(
+    conn: &Connection,
+    request: SearchCommitsRequest,
+) -> AppResult<CommitSearchResult> {
+    info!(
+        "コミット検索: project_id={:?}, branch={:?}, since={:?}, until={:?}, page={}",
+        request.project_id, request.branch_name, request.since, request.until, request.page
+    );
+
+    let query = request.query.trim();
+    if query.is_empty() {
+        return Err(AppError::Validation(
+            "検索クエリを入力してください".to_string(),
+        ));
+    }
+    if request.page == 0 {
+        return Err(AppError::Validation(
+            "page は 1 以上で指定してください".to_string(),
+        ));
+    }
+    if request.per_page == 0 || request.per_page > MAX_PER_PAGE {
+        return Err(AppError::Validation(format!(
+            "perPage は 1〜{} で指定してください: {}",
+            MAX_PER_PAGE, request.per_page
+        )));
+    }
+    if let (Some(since), Some(until)) = (request.since, request.until) {
+        if since > until {
+            return Err(AppError::Validation(format!(
+                "since ({}) は until ({}) 以前の日付を指定してください",
+                since, until
+            )));
+        }
+    }
+
+    let filter = CommitFilter {
+        project_id: request.project_id.map(|id| id as i32),
+        branch_name: request.branch_name.clone(),
+        since_utc: request.since.map(start_of_day_rfc3339),
+        until_utc: request
+            .until
+            .and_then(|date| date.succ_opt())
+            .map(start_of_day_rfc3339),
+        author: request
+            .author
+            .clone()
+            .filter(|author| !author.trim().is_empty()),
+        ..Default::default()
+    };
+
+    let limit = request.per_page as i64;
+    let offset = (request.page as i64 - 1) * limit;
+    let (hits, total_count) =
+        CommitRepository::search_with_connection(conn, query, &filter, limit, offset)?;
+
+    // コミット URL 生成用のプロジェクト
+    let projects: HashMap<i64, Project> = sqlite::ProjectRepository::list_projects(conn)
+        .map_err(|e| AppError::Storage(e.to_string()))?
+        .into_iter()
+        .map(|p| {
+            let project = Project {
+                project_id: p.project_id as i64,
+                name: p.name,
+                path_with_namespace: p.path_with_namespace,
+                web_url: p.web_url,
+            };
+            (project.project_id, project)
+        })
+        .collect();
+
+    let items = hits
+        .into_iter()
+        .map(|(commit, snippet)| to_item(commit, &snippet, &projects))
+        .collect();
+
+    Ok(CommitSearchResult {
+        items,
+        total_count,
+        page: request.page,
+        per_page: request.per_page,
+    })
+}
+
+/// 日付の 0 時（UTC）を保存形式と同じ RFC3339 で返す
+fn start_of_day_rfc3339(date: NaiveDate) -> String {
+    Utc.from_utc_datetime(&date.and_hms_opt(0, 0, 0).unwrap_or_default())
+        .to_rfc3339()
+}
+
+/// スニペットを HTML エスケープし、一致箇所のマーカーを `<mark>` に置き換える
+fn highlight_snippet(snippet: &str) -> String {
+    snippet
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace(SNIPPET_MARK_START, "<mark>")
+        .replace(SNIPPET_MARK_END, "</mark>")
+}
+
+fn to_item(commit: Commit, snippet: &str, projects: &HashMap<i64, Project>) -> CommitSearchItem {
+    let user_key = commit.user_key();
+    let web_url = projects
+        .get(&commit.project_id)
+        .map(|project| project.commit_url(&commit.sha));
+
+    CommitSearchItem {
+        project_id: commit.project_id,
+        branch_name: commit.branch_name,
+        sha: commit.sha,
+        message: commit.message,
+        snippet: highlight_snippet(snippet),
+        committed_date: commit.committed_date_utc.to_rfc3339(),
+        author_name: commit.author_name,
+        user_key,
+        date_invalid: commit.date_invalid,
+        additions: commit.additions,
+        deletions: commit.deletions,
+        web_url,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_highlight_snippet_escapes_html() {
+        let snippet = format!(
+            "<b>{}billing{} & more",
+            SNIPPET_MARK_START, SNIPPET_MARK_END
+        );
+
+        assert_eq!(
+            highlight_snippet(&snippet),
+            "&lt;b&gt;<mark>billing</mark> &amp; more"
+        );
+    }
+}
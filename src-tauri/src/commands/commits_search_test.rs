@@ -0,0 +1,160 @@
+//! コミット全文検索コマンドのテスト
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::commits_search::{search_commits_with_connection, SearchCommitsRequest};
+    use crate::error::AppError;
+    use crate::storage::sqlite::run_migrations;
+    use chrono::NaiveDate;
+    use rusqlite::Connection;
+
+    fn create_test_connection() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        run_migrations(&conn).unwrap();
+        conn.execute(
+            "INSERT INTO projects (project_id, name, path_with_namespace, web_url, last_sync_time_utc)
+             VALUES (1, 'billing', 'group/billing', 'https://gitlab.example.com/group/billing', '2026-02-01T00:00:00Z')",
+            [],
+        )
+        .unwrap();
+        conn.execute_batch(
+            "INSERT INTO commits (project_id, branch_name, sha, message, author_name, author_email, committed_date_utc, additions, deletions) VALUES
+             (1, 'main', 'a1', 'Start billing migration to new schema', 'alice', 'alice@example.com', '2026-01-10T09:00:00+00:00', 10, 2),
+             (1, 'main', 'a2', 'Finish billing data migration', 'bob', 'bob@example.com', '2026-02-20T09:00:00+00:00', 3, 1),
+             (1, 'dev', 'a3', 'Migration of billing: cleanup', 'alice', 'alice@example.com', '2025-11-01T00:00:00+00:00', 1, 0),
+             (1, 'main', 'a4', 'Fix login', 'carol', 'carol@example.com', '2026-01-15T09:00:00+00:00', 50, 40),
+             (1, 'main', 'a5', 'ログイン画面のバグを修正', 'dave', 'dave@example.com', '2026-01-20T09:00:00+00:00', 4, 4),
+             (1, 'main', 'a6', 'バグ修正: 請求データ移行の再実行に対応', 'dave', 'dave@example.com', '2026-01-25T09:00:00+00:00', 6, 1);",
+        )
+        .unwrap();
+        conn
+    }
+
+    fn request(query: &str) -> SearchCommitsRequest {
+        SearchCommitsRequest {
+            query: query.to_string(),
+            project_id: None,
+            branch_name: None,
+            author: None,
+            since: None,
+            until: None,
+            page: 1,
+            per_page: 50,
+        }
+    }
+
+    fn shas(conn: &Connection, req: SearchCommitsRequest) -> Vec<String> {
+        let mut shas: Vec<String> = search_commits_with_connection(conn, req)
+            .unwrap()
+            .items
+            .into_iter()
+            .map(|item| item.sha)
+            .collect();
+        shas.sort();
+        shas
+    }
+
+    #[test]
+    fn test_phrase_query_and_snippet() {
+        let conn = create_test_connection();
+
+        let result =
+            search_commits_with_connection(&conn, request("\"billing migration\"")).unwrap();
+
+        assert_eq!(result.total_count, 1);
+        assert_eq!(result.items[0].sha, "a1");
+        assert_eq!(
+            result.items[0].snippet,
+            "Start <mark>billing migration</mark> to new schema"
+        );
+        assert_eq!(
+            result.items[0].web_url.as_deref(),
+            Some("https://gitlab.example.com/group/billing/-/commit/a1")
+        );
+    }
+
+    #[test]
+    fn test_filters_by_author_branch_and_date() {
+        let conn = create_test_connection();
+
+        assert_eq!(
+            shas(&conn, request("billing migration")),
+            vec!["a1", "a2", "a3"]
+        );
+
+        let mut req = request("billing migration");
+        req.author = Some("ALICE@example.com".to_string());
+        assert_eq!(shas(&conn, req), vec!["a1", "a3"]);
+
+        let mut req = request("billing migration");
+        req.branch_name = Some("main".to_string());
+        req.since = NaiveDate::from_ymd_opt(2026, 1, 1);
+        req.until = NaiveDate::from_ymd_opt(2026, 2, 20);
+        assert_eq!(shas(&conn, req), vec!["a1", "a2"]);
+    }
+
+    #[test]
+    fn test_index_follows_updates_and_deletes() {
+        let conn = create_test_connection();
+
+        // 再収集（UPSERT）でメッセージが変わった場合
+        conn.execute(
+            "UPDATE commits SET message = 'Fix logout' WHERE sha = 'a4'",
+            [],
+        )
+        .unwrap();
+        assert!(shas(&conn, request("login")).is_empty());
+        assert_eq!(shas(&conn, request("logout")), vec!["a4"]);
+
+        // ブランチ単位の削除（delete_by_branch と同じ SQL）
+        conn.execute(
+            "DELETE FROM commits WHERE project_id = 1 AND branch_name = 'main'",
+            [],
+        )
+        .unwrap();
+        assert_eq!(shas(&conn, request("billing")), vec!["a3"]);
+    }
+
+    #[test]
+    fn test_japanese_message_search() {
+        let conn = create_test_connection();
+
+        // 空白で区切らない日本語も部分一致で検索できる
+        let result = search_commits_with_connection(&conn, request("ログイン")).unwrap();
+        assert_eq!(result.total_count, 1);
+        assert_eq!(result.items[0].sha, "a5");
+        assert_eq!(
+            result.items[0].snippet,
+            "<mark>ログイン</mark>画面のバグを修正"
+        );
+        assert_eq!(shas(&conn, request("バグ修正")), vec!["a6"]);
+        assert_eq!(shas(&conn, request("請求 再実行")), vec!["a6"]);
+
+        // 3 文字未満は部分一致（新しい順）で検索する
+        let result = search_commits_with_connection(&conn, request("修正")).unwrap();
+        assert_eq!(result.total_count, 2);
+        let shas: Vec<&str> = result.items.iter().map(|i| i.sha.as_str()).collect();
+        assert_eq!(shas, vec!["a6", "a5"]);
+        assert_eq!(
+            result.items[1].snippet,
+            "ログイン画面のバグを<mark>修正</mark>"
+        );
+
+        let mut req = request("バグ");
+        req.until = NaiveDate::from_ymd_opt(2026, 1, 20);
+        let result = search_commits_with_connection(&conn, req).unwrap();
+        assert_eq!(result.total_count, 1);
+        assert_eq!(result.items[0].sha, "a5");
+    }
+
+    #[test]
+    fn test_invalid_query_is_validation_error() {
+        let conn = create_test_connection();
+
+        let result = search_commits_with_connection(&conn, request("\"unterminated"));
+        assert!(matches!(result, Err(AppError::Validation(_))));
+
+        let result = search_commits_with_connection(&conn, request("   "));
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+}
@@ -0,0 +1,35 @@
+//! コマンド: データベースのバックアップ
+//!
+//! SQLite のオンラインバックアップ API で、使用中のデータベースを一貫した状態のまま複製する。
+
+use crate::error::AppResult;
+use crate::storage::{self, DatabaseBackup};
+use serde::Deserialize;
+use std::path::Path;
+use tracing::info;
+
+/// バックアップリクエスト
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupDatabaseRequest {
+    /// 出力先ファイルパス（省略時はアプリデータディレクトリの backups に作成）
+    #[serde(default)]
+    pub output_path: Option<String>,
+}
+
+/// データベースをバックアップする
+#[tauri::command]
+pub fn backup_database(request: BackupDatabaseRequest) -> Result<DatabaseBackup, String> {
+    backup_database_inner(request).map_err(|e| e.user_message())
+}
+
+pub fn backup_database_inner(request: BackupDatabaseRequest) -> AppResult<DatabaseBackup> {
+    let backup = storage::backup_database(request.output_path.as_deref().map(Path::new))?;
+
+    info!(
+        "データベースをバックアップしました: path={}, size={}",
+        backup.path, backup.size_bytes
+    );
+
+    Ok(backup)
+}
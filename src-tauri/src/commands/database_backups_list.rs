@@ -0,0 +1,17 @@
+//! コマンド: バックアップ一覧
+//!
+//! 手動バックアップと自動スナップショット（マイグレーション・ブランチ削除などの前に作成）を
+//! 新しい順に返す。
+
+use crate::error::AppResult;
+use crate::storage::{self, DatabaseBackup};
+
+/// バックアップ一覧を取得
+#[tauri::command]
+pub fn list_database_backups() -> Result<Vec<DatabaseBackup>, String> {
+    list_database_backups_inner().map_err(|e| e.user_message())
+}
+
+pub fn list_database_backups_inner() -> AppResult<Vec<DatabaseBackup>> {
+    storage::list_backups()
+}
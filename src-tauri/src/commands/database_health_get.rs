@@ -0,0 +1,18 @@
+//! コマンド: データベース健全性レポート
+//!
+//! ファイルサイズ（WAL を含む）、テーブルごとの行数、インテグリティチェックの結果、
+//! 最後に VACUUM / ANALYZE した日時を返す。
+
+use crate::error::AppResult;
+use crate::storage;
+use crate::storage::sqlite::DatabaseHealth;
+
+/// データベースの健全性レポートを取得
+#[tauri::command]
+pub fn get_database_health() -> Result<DatabaseHealth, String> {
+    get_database_health_inner().map_err(|e| e.user_message())
+}
+
+pub fn get_database_health_inner() -> AppResult<DatabaseHealth> {
+    storage::get_database_health()
+}
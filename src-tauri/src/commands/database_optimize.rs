@@ -0,0 +1,34 @@
+//! コマンド: データベース最適化
+//!
+//! VACUUM（空き領域の回収）と ANALYZE（クエリプランナー用の統計更新）を実行する。
+//! 一括収集の実行中は実行できない。
+
+use crate::error::AppResult;
+use crate::storage::{self, DatabaseOptimizeResult};
+use serde::Deserialize;
+
+/// 最適化リクエスト
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptimizeDatabaseRequest {
+    /// VACUUM を実行する
+    #[serde(default)]
+    pub vacuum: bool,
+    /// ANALYZE を実行する
+    #[serde(default)]
+    pub analyze: bool,
+}
+
+/// データベースを最適化
+#[tauri::command]
+pub fn optimize_database(
+    request: OptimizeDatabaseRequest,
+) -> Result<DatabaseOptimizeResult, String> {
+    optimize_database_inner(request).map_err(|e| e.user_message())
+}
+
+pub fn optimize_database_inner(
+    request: OptimizeDatabaseRequest,
+) -> AppResult<DatabaseOptimizeResult> {
+    storage::optimize_database(request.vacuum, request.analyze)
+}
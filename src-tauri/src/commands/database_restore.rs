@@ -0,0 +1,45 @@
+//! コマンド: データベースの復元
+//!
+//! バックアップ/スナップショットの内容でデータベースを置き換える。
+//! 古いスキーマのバックアップは復元後にマイグレーションし、新しいスキーマのバックアップは復元しない。
+//! 復元前の状態は自動スナップショットとして残す。
+
+use crate::error::AppResult;
+use crate::storage::{self, DatabaseBackup};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// 復元リクエスト
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreDatabaseRequest {
+    /// 復元元のバックアップファイルのパス
+    pub path: String,
+}
+
+/// 復元結果
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreDatabaseResult {
+    /// 復元元のバックアップ
+    pub restored_from: DatabaseBackup,
+    /// 復元前に作成したスナップショット
+    pub snapshot: DatabaseBackup,
+}
+
+/// バックアップからデータベースを復元する
+#[tauri::command]
+pub fn restore_database(request: RestoreDatabaseRequest) -> Result<RestoreDatabaseResult, String> {
+    restore_database_inner(request).map_err(|e| e.user_message())
+}
+
+pub fn restore_database_inner(request: RestoreDatabaseRequest) -> AppResult<RestoreDatabaseResult> {
+    let path = Path::new(&request.path);
+    let restored_from = storage::describe_backup(path)?;
+    let snapshot = storage::restore_database(path)?;
+
+    Ok(RestoreDatabaseResult {
+        restored_from,
+        snapshot,
+    })
+}
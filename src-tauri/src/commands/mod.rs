@@ -6,6 +6,8 @@
 // US1: GitLab 接続設定
 pub mod gitlab_connection_get;
 pub mod gitlab_connection_set;
+pub mod project_tags_list;
+pub mod project_tags_set;
 pub mod projects_list;
 pub mod projects_sync;
 
@@ -13,10 +15,20 @@ pub mod projects_sync;
 pub mod branches_list;
 pub mod commits_collect;
 pub mod commits_collect_bulk;
+pub mod commits_detail_list;
+pub mod commits_import_local;
+pub mod commits_repair_stats;
+pub mod commits_search;
 
 // US3: 月次集計
+pub mod stats_bus_factor;
+pub mod stats_compare;
+pub mod stats_export;
+pub mod stats_heatmap;
+pub mod stats_lifecycle;
 pub mod stats_monthly_cross_view;
 pub mod stats_monthly_project_view;
+pub mod stats_size_distribution;
 
 // ブランチ削除
 pub mod commits_branch_delete;
@@ -26,20 +38,110 @@ pub mod commits_branch_delete_impact;
 pub mod user_filter_get;
 pub mod user_filter_set;
 
+// コミット分類
+pub mod category_rules_get;
+pub mod category_rules_set;
+
+// ボット判定
+pub mod bot_config_get;
+pub mod bot_config_set;
+pub mod bot_users_sync;
+
+// チーム
+pub mod team_create;
+pub mod team_delete;
+pub mod team_update;
+pub mod teams_list;
+
+// 集計レポート設定
+pub mod reporting_settings_get;
+pub mod reporting_settings_set;
+
+// 稼働日カレンダー
+pub mod work_calendar_get;
+pub mod work_calendar_import_holidays;
+pub mod work_calendar_set;
+
+// アラート
+pub mod alert_rules_get;
+pub mod alert_rules_set;
+pub mod alerts_acknowledge;
+pub mod alerts_evaluate;
+pub mod alerts_list;
+
+// データ移行
+pub mod archive_export;
+pub mod archive_import;
+
+// バックアップ
+pub mod database_backup;
+pub mod database_backups_list;
+pub mod database_restore;
+
+// データベース保守
+pub mod commits_purge_old;
+pub mod database_health_get;
+pub mod database_optimize;
+
+#[cfg(test)]
+mod bot_users_sync_test;
 #[cfg(test)]
 mod commits_collect_bulk_test;
+#[cfg(test)]
+mod commits_detail_list_test;
+#[cfg(test)]
+mod commits_search_test;
 
 // Re-export for convenience
+pub use alert_rules_get::*;
+pub use alert_rules_set::*;
+pub use alerts_acknowledge::*;
+pub use alerts_evaluate::*;
+pub use alerts_list::*;
+pub use archive_export::*;
+pub use archive_import::*;
+pub use bot_config_get::*;
+pub use bot_config_set::*;
+pub use bot_users_sync::*;
 pub use branches_list::*;
+pub use category_rules_get::*;
+pub use category_rules_set::*;
 pub use commits_branch_delete::*;
 pub use commits_branch_delete_impact::*;
 pub use commits_collect::*;
 pub use commits_collect_bulk::*;
+pub use commits_detail_list::*;
+pub use commits_import_local::*;
+pub use commits_purge_old::*;
+pub use commits_repair_stats::*;
+pub use commits_search::*;
+pub use database_backup::*;
+pub use database_backups_list::*;
+pub use database_health_get::*;
+pub use database_optimize::*;
+pub use database_restore::*;
 pub use gitlab_connection_get::*;
 pub use gitlab_connection_set::*;
+pub use project_tags_list::*;
+pub use project_tags_set::*;
 pub use projects_list::*;
 pub use projects_sync::*;
+pub use reporting_settings_get::*;
+pub use reporting_settings_set::*;
+pub use stats_bus_factor::*;
+pub use stats_compare::*;
+pub use stats_export::*;
+pub use stats_heatmap::*;
+pub use stats_lifecycle::*;
 pub use stats_monthly_cross_view::*;
 pub use stats_monthly_project_view::*;
+pub use stats_size_distribution::*;
+pub use team_create::*;
+pub use team_delete::*;
+pub use team_update::*;
+pub use teams_list::*;
 pub use user_filter_get::*;
 pub use user_filter_set::*;
+pub use work_calendar_get::*;
+pub use work_calendar_import_holidays::*;
+pub use work_calendar_set::*;
@@ -0,0 +1,17 @@
+//! コマンド: プロジェクトタグ一覧取得
+//!
+//! 全プロジェクトのタグ（手動 + 名前空間/トピックの自動取り込み）を返す。
+
+use crate::domain::ProjectTags;
+use crate::error::AppResult;
+use crate::storage;
+
+/// プロジェクトタグ一覧を取得
+#[tauri::command]
+pub fn list_project_tags() -> Result<Vec<ProjectTags>, String> {
+    list_project_tags_inner().map_err(|e| e.user_message())
+}
+
+pub fn list_project_tags_inner() -> AppResult<Vec<ProjectTags>> {
+    storage::list_project_tags()
+}
@@ -0,0 +1,42 @@
+//! コマンド: プロジェクトタグ保存
+//!
+//! プロジェクトの手動タグを置き換える（自動取り込みのタグは同期時に更新される）。
+
+use crate::domain::ProjectTag;
+use crate::error::{AppError, AppResult};
+use crate::storage::{self, ProjectRepository};
+use serde::Deserialize;
+use tracing::info;
+
+/// プロジェクトタグ保存リクエスト
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetProjectTagsRequest {
+    /// プロジェクト ID
+    pub project_id: i64,
+    /// 手動タグ（空配列で全削除）
+    pub tags: Vec<String>,
+}
+
+/// プロジェクトの手動タグを保存
+#[tauri::command]
+pub fn set_project_tags(request: SetProjectTagsRequest) -> Result<Vec<ProjectTag>, String> {
+    set_project_tags_inner(request).map_err(|e| e.user_message())
+}
+
+pub fn set_project_tags_inner(request: SetProjectTagsRequest) -> AppResult<Vec<ProjectTag>> {
+    info!(
+        "プロジェクトタグ保存: project_id={}, tags={}",
+        request.project_id,
+        request.tags.len()
+    );
+
+    if ProjectRepository::find_by_id(request.project_id)?.is_none() {
+        return Err(AppError::Validation(format!(
+            "プロジェクトが見つかりません: id={}",
+            request.project_id
+        )));
+    }
+
+    storage::set_manual_project_tags(request.project_id, &request.tags)
+}
@@ -12,6 +12,6 @@ pub fn get_projects() -> Result<Vec<Project>, String> {
     get_projects_inner().map_err(|e| e.to_string())
 }
 
-fn get_projects_inner() -> AppResult<Vec<Project>> {
+pub fn get_projects_inner() -> AppResult<Vec<Project>> {
     ProjectRepository::find_all()
 }
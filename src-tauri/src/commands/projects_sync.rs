@@ -1,11 +1,12 @@
 //! コマンド: プロジェクト同期
 //!
 //! GitLab からアクセス可能なプロジェクト一覧を取得し、ローカルに保存する。
+//! 名前空間とトピックはプロジェクトタグとして自動で取り込む（手動タグは保持）。
 
-use crate::domain::Project;
+use crate::domain::{auto_project_tags, Project, ProjectTags};
 use crate::error::{AppError, AppResult};
 use crate::gitlab::GitLabClient;
-use crate::storage::{ConnectionRepository, ProjectRepository};
+use crate::storage::{self, ConnectionRepository, ProjectRepository};
 use tracing::info;
 
 /// プロジェクトを同期
@@ -14,7 +15,7 @@ pub async fn sync_projects() -> Result<Vec<Project>, String> {
     sync_projects_inner().await.map_err(|e| e.user_message())
 }
 
-async fn sync_projects_inner() -> AppResult<Vec<Project>> {
+pub async fn sync_projects_inner() -> AppResult<Vec<Project>> {
     // 接続設定を取得
     let connection = ConnectionRepository::get()?.ok_or(AppError::ConnectionNotConfigured)?;
 
@@ -24,6 +25,15 @@ async fn sync_projects_inner() -> AppResult<Vec<Project>> {
     let client = GitLabClient::new(&connection.base_url, &connection.access_token)?;
     let gitlab_projects = client.list_projects().await?;
 
+    // 名前空間/トピックをタグとして取り込む
+    let auto_tags: Vec<ProjectTags> = gitlab_projects
+        .iter()
+        .map(|p| ProjectTags {
+            project_id: p.id,
+            tags: auto_project_tags(p),
+        })
+        .collect();
+
     // ドメインモデルに変換
     let projects: Vec<Project> = gitlab_projects.into_iter().map(Project::from).collect();
 
@@ -31,6 +41,7 @@ async fn sync_projects_inner() -> AppResult<Vec<Project>> {
 
     // ローカルに保存
     ProjectRepository::replace_all(projects.clone())?;
+    storage::replace_auto_project_tags(&auto_tags)?;
 
     Ok(projects)
 }
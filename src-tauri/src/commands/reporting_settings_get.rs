@@ -0,0 +1,17 @@
+//! コマンド: 集計レポート設定取得
+//!
+//! 曜日・時間帯の集計に使うタイムゾーンなどを返す。
+
+use crate::domain::ReportingSettings;
+use crate::error::AppResult;
+use crate::storage;
+
+/// 集計レポート設定を取得
+#[tauri::command]
+pub fn get_reporting_settings() -> Result<ReportingSettings, String> {
+    get_reporting_settings_inner().map_err(|e| e.user_message())
+}
+
+pub fn get_reporting_settings_inner() -> AppResult<ReportingSettings> {
+    storage::get_reporting_settings()
+}
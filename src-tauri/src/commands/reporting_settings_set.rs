@@ -0,0 +1,32 @@
+//! コマンド: 集計レポート設定保存
+//!
+//! タイムゾーンを検証して保存する。
+
+use crate::domain::ReportingSettings;
+use crate::error::AppResult;
+use crate::storage;
+use serde::Deserialize;
+use tracing::info;
+
+/// 集計レポート設定保存リクエスト
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetReportingSettingsRequest {
+    /// 集計レポート設定
+    pub settings: ReportingSettings,
+}
+
+/// 集計レポート設定を保存
+#[tauri::command]
+pub fn set_reporting_settings(request: SetReportingSettingsRequest) -> Result<(), String> {
+    set_reporting_settings_inner(request).map_err(|e| e.user_message())
+}
+
+pub fn set_reporting_settings_inner(request: SetReportingSettingsRequest) -> AppResult<()> {
+    info!(
+        "集計レポート設定保存: time_zone={}, date_basis={:?}",
+        request.settings.time_zone, request.settings.date_basis
+    );
+
+    storage::save_reporting_settings(&request.settings)
+}
@@ -0,0 +1,126 @@
+//! コマンド: バスファクター
+//!
+//! 直近の期間について、プロジェクトごとの作者シェアと知識の集中度を返す。
+
+use crate::commands::stats_monthly_cross_view::filter_commits_by_projects;
+use crate::commands::stats_monthly_project_view::resolve_date_basis;
+use crate::domain::{CommitDateBasis, ProjectFilter};
+use crate::error::{AppError, AppResult};
+use crate::stats::{
+    knowledge_concentration, AggregateOptions, ConcentrationOptions, DateWindow,
+    KnowledgeConcentrationReport,
+};
+use crate::storage::{CommitRepository, ProjectRepository};
+use chrono::NaiveDate;
+use serde::Deserialize;
+use tracing::info;
+
+/// バスファクター集計リクエスト
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BusFactorRequest {
+    /// 集計期間の月数（既定: 12）
+    #[serde(default = "default_window_months")]
+    pub window_months: u32,
+    /// 集計期間の終了日（UTC、この日を含む。省略時は今日）
+    pub until: Option<NaiveDate>,
+    /// 対象カテゴリ（空の場合は全カテゴリ）
+    #[serde(default)]
+    pub categories: Vec<String>,
+    /// ボットを除外するか（既定: 除外）
+    #[serde(default = "default_exclude_bots")]
+    pub exclude_bots: bool,
+    /// 対象プロジェクトの絞り込み（省略時は全プロジェクト）
+    #[serde(default)]
+    pub projects: ProjectFilter,
+    /// 「1 人に偏っている」と判定するシェアの閾値（0.0-1.0、既定: 0.7）
+    #[serde(default = "default_dominance_threshold")]
+    pub dominance_threshold: f64,
+    /// 出力する上位作者の人数（既定: 5）
+    #[serde(default = "default_top_contributors")]
+    pub top_contributors: usize,
+    /// 期間に振り分ける日時（省略時は集計レポート設定に従う）
+    #[serde(default)]
+    pub date_basis: Option<CommitDateBasis>,
+}
+
+fn default_window_months() -> u32 {
+    12
+}
+
+fn default_exclude_bots() -> bool {
+    true
+}
+
+fn default_dominance_threshold() -> f64 {
+    0.7
+}
+
+fn default_top_contributors() -> usize {
+    5
+}
+
+/// プロジェクトごとのバスファクターを取得
+#[tauri::command]
+pub fn get_bus_factor_report(
+    request: BusFactorRequest,
+) -> Result<KnowledgeConcentrationReport, String> {
+    get_bus_factor_report_inner(request).map_err(|e| e.user_message())
+}
+
+pub fn get_bus_factor_report_inner(
+    request: BusFactorRequest,
+) -> AppResult<KnowledgeConcentrationReport> {
+    if !(1..=120).contains(&request.window_months) {
+        return Err(AppError::Validation(format!(
+            "集計期間は 1〜120 ヶ月で指定してください: {}",
+            request.window_months
+        )));
+    }
+    if !(request.dominance_threshold > 0.0 && request.dominance_threshold <= 1.0) {
+        return Err(AppError::Validation(format!(
+            "偏りの閾値は 0 より大きく 1 以下で指定してください: {}",
+            request.dominance_threshold
+        )));
+    }
+
+    let until = request
+        .until
+        .unwrap_or_else(|| chrono::Utc::now().date_naive());
+    let window = DateWindow::months_until(until, request.window_months);
+
+    info!(
+        "バスファクター集計: from={}, to={}, dominance_threshold={}",
+        window.from, window.to, request.dominance_threshold
+    );
+
+    let end = window.to.succ_opt().unwrap_or(window.to);
+    let commits = CommitRepository::find_by_date_range(
+        window.from,
+        end,
+        resolve_date_basis(request.date_basis)?,
+    )?;
+    let commits = filter_commits_by_projects(commits, &request.projects)?;
+
+    info!("集計対象コミット数: {}", commits.len());
+
+    let options = AggregateOptions {
+        categories: request.categories.clone(),
+        exclude_bots: request.exclude_bots,
+        projects: ProjectRepository::find_all()?
+            .into_iter()
+            .map(|p| (p.project_id, p))
+            .collect(),
+        ..Default::default()
+    };
+
+    Ok(knowledge_concentration(
+        &commits,
+        &options,
+        ConcentrationOptions {
+            window,
+            dominance_threshold: request.dominance_threshold,
+            top_contributors: request.top_contributors,
+        },
+    ))
+}
@@ -0,0 +1,114 @@
+//! コマンド: 期間比較
+//!
+//! プロジェクトビュー/横断ビューの集計を 2 つの期間で比較し、系列ごとと全体の差分・変化率を返す。
+
+use crate::commands::stats_monthly_cross_view::find_view_commits;
+use crate::commands::stats_monthly_project_view::{resolve_aggregate_options, resolve_date_basis};
+use crate::domain::{Commit, CommitDateBasis, ProjectFilter};
+use crate::error::AppResult;
+use crate::stats::{
+    compare_periods, handle_outliers, AggregateOptions, GroupBy, Metric, OutlierHandling,
+    StatsComparison, StatsPeriod,
+};
+use serde::Deserialize;
+use std::collections::BTreeSet;
+use tracing::info;
+
+/// 期間比較リクエスト
+///
+/// `projectId` と `branchName` を指定した場合はプロジェクトビュー、どちらも省略した場合は横断ビューを比較する。
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompareStatsRequest {
+    /// 比較元の期間
+    pub base: StatsPeriod,
+    /// 比較先の期間
+    pub target: StatsPeriod,
+    /// プロジェクト ID（プロジェクトビューのみ）
+    pub project_id: Option<i64>,
+    /// ブランチ名（プロジェクトビューのみ）
+    pub branch_name: Option<String>,
+    /// フィルタするユーザーキー
+    #[serde(default)]
+    pub user_keys: Vec<String>,
+    /// 対象カテゴリ（空の場合は全カテゴリ）
+    #[serde(default)]
+    pub categories: Vec<String>,
+    /// ボットを除外するか（既定: 除外）
+    #[serde(default = "default_exclude_bots")]
+    pub exclude_bots: bool,
+    /// 集計単位（user / team / project）
+    #[serde(default)]
+    pub group_by: GroupBy,
+    /// 比較する指標（既定: totalLines）
+    #[serde(default)]
+    pub metric: Metric,
+    /// 対象プロジェクトの絞り込み（横断ビューのみ）
+    #[serde(default)]
+    pub projects: ProjectFilter,
+    /// 外れ値コミットの除外/頭打ち（省略時はそのまま集計）
+    #[serde(default)]
+    pub outliers: Option<OutlierHandling>,
+    /// 期間に振り分ける日時（省略時は集計レポート設定に従う）
+    #[serde(default)]
+    pub date_basis: Option<CommitDateBasis>,
+}
+
+fn default_exclude_bots() -> bool {
+    true
+}
+
+/// 2 つの期間の集計を比較
+#[tauri::command]
+pub fn compare_stats(request: CompareStatsRequest) -> Result<StatsComparison, String> {
+    compare_stats_inner(request).map_err(|e| e.user_message())
+}
+
+pub fn compare_stats_inner(request: CompareStatsRequest) -> AppResult<StatsComparison> {
+    info!(
+        "期間比較: base={:?}, target={:?}, project_id={:?}, metric={:?}",
+        request.base, request.target, request.project_id, request.metric
+    );
+
+    request.base.validate()?;
+    request.target.validate()?;
+
+    // 両期間の年のコミットを取得
+    let date_basis = resolve_date_basis(request.date_basis)?;
+    let years: BTreeSet<i32> = [request.base.year, request.target.year].into();
+    let mut commits: Vec<Commit> = Vec::new();
+    for year in years {
+        commits.extend(find_view_commits(
+            request.project_id,
+            request.branch_name.as_deref(),
+            &request.projects,
+            year,
+            date_basis,
+        )?);
+    }
+
+    info!("比較対象コミット数: {}", commits.len());
+
+    let options = resolve_aggregate_options(AggregateOptions {
+        categories: request.categories.clone(),
+        exclude_bots: request.exclude_bots,
+        group_by: request.group_by,
+        metric: request.metric,
+        ..Default::default()
+    })?;
+
+    let (commits, outliers) =
+        handle_outliers(commits, &request.user_keys, &options, request.outliers)?;
+    let comparison = compare_periods(
+        &commits,
+        &request.user_keys,
+        &options,
+        request.base,
+        request.target,
+    )?;
+
+    Ok(StatsComparison {
+        outliers,
+        ..comparison
+    })
+}
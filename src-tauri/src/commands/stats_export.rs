@@ -0,0 +1,207 @@
+//! コマンド: 月次集計エクスポート
+//!
+//! プロジェクトビュー/横断ビューの集計結果（と集計元コミット）をファイルに書き出す。
+
+use crate::commands::stats_monthly_cross_view::filter_commits_by_projects;
+use crate::commands::stats_monthly_project_view::{
+    find_project_view_commits, resolve_aggregate_options, resolve_date_basis,
+};
+use crate::domain::{Commit, CommitDateBasis, ProjectFilter};
+use crate::error::{AppError, AppResult};
+use crate::export::{
+    build_commit_table, build_summary_table, write_tables, CommitColumn, ExportFormat,
+    IdentityMode, SummaryColumn,
+};
+use crate::stats::{
+    aggregate_cross_view, aggregate_project_view, handle_outliers, AggregateOptions, GroupBy,
+    Metric, OutlierHandling, OutlierReport,
+};
+use crate::storage::CommitRepository;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use tracing::info;
+
+/// エクスポート対象のビュー
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum ExportView {
+    /// プロジェクトビュー
+    #[serde(rename = "project-view")]
+    ProjectView,
+    /// 横断ビュー
+    #[serde(rename = "cross-view")]
+    CrossView,
+}
+
+/// エクスポートリクエスト
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportStatsRequest {
+    /// 対象ビュー
+    pub view: ExportView,
+    /// プロジェクト ID（プロジェクトビューのみ）
+    pub project_id: Option<i64>,
+    /// ブランチ名（プロジェクトビューのみ）
+    pub branch_name: Option<String>,
+    /// 対象年
+    pub year: i32,
+    /// フィルタするユーザーキー（空の場合は全ユーザー）
+    #[serde(default)]
+    pub user_keys: Vec<String>,
+    /// 対象カテゴリ（空の場合は全カテゴリ）
+    #[serde(default)]
+    pub categories: Vec<String>,
+    /// ボットを除外するか（既定: 除外）
+    #[serde(default = "default_exclude_bots")]
+    pub exclude_bots: bool,
+    /// 集計単位（user / team / project）
+    #[serde(default)]
+    pub group_by: GroupBy,
+    /// 集計テーブルに出力する指標（既定: totalLines）
+    #[serde(default)]
+    pub metric: Metric,
+    /// 対象プロジェクトの絞り込み（横断ビューのみ）
+    #[serde(default)]
+    pub projects: ProjectFilter,
+    /// 外れ値コミットの除外/頭打ち（省略時はそのまま集計）
+    #[serde(default)]
+    pub outliers: Option<OutlierHandling>,
+    /// 出力形式
+    pub format: ExportFormat,
+    /// 出力先ファイルパス
+    pub output_path: String,
+    /// 集計テーブルの出力列（空の場合は全列）
+    #[serde(default)]
+    pub summary_columns: Vec<SummaryColumn>,
+    /// 集計元コミットも出力するか
+    #[serde(default)]
+    pub include_commits: bool,
+    /// コミットテーブルの出力列（空の場合は全列）
+    #[serde(default)]
+    pub commit_columns: Vec<CommitColumn>,
+    /// 作者識別子の出力方法（既定はマスク）
+    #[serde(default)]
+    pub identity: IdentityMode,
+    /// 期間に振り分ける日時（省略時は集計レポート設定に従う）
+    #[serde(default)]
+    pub date_basis: Option<CommitDateBasis>,
+}
+
+fn default_exclude_bots() -> bool {
+    true
+}
+
+/// エクスポート結果
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportStatsResult {
+    /// 作成したファイル
+    pub files: Vec<String>,
+    /// 集計テーブルの行数
+    pub summary_row_count: usize,
+    /// コミットテーブルの行数（出力しない場合は 0）
+    pub commit_row_count: usize,
+    /// 除外/頭打ちした外れ値コミット（outliers 指定時のみ）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outliers: Option<OutlierReport>,
+}
+
+/// 月次集計をエクスポート
+#[tauri::command]
+pub fn export_stats(request: ExportStatsRequest) -> Result<ExportStatsResult, String> {
+    export_stats_inner(request).map_err(|e| e.user_message())
+}
+
+pub fn export_stats_inner(request: ExportStatsRequest) -> AppResult<ExportStatsResult> {
+    info!(
+        "エクスポート開始: view={:?}, year={}, format={:?}, include_commits={}",
+        request.view, request.year, request.format, request.include_commits
+    );
+
+    let commits = find_export_commits(&request)?;
+    let options = resolve_aggregate_options(AggregateOptions {
+        categories: request.categories.clone(),
+        exclude_bots: request.exclude_bots,
+        group_by: request.group_by,
+        metric: request.metric,
+        year: request.year,
+        ..Default::default()
+    })?;
+    let (commits, outliers) =
+        handle_outliers(commits, &request.user_keys, &options, request.outliers)?;
+    if let Some(report) = &outliers {
+        info!(
+            "外れ値コミット: mode={:?}, threshold={:?}, count={}",
+            report.mode,
+            report.threshold,
+            report.commits.len()
+        );
+    }
+    let response = match request.view {
+        ExportView::ProjectView => aggregate_project_view(&commits, &request.user_keys, &options),
+        ExportView::CrossView => aggregate_cross_view(&commits, &request.user_keys, &options),
+    };
+
+    let mut tables = vec![build_summary_table(
+        &response,
+        &request.summary_columns,
+        request.identity,
+    )];
+
+    if request.include_commits {
+        // 集計と同じユーザー/カテゴリフィルタを適用
+        let filtered: Vec<Commit> = commits
+            .into_iter()
+            .filter(|c| request.user_keys.is_empty() || request.user_keys.contains(&c.user_key()))
+            .filter(|c| options.includes(c))
+            .filter(|c| !(options.exclude_bots && c.is_bot))
+            .collect();
+        tables.push(build_commit_table(
+            &filtered,
+            &request.commit_columns,
+            request.identity,
+        ));
+    }
+
+    let files = write_tables(&tables, request.format, Path::new(&request.output_path))?;
+
+    let result = ExportStatsResult {
+        files: files
+            .iter()
+            .map(|p| p.to_string_lossy().into_owned())
+            .collect(),
+        summary_row_count: tables[0].rows.len(),
+        commit_row_count: tables.get(1).map(|t| t.rows.len()).unwrap_or(0),
+        outliers,
+    };
+
+    info!(
+        "エクスポート完了: files={}, summary_rows={}, commit_rows={}",
+        result.files.len(),
+        result.summary_row_count,
+        result.commit_row_count
+    );
+
+    Ok(result)
+}
+
+/// ビューに応じた集計対象コミットを取得
+fn find_export_commits(request: &ExportStatsRequest) -> AppResult<Vec<Commit>> {
+    let date_basis = resolve_date_basis(request.date_basis)?;
+    match request.view {
+        ExportView::ProjectView => {
+            let (Some(project_id), Some(branch_name)) =
+                (request.project_id, request.branch_name.as_deref())
+            else {
+                return Err(AppError::Validation(
+                    "プロジェクトビューのエクスポートにはプロジェクトとブランチの指定が必要です"
+                        .to_string(),
+                ));
+            };
+            find_project_view_commits(project_id, branch_name, request.year, date_basis)
+        }
+        ExportView::CrossView => filter_commits_by_projects(
+            CommitRepository::find_by_year(request.year, date_basis)?,
+            &request.projects,
+        ),
+    }
+}
@@ -0,0 +1,92 @@
+//! コマンド: 曜日×時間帯ヒートマップ
+//!
+//! プロジェクトビュー/横断ビューのコミットを、集計用タイムゾーンの曜日×時で集計して返す。
+
+use crate::commands::stats_monthly_cross_view::find_view_commits;
+use crate::commands::stats_monthly_project_view::{resolve_aggregate_options, resolve_date_basis};
+use crate::domain::{parse_time_zone, CommitDateBasis, ProjectFilter};
+use crate::error::AppResult;
+use crate::stats::{aggregate_heatmap, AggregateOptions, GroupBy, HeatmapResponse};
+use crate::storage;
+use serde::Deserialize;
+use tracing::info;
+
+/// ヒートマップ集計リクエスト
+///
+/// `projectId` と `branchName` を指定した場合はプロジェクトビュー、どちらも省略した場合は横断ビューを集計する。
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeatmapRequest {
+    /// 対象年（UTC 基準で絞り込み）
+    pub year: i32,
+    /// プロジェクト ID（プロジェクトビューのみ）
+    pub project_id: Option<i64>,
+    /// ブランチ名（プロジェクトビューのみ）
+    pub branch_name: Option<String>,
+    /// フィルタするユーザーキー
+    #[serde(default)]
+    pub user_keys: Vec<String>,
+    /// 対象カテゴリ（空の場合は全カテゴリ）
+    #[serde(default)]
+    pub categories: Vec<String>,
+    /// ボットを除外するか（既定: 除外）
+    #[serde(default = "default_exclude_bots")]
+    pub exclude_bots: bool,
+    /// 集計単位（user / team / project）
+    #[serde(default)]
+    pub group_by: GroupBy,
+    /// 対象プロジェクトの絞り込み（横断ビューのみ）
+    #[serde(default)]
+    pub projects: ProjectFilter,
+    /// タイムゾーン（IANA 名、省略時は集計レポート設定のタイムゾーン）
+    pub time_zone: Option<String>,
+    /// 期間に振り分ける日時（省略時は集計レポート設定に従う）
+    #[serde(default)]
+    pub date_basis: Option<CommitDateBasis>,
+}
+
+fn default_exclude_bots() -> bool {
+    true
+}
+
+/// 曜日×時間帯のヒートマップを取得
+#[tauri::command]
+pub fn get_contribution_heatmap(request: HeatmapRequest) -> Result<HeatmapResponse, String> {
+    get_contribution_heatmap_inner(request).map_err(|e| e.user_message())
+}
+
+pub fn get_contribution_heatmap_inner(request: HeatmapRequest) -> AppResult<HeatmapResponse> {
+    info!(
+        "ヒートマップ集計: year={}, project_id={:?}, group_by={:?}",
+        request.year, request.project_id, request.group_by
+    );
+
+    let tz = match &request.time_zone {
+        Some(name) => parse_time_zone(name)?,
+        None => storage::get_reporting_settings()?.tz()?,
+    };
+
+    let commits = find_view_commits(
+        request.project_id,
+        request.branch_name.as_deref(),
+        &request.projects,
+        request.year,
+        resolve_date_basis(request.date_basis)?,
+    )?;
+
+    info!("集計対象コミット数: {} (time_zone={})", commits.len(), tz);
+
+    let options = resolve_aggregate_options(AggregateOptions {
+        categories: request.categories.clone(),
+        exclude_bots: request.exclude_bots,
+        group_by: request.group_by,
+        ..Default::default()
+    })?;
+
+    Ok(aggregate_heatmap(
+        &commits,
+        &request.user_keys,
+        &options,
+        tz,
+    ))
+}
@@ -0,0 +1,111 @@
+//! コマンド: コントリビューターのライフサイクル
+//!
+//! 作者ごとの最初/最後のコミット日と、月ごとの新規参加者・離脱者を
+//! プロジェクトごとと全プロジェクト横断で返す（オンボーディング/オフボーディングの確認用）。
+
+use crate::commands::stats_monthly_cross_view::filter_commits_by_projects;
+use crate::commands::stats_monthly_project_view::resolve_date_basis;
+use crate::domain::{CommitDateBasis, ProjectFilter};
+use crate::error::{AppError, AppResult};
+use crate::stats::{
+    contributor_lifecycle, AggregateOptions, ContributorLifecycleReport, DateWindow,
+};
+use crate::storage::{CommitRepository, ProjectRepository};
+use chrono::NaiveDate;
+use serde::Deserialize;
+use tracing::info;
+
+/// ライフサイクル集計リクエスト
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContributorLifecycleRequest {
+    /// 参加/離脱を集計する月数（既定: 12）
+    #[serde(default = "default_window_months")]
+    pub window_months: u32,
+    /// 基準日（UTC、この日を含む。省略時は今日）
+    pub until: Option<NaiveDate>,
+    /// 離脱扱いにするコミットのない月数（既定: 3）
+    #[serde(default = "default_inactive_months")]
+    pub inactive_months: u32,
+    /// ボットを除外するか（既定: 除外）
+    #[serde(default = "default_exclude_bots")]
+    pub exclude_bots: bool,
+    /// 対象プロジェクトの絞り込み（省略時は全プロジェクト）
+    #[serde(default)]
+    pub projects: ProjectFilter,
+    /// 期間に振り分ける日時（省略時は集計レポート設定に従う）
+    #[serde(default)]
+    pub date_basis: Option<CommitDateBasis>,
+}
+
+fn default_window_months() -> u32 {
+    12
+}
+
+fn default_inactive_months() -> u32 {
+    3
+}
+
+fn default_exclude_bots() -> bool {
+    true
+}
+
+/// コントリビューターのライフサイクルを取得
+#[tauri::command]
+pub fn get_contributor_lifecycle(
+    request: ContributorLifecycleRequest,
+) -> Result<ContributorLifecycleReport, String> {
+    get_contributor_lifecycle_inner(request).map_err(|e| e.user_message())
+}
+
+pub fn get_contributor_lifecycle_inner(
+    request: ContributorLifecycleRequest,
+) -> AppResult<ContributorLifecycleReport> {
+    if !(1..=120).contains(&request.window_months) {
+        return Err(AppError::Validation(format!(
+            "集計期間は 1〜120 ヶ月で指定してください: {}",
+            request.window_months
+        )));
+    }
+    if !(1..=120).contains(&request.inactive_months) {
+        return Err(AppError::Validation(format!(
+            "離脱扱いにする月数は 1〜120 ヶ月で指定してください: {}",
+            request.inactive_months
+        )));
+    }
+
+    let until = request
+        .until
+        .unwrap_or_else(|| chrono::Utc::now().date_naive());
+    let window = DateWindow::months_until(until, request.window_months);
+
+    info!(
+        "ライフサイクル集計: from={}, to={}, inactive_months={}",
+        window.from, window.to, request.inactive_months
+    );
+
+    // 最初のコミットを判定するため、基準日までの全期間を対象にする
+    let start = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap_or(NaiveDate::MIN);
+    let end = until.succ_opt().unwrap_or(until);
+    let commits =
+        CommitRepository::find_by_date_range(start, end, resolve_date_basis(request.date_basis)?)?;
+    let commits = filter_commits_by_projects(commits, &request.projects)?;
+
+    info!("集計対象コミット数: {}", commits.len());
+
+    let options = AggregateOptions {
+        exclude_bots: request.exclude_bots,
+        projects: ProjectRepository::find_all()?
+            .into_iter()
+            .map(|p| (p.project_id, p))
+            .collect(),
+        ..Default::default()
+    };
+
+    Ok(contributor_lifecycle(
+        &commits,
+        &options,
+        window,
+        request.inactive_months,
+    ))
+}
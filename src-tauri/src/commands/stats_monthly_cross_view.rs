@@ -1,11 +1,19 @@
 //! コマンド: 横断ビュー月次集計
 //!
-//! 全プロジェクト横断の月次集計を返す。
+//! 全プロジェクト（またはタグ/名前空間で絞り込んだプロジェクト）横断の月次集計を返す。
 
-use crate::error::AppResult;
-use crate::stats::{aggregate_cross_view, MonthlyStatsResponse};
-use crate::storage::CommitRepository;
+use crate::commands::stats_monthly_project_view::{
+    attach_trend, find_project_view_commits, resolve_aggregate_options, resolve_date_basis,
+};
+use crate::domain::{Commit, CommitDateBasis, ProjectFilter, ProjectTag};
+use crate::error::{AppError, AppResult};
+use crate::stats::{
+    aggregate_cross_view, apply_working_days, handle_outliers, AggregateOptions, GroupBy, Metric,
+    MonthlyStatsResponse, OutlierHandling, ProjectBreakdown, TrendOptions,
+};
+use crate::storage::{self, CommitRepository, ProjectRepository};
 use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
 use tracing::info;
 
 /// 横断ビュー集計リクエスト
@@ -17,6 +25,59 @@ pub struct CrossViewStatsRequest {
     /// フィルタするユーザーキー
     #[serde(default)]
     pub user_keys: Vec<String>,
+    /// 対象カテゴリ（空の場合は全カテゴリ）
+    #[serde(default)]
+    pub categories: Vec<String>,
+    /// ユーザーごとの合計をカテゴリ別にも返すか
+    #[serde(default)]
+    pub split_by_category: bool,
+    /// ボットを除外するか（既定: 除外、除外分は excludedBots に集計）
+    #[serde(default = "default_exclude_bots")]
+    pub exclude_bots: bool,
+    /// 集計単位（user / team / project）
+    #[serde(default)]
+    pub group_by: GroupBy,
+    /// series に出力する指標（既定: totalLines）
+    #[serde(default)]
+    pub metric: Metric,
+    /// 対象プロジェクトの絞り込み（タグ/名前空間/ID、省略時は全プロジェクト）
+    #[serde(default)]
+    pub projects: ProjectFilter,
+    /// 系列ごとのプロジェクト/名前空間内訳（project / namespace、省略時は内訳なし）
+    #[serde(default)]
+    pub breakdown: Option<ProjectBreakdown>,
+    /// 外れ値コミットの除外/頭打ち（省略時はそのまま集計）
+    #[serde(default)]
+    pub outliers: Option<OutlierHandling>,
+    /// 稼働日あたりの値も返すか（稼働日カレンダーで各月の稼働日数を求めて割る）
+    #[serde(default)]
+    pub per_working_day: bool,
+    /// 系列ごとのトレンドと予測も返すか（省略時は返さない）
+    #[serde(default)]
+    pub trend: Option<TrendOptions>,
+    /// 期間に振り分ける日時（省略時は集計レポート設定に従う）
+    #[serde(default)]
+    pub date_basis: Option<CommitDateBasis>,
+}
+
+fn default_exclude_bots() -> bool {
+    true
+}
+
+impl CrossViewStatsRequest {
+    /// 集計オプション
+    pub fn aggregate_options(&self) -> AggregateOptions {
+        AggregateOptions {
+            categories: self.categories.clone(),
+            split_by_category: self.split_by_category,
+            exclude_bots: self.exclude_bots,
+            group_by: self.group_by,
+            metric: self.metric,
+            breakdown: self.breakdown,
+            year: self.year,
+            ..Default::default()
+        }
+    }
 }
 
 /// 横断ビューの月次集計
@@ -27,18 +88,102 @@ pub fn get_monthly_stats_cross_view(
     get_monthly_stats_cross_view_inner(request).map_err(|e| e.to_string())
 }
 
-fn get_monthly_stats_cross_view_inner(
+pub fn get_monthly_stats_cross_view_inner(
     request: CrossViewStatsRequest,
 ) -> AppResult<MonthlyStatsResponse> {
     info!("横断ビュー集計: year={}", request.year);
 
     // 年でフィルタしたコミットを取得
-    let commits = CommitRepository::find_by_year(request.year)?;
+    let date_basis = resolve_date_basis(request.date_basis)?;
+    let find_commits = |year: i32| {
+        filter_commits_by_projects(
+            CommitRepository::find_by_year(year, date_basis)?,
+            &request.projects,
+        )
+    };
+    let commits = find_commits(request.year)?;
 
     info!("集計対象コミット数: {}", commits.len());
 
     // 集計
-    let response = aggregate_cross_view(&commits, &request.user_keys);
+    let options = resolve_aggregate_options(request.aggregate_options())?;
+    let aggregate =
+        |commits: Vec<Commit>, options: &AggregateOptions| -> AppResult<MonthlyStatsResponse> {
+            let (commits, outliers) =
+                handle_outliers(commits, &request.user_keys, options, request.outliers)?;
+            Ok(MonthlyStatsResponse {
+                outliers,
+                ..aggregate_cross_view(&commits, &request.user_keys, options)
+            })
+        };
+
+    let mut response = aggregate(commits, &options)?;
+    if request.per_working_day {
+        let calendar = storage::get_work_calendar()?;
+        apply_working_days(&mut response, &calendar, request.year, options.group_by);
+    }
+    attach_trend(&mut response, request.year, request.trend, || {
+        // 前年の系列はチームの所属人数も前年で数える
+        let options = AggregateOptions {
+            year: request.year - 1,
+            ..options.clone()
+        };
+        aggregate(find_commits(request.year - 1)?, &options)
+    })?;
 
     Ok(response)
 }
+
+/// 絞り込み条件に一致するプロジェクトのコミットだけを残す
+pub(crate) fn filter_commits_by_projects(
+    commits: Vec<Commit>,
+    filter: &ProjectFilter,
+) -> AppResult<Vec<Commit>> {
+    if filter.is_empty() {
+        return Ok(commits);
+    }
+
+    let mut tags: HashMap<i64, Vec<ProjectTag>> = storage::list_project_tags()?
+        .into_iter()
+        .map(|p| (p.project_id, p.tags))
+        .collect();
+    let project_ids: HashSet<i64> = ProjectRepository::find_all()?
+        .into_iter()
+        .filter(|project| {
+            let project_tags = tags.remove(&project.project_id).unwrap_or_default();
+            filter.matches(project, &project_tags)
+        })
+        .map(|project| project.project_id)
+        .collect();
+
+    info!("対象プロジェクト数: {}", project_ids.len());
+
+    Ok(commits
+        .into_iter()
+        .filter(|c| project_ids.contains(&c.project_id))
+        .collect())
+}
+
+/// ビューに応じて指定年のコミットを取得
+///
+/// `project_id` と `branch_name` の両方を指定した場合はプロジェクトビュー、
+/// どちらも省略した場合は横断ビュー（`projects` で絞り込み）のコミットを返す。
+pub(crate) fn find_view_commits(
+    project_id: Option<i64>,
+    branch_name: Option<&str>,
+    projects: &ProjectFilter,
+    year: i32,
+    date_basis: CommitDateBasis,
+) -> AppResult<Vec<Commit>> {
+    match (project_id, branch_name) {
+        (Some(project_id), Some(branch_name)) => {
+            find_project_view_commits(project_id, branch_name, year, date_basis)
+        }
+        (None, None) => {
+            filter_commits_by_projects(CommitRepository::find_by_year(year, date_basis)?, projects)
+        }
+        _ => Err(AppError::Validation(
+            "プロジェクトビューの集計にはプロジェクトとブランチの両方の指定が必要です".to_string(),
+        )),
+    }
+}
@@ -2,9 +2,14 @@
 //!
 //! 特定プロジェクト/ブランチの月次集計を返す。
 
+use crate::domain::{Commit, CommitDateBasis};
 use crate::error::AppResult;
-use crate::stats::{aggregate_project_view, MonthlyStatsResponse};
-use crate::storage::CommitRepository;
+use crate::stats::{
+    aggregate_project_view, apply_working_days, compute_trends, handle_outliers, AggregateOptions,
+    GroupBy, Metric, MonthlyStatsResponse, OutlierHandling, TrendOptions,
+};
+use crate::storage::{self, CommitRepository, ProjectRepository};
+use chrono::Datelike;
 use serde::Deserialize;
 use tracing::info;
 
@@ -21,6 +26,52 @@ pub struct ProjectViewStatsRequest {
     /// フィルタするユーザーキー
     #[serde(default)]
     pub user_keys: Vec<String>,
+    /// 対象カテゴリ（空の場合は全カテゴリ）
+    #[serde(default)]
+    pub categories: Vec<String>,
+    /// ユーザーごとの合計をカテゴリ別にも返すか
+    #[serde(default)]
+    pub split_by_category: bool,
+    /// ボットを除外するか（既定: 除外、除外分は excludedBots に集計）
+    #[serde(default = "default_exclude_bots")]
+    pub exclude_bots: bool,
+    /// 集計単位（user / team / project）
+    #[serde(default)]
+    pub group_by: GroupBy,
+    /// series に出力する指標（既定: totalLines）
+    #[serde(default)]
+    pub metric: Metric,
+    /// 外れ値コミットの除外/頭打ち（省略時はそのまま集計）
+    #[serde(default)]
+    pub outliers: Option<OutlierHandling>,
+    /// 稼働日あたりの値も返すか（稼働日カレンダーで各月の稼働日数を求めて割る）
+    #[serde(default)]
+    pub per_working_day: bool,
+    /// 系列ごとのトレンドと予測も返すか（省略時は返さない）
+    #[serde(default)]
+    pub trend: Option<TrendOptions>,
+    /// 期間に振り分ける日時（省略時は集計レポート設定に従う）
+    #[serde(default)]
+    pub date_basis: Option<CommitDateBasis>,
+}
+
+fn default_exclude_bots() -> bool {
+    true
+}
+
+impl ProjectViewStatsRequest {
+    /// 集計オプション
+    pub fn aggregate_options(&self) -> AggregateOptions {
+        AggregateOptions {
+            categories: self.categories.clone(),
+            split_by_category: self.split_by_category,
+            exclude_bots: self.exclude_bots,
+            group_by: self.group_by,
+            metric: self.metric,
+            year: self.year,
+            ..Default::default()
+        }
+    }
 }
 
 /// プロジェクトビューの月次集計
@@ -31,7 +82,7 @@ pub fn get_monthly_stats_project_view(
     get_monthly_stats_project_view_inner(request).map_err(|e| e.to_string())
 }
 
-fn get_monthly_stats_project_view_inner(
+pub fn get_monthly_stats_project_view_inner(
     request: ProjectViewStatsRequest,
 ) -> AppResult<MonthlyStatsResponse> {
     info!(
@@ -39,20 +90,125 @@ fn get_monthly_stats_project_view_inner(
         request.project_id, request.branch_name, request.year
     );
 
-    // コミットを取得
-    let commits =
-        CommitRepository::find_by_project_and_branch(request.project_id, &request.branch_name)?;
-
-    // 年でフィルタ
-    let commits: Vec<_> = commits
-        .into_iter()
-        .filter(|c| c.year() == request.year)
-        .collect();
+    let date_basis = resolve_date_basis(request.date_basis)?;
+    let commits = find_project_view_commits(
+        request.project_id,
+        &request.branch_name,
+        request.year,
+        date_basis,
+    )?;
 
     info!("集計対象コミット数: {}", commits.len());
 
     // 集計
-    let response = aggregate_project_view(&commits, &request.user_keys);
+    let options = resolve_aggregate_options(request.aggregate_options())?;
+    let aggregate =
+        |commits: Vec<Commit>, options: &AggregateOptions| -> AppResult<MonthlyStatsResponse> {
+            let (commits, outliers) =
+                handle_outliers(commits, &request.user_keys, options, request.outliers)?;
+            Ok(MonthlyStatsResponse {
+                outliers,
+                ..aggregate_project_view(&commits, &request.user_keys, options)
+            })
+        };
+
+    let mut response = aggregate(commits, &options)?;
+    if request.per_working_day {
+        let calendar = storage::get_work_calendar()?;
+        apply_working_days(&mut response, &calendar, request.year, options.group_by);
+    }
+    attach_trend(&mut response, request.year, request.trend, || {
+        // 前年の系列はチームの所属人数も前年で数える
+        let options = AggregateOptions {
+            year: request.year - 1,
+            ..options.clone()
+        };
+        aggregate(
+            find_project_view_commits(
+                request.project_id,
+                &request.branch_name,
+                request.year - 1,
+                date_basis,
+            )?,
+            &options,
+        )
+    })?;
 
     Ok(response)
 }
+
+/// プロジェクトビューの集計対象コミットを取得（プロジェクト/ブランチ/年でフィルタ）
+pub(crate) fn find_project_view_commits(
+    project_id: i64,
+    branch_name: &str,
+    year: i32,
+    date_basis: CommitDateBasis,
+) -> AppResult<Vec<Commit>> {
+    let commits = CommitRepository::find_by_project_and_branch(project_id, branch_name)?;
+
+    // 振り分ける日時に置き換えてから年でフィルタ（日時不正のコミットは含めない）
+    Ok(commits
+        .into_iter()
+        .filter(|c| !c.date_invalid)
+        .map(|c| c.with_date_basis(date_basis))
+        .filter(|c| c.year() == year)
+        .collect())
+}
+
+/// 期間に振り分ける日時を決定（省略時は集計レポート設定に従う）
+pub(crate) fn resolve_date_basis(requested: Option<CommitDateBasis>) -> AppResult<CommitDateBasis> {
+    match requested {
+        Some(basis) => Ok(basis),
+        None => Ok(storage::get_reporting_settings()?.date_basis),
+    }
+}
+
+/// 集計単位/内訳に必要なチーム・プロジェクト定義を読み込む
+pub(crate) fn resolve_aggregate_options(
+    mut options: AggregateOptions,
+) -> AppResult<AggregateOptions> {
+    if options.group_by == GroupBy::Team {
+        options.teams = storage::list_teams()?;
+    }
+    if options.needs_projects() {
+        options.projects = ProjectRepository::find_all()?
+            .into_iter()
+            .map(|p| (p.project_id, p))
+            .collect();
+    }
+    Ok(options)
+}
+
+/// トレンドを付与する（当年は観測する月の既定を前月までにする）
+///
+/// `previous_year` は前年の同じ条件の集計で、季節ナイーブ予測に使う（トレンド指定時のみ呼ぶ）。
+pub(crate) fn attach_trend(
+    response: &mut MonthlyStatsResponse,
+    year: i32,
+    trend: Option<TrendOptions>,
+    previous_year: impl FnOnce() -> AppResult<MonthlyStatsResponse>,
+) -> AppResult<()> {
+    let Some(mut options) = trend else {
+        return Ok(());
+    };
+    options.validate()?;
+
+    if options.through_month.is_none() {
+        let today = chrono::Utc::now().date_naive();
+        // 1 月は当年に完了した月がないため、当年の実績を含めない（0 = 実績なし）
+        if today.year() == year {
+            options.through_month = Some(today.month() - 1);
+        } else if today.year() < year {
+            options.through_month = Some(0);
+        }
+    }
+
+    let previous_year = previous_year()?;
+    response.trend = Some(compute_trends(
+        response,
+        Some(&previous_year),
+        year,
+        &options,
+    ));
+    Ok(())
+}
@@ -0,0 +1,96 @@
+//! コマンド: コミットサイズ分布
+//!
+//! プロジェクトビュー/横断ビューのコミットサイズのヒストグラムを系列ごとに返し、外れ値を検出する。
+
+use crate::commands::stats_monthly_cross_view::find_view_commits;
+use crate::commands::stats_monthly_project_view::{resolve_aggregate_options, resolve_date_basis};
+use crate::domain::{CommitDateBasis, ProjectFilter};
+use crate::error::AppResult;
+use crate::stats::{
+    commit_size_distribution, AggregateOptions, GroupBy, OutlierRule, SizeDistributionResponse,
+};
+use serde::Deserialize;
+use tracing::info;
+
+/// コミットサイズ分布リクエスト
+///
+/// `projectId` と `branchName` を指定した場合はプロジェクトビュー、どちらも省略した場合は横断ビューを集計する。
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SizeDistributionRequest {
+    /// 対象年
+    pub year: i32,
+    /// プロジェクト ID（プロジェクトビューのみ）
+    pub project_id: Option<i64>,
+    /// ブランチ名（プロジェクトビューのみ）
+    pub branch_name: Option<String>,
+    /// フィルタするユーザーキー
+    #[serde(default)]
+    pub user_keys: Vec<String>,
+    /// 対象カテゴリ（空の場合は全カテゴリ）
+    #[serde(default)]
+    pub categories: Vec<String>,
+    /// ボットを除外するか（既定: 除外）
+    #[serde(default = "default_exclude_bots")]
+    pub exclude_bots: bool,
+    /// 集計単位（user / team / project）
+    #[serde(default)]
+    pub group_by: GroupBy,
+    /// 対象プロジェクトの絞り込み（横断ビューのみ）
+    #[serde(default)]
+    pub projects: ProjectFilter,
+    /// 外れ値の判定方法（既定: IQR × 1.5）
+    #[serde(default)]
+    pub rule: OutlierRule,
+    /// 期間に振り分ける日時（省略時は集計レポート設定に従う）
+    #[serde(default)]
+    pub date_basis: Option<CommitDateBasis>,
+}
+
+fn default_exclude_bots() -> bool {
+    true
+}
+
+/// コミットサイズの分布と外れ値を取得
+#[tauri::command]
+pub fn get_commit_size_distribution(
+    request: SizeDistributionRequest,
+) -> Result<SizeDistributionResponse, String> {
+    get_commit_size_distribution_inner(request).map_err(|e| e.user_message())
+}
+
+pub fn get_commit_size_distribution_inner(
+    request: SizeDistributionRequest,
+) -> AppResult<SizeDistributionResponse> {
+    info!(
+        "コミットサイズ分布: year={}, project_id={:?}, group_by={:?}, rule={:?}",
+        request.year, request.project_id, request.group_by, request.rule
+    );
+
+    let commits = find_view_commits(
+        request.project_id,
+        request.branch_name.as_deref(),
+        &request.projects,
+        request.year,
+        resolve_date_basis(request.date_basis)?,
+    )?;
+
+    info!("集計対象コミット数: {}", commits.len());
+
+    let options = resolve_aggregate_options(AggregateOptions {
+        categories: request.categories.clone(),
+        exclude_bots: request.exclude_bots,
+        group_by: request.group_by,
+        ..Default::default()
+    })?;
+
+    let response = commit_size_distribution(&commits, &request.user_keys, &options, request.rule)?;
+
+    info!(
+        "外れ値: threshold={:?}, count={}",
+        response.threshold,
+        response.outliers.len()
+    );
+
+    Ok(response)
+}
@@ -0,0 +1,32 @@
+//! コマンド: チーム作成
+//!
+//! 名前付きのチームを作成する。メンバーの所属は期間付きにできる。
+
+use crate::domain::{Team, TeamMember};
+use crate::error::AppResult;
+use crate::storage;
+use serde::Deserialize;
+use tracing::info;
+
+/// チーム作成リクエスト
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTeamRequest {
+    /// チーム名（一意）
+    pub name: String,
+    /// メンバー
+    #[serde(default)]
+    pub members: Vec<TeamMember>,
+}
+
+/// チームを作成
+#[tauri::command]
+pub fn create_team(request: CreateTeamRequest) -> Result<Team, String> {
+    create_team_inner(request).map_err(|e| e.user_message())
+}
+
+pub fn create_team_inner(request: CreateTeamRequest) -> AppResult<Team> {
+    info!("チーム作成: members={}", request.members.len());
+
+    storage::create_team(&request.name, &request.members)
+}
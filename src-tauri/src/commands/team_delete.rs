@@ -0,0 +1,28 @@
+//! コマンド: チーム削除
+//!
+//! チームとメンバー所属を削除する（コミットデータは削除しない）。
+
+use crate::error::AppResult;
+use crate::storage;
+use serde::Deserialize;
+use tracing::info;
+
+/// チーム削除リクエスト
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteTeamRequest {
+    /// チーム ID
+    pub team_id: i64,
+}
+
+/// チームを削除
+#[tauri::command]
+pub fn delete_team(request: DeleteTeamRequest) -> Result<(), String> {
+    delete_team_inner(request).map_err(|e| e.user_message())
+}
+
+pub fn delete_team_inner(request: DeleteTeamRequest) -> AppResult<()> {
+    info!("チーム削除: team_id={}", request.team_id);
+
+    storage::delete_team(request.team_id)
+}
@@ -0,0 +1,38 @@
+//! コマンド: チーム更新
+//!
+//! チーム名とメンバー所属を更新する（メンバーは置き換え）。
+
+use crate::domain::{Team, TeamMember};
+use crate::error::AppResult;
+use crate::storage;
+use serde::Deserialize;
+use tracing::info;
+
+/// チーム更新リクエスト
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateTeamRequest {
+    /// チーム ID
+    pub team_id: i64,
+    /// チーム名（一意）
+    pub name: String,
+    /// メンバー（既存のメンバーと置き換える）
+    #[serde(default)]
+    pub members: Vec<TeamMember>,
+}
+
+/// チームを更新
+#[tauri::command]
+pub fn update_team(request: UpdateTeamRequest) -> Result<Team, String> {
+    update_team_inner(request).map_err(|e| e.user_message())
+}
+
+pub fn update_team_inner(request: UpdateTeamRequest) -> AppResult<Team> {
+    info!(
+        "チーム更新: team_id={}, members={}",
+        request.team_id,
+        request.members.len()
+    );
+
+    storage::update_team(request.team_id, &request.name, &request.members)
+}
@@ -0,0 +1,17 @@
+//! コマンド: チーム一覧取得
+//!
+//! 保存済みのチームとメンバー所属を返す。
+
+use crate::domain::Team;
+use crate::error::AppResult;
+use crate::storage;
+
+/// チーム一覧を取得
+#[tauri::command]
+pub fn list_teams() -> Result<Vec<Team>, String> {
+    list_teams_inner().map_err(|e| e.user_message())
+}
+
+pub fn list_teams_inner() -> AppResult<Vec<Team>> {
+    storage::list_teams()
+}
@@ -0,0 +1,17 @@
+//! コマンド: 稼働日カレンダー取得
+//!
+//! 週末・祝日・ユーザーごとの休暇を返す。
+
+use crate::domain::WorkCalendar;
+use crate::error::AppResult;
+use crate::storage;
+
+/// 稼働日カレンダーを取得
+#[tauri::command]
+pub fn get_work_calendar() -> Result<WorkCalendar, String> {
+    get_work_calendar_inner().map_err(|e| e.user_message())
+}
+
+pub fn get_work_calendar_inner() -> AppResult<WorkCalendar> {
+    storage::get_work_calendar()
+}
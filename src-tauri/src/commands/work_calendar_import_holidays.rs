@@ -0,0 +1,74 @@
+//! コマンド: 祝日の取り込み
+//!
+//! iCalendar（.ics）ファイルの終日イベントを祝日として稼働日カレンダーに取り込む。
+//! 同じ取り込み元（既定はファイル名）から取り込み済みの祝日は置き換える。
+
+use crate::domain::parse_ical_holidays;
+use crate::error::{AppError, AppResult};
+use crate::storage;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use tracing::info;
+
+/// 祝日取り込みリクエスト
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportHolidaysRequest {
+    /// iCalendar ファイルのパス
+    pub path: String,
+    /// 取り込み元の名前（省略時はファイル名）
+    pub source: Option<String>,
+}
+
+/// 祝日取り込み結果
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportHolidaysResult {
+    /// 取り込み元の名前
+    pub source: String,
+    /// 取り込んだ祝日の件数
+    pub imported_count: usize,
+    /// 取り込み後の祝日の総数
+    pub holiday_count: usize,
+}
+
+/// iCalendar ファイルから祝日を取り込む
+#[tauri::command]
+pub fn import_holidays(request: ImportHolidaysRequest) -> Result<ImportHolidaysResult, String> {
+    import_holidays_inner(request).map_err(|e| e.user_message())
+}
+
+pub fn import_holidays_inner(request: ImportHolidaysRequest) -> AppResult<ImportHolidaysResult> {
+    let path = Path::new(&request.path);
+    let source = request
+        .source
+        .clone()
+        .filter(|source| !source.trim().is_empty())
+        .or_else(|| {
+            path.file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+        })
+        .ok_or_else(|| AppError::Validation("取り込み元の名前を指定してください".to_string()))?;
+
+    info!("祝日取り込み: path={}, source={}", request.path, source);
+
+    let content = std::fs::read_to_string(path).map_err(|e| {
+        AppError::Validation(format!(
+            "iCalendar ファイルを読み込めません（{}）: {}",
+            request.path, e
+        ))
+    })?;
+    let holidays = parse_ical_holidays(&content, &source)?;
+
+    let mut calendar = storage::get_work_calendar()?;
+    let imported_count = calendar.import_holidays(&source, holidays);
+    storage::save_work_calendar(&calendar)?;
+
+    info!("取り込んだ祝日: {}", imported_count);
+
+    Ok(ImportHolidaysResult {
+        source,
+        imported_count,
+        holiday_count: calendar.holidays.len(),
+    })
+}
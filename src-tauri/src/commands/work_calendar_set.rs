@@ -0,0 +1,34 @@
+//! コマンド: 稼働日カレンダー保存
+//!
+//! 週末・祝日・ユーザーごとの休暇を検証して保存する。
+
+use crate::domain::WorkCalendar;
+use crate::error::AppResult;
+use crate::storage;
+use serde::Deserialize;
+use tracing::info;
+
+/// 稼働日カレンダー保存リクエスト
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetWorkCalendarRequest {
+    /// 稼働日カレンダー
+    pub calendar: WorkCalendar,
+}
+
+/// 稼働日カレンダーを保存
+#[tauri::command]
+pub fn set_work_calendar(request: SetWorkCalendarRequest) -> Result<(), String> {
+    set_work_calendar_inner(request).map_err(|e| e.user_message())
+}
+
+pub fn set_work_calendar_inner(request: SetWorkCalendarRequest) -> AppResult<()> {
+    info!(
+        "稼働日カレンダー保存: weekend_days={:?}, holidays={}, leaves={}",
+        request.calendar.weekend_days,
+        request.calendar.holidays.len(),
+        request.calendar.leaves.len()
+    );
+
+    storage::save_work_calendar(&request.calendar)
+}
@@ -0,0 +1,174 @@
+//! ボット/サービスアカウント判定
+//!
+//! 設定（メールアドレス完全一致、名前/メールの正規表現）と GitLab の `bot` ユーザーフラグから
+//! コミット作者がボットかどうかを判定する。判定は収集（保存）時に行い、commits.is_bot に保存する。
+
+use crate::error::{AppError, AppResult};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// ボット判定設定
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BotConfig {
+    /// ボットとして扱うメールアドレス（完全一致、大文字小文字を区別しない）
+    #[serde(default)]
+    pub exact_emails: Vec<String>,
+    /// 作者名の正規表現
+    #[serde(default)]
+    pub name_patterns: Vec<String>,
+    /// メールアドレスの正規表現
+    #[serde(default)]
+    pub email_patterns: Vec<String>,
+    /// GitLab の users API で `bot` と判定されたユーザーもボットとして扱うか
+    #[serde(default = "default_true")]
+    pub use_gitlab_bot_flag: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Default for BotConfig {
+    /// 代表的なボット（Renovate / Dependabot / GitLab のプロジェクト・グループアクセストークン）を既定で登録
+    fn default() -> Self {
+        Self {
+            exact_emails: Vec::new(),
+            name_patterns: vec![
+                r"(?i)\[bot\]$".to_string(),
+                r"(?i)^renovate( bot)?$".to_string(),
+                r"(?i)^dependabot".to_string(),
+                r"(?i)^gitlab[ -]?bot$".to_string(),
+            ],
+            email_patterns: vec![
+                r"(?i)^(project|group)_\d+_bot".to_string(),
+                r"(?i)^renovate(-bot)?@".to_string(),
+            ],
+            use_gitlab_bot_flag: true,
+        }
+    }
+}
+
+/// ボット判定器
+#[derive(Debug, Clone)]
+pub struct BotDetector {
+    exact_emails: HashSet<String>,
+    name_patterns: Vec<Regex>,
+    email_patterns: Vec<Regex>,
+    /// GitLab の bot フラグが立っているユーザーキー
+    gitlab_bot_keys: HashSet<String>,
+}
+
+impl BotDetector {
+    /// 設定をコンパイルして判定器を作成
+    ///
+    /// # Arguments
+    /// * `config` - ボット判定設定
+    /// * `gitlab_bot_keys` - GitLab で bot と判定済みのユーザーキー（`use_gitlab_bot_flag` が false なら無視）
+    pub fn new(config: &BotConfig, gitlab_bot_keys: HashSet<String>) -> AppResult<Self> {
+        Ok(Self {
+            exact_emails: config
+                .exact_emails
+                .iter()
+                .map(|email| email.trim().to_lowercase())
+                .filter(|email| !email.is_empty())
+                .collect(),
+            name_patterns: compile_patterns(&config.name_patterns, "名前")?,
+            email_patterns: compile_patterns(&config.email_patterns, "メールアドレス")?,
+            gitlab_bot_keys: if config.use_gitlab_bot_flag {
+                gitlab_bot_keys
+            } else {
+                HashSet::new()
+            },
+        })
+    }
+
+    /// 作者がボットか
+    pub fn is_bot(&self, author_name: &str, author_email: Option<&str>) -> bool {
+        let email = author_email.filter(|email| !email.is_empty());
+        let user_key = email.unwrap_or(author_name);
+
+        if self.gitlab_bot_keys.contains(user_key) {
+            return true;
+        }
+        if self.name_patterns.iter().any(|re| re.is_match(author_name)) {
+            return true;
+        }
+
+        match email {
+            Some(email) => {
+                self.exact_emails.contains(&email.to_lowercase())
+                    || self.email_patterns.iter().any(|re| re.is_match(email))
+            }
+            None => false,
+        }
+    }
+}
+
+fn compile_patterns(patterns: &[String], label: &str) -> AppResult<Vec<Regex>> {
+    patterns
+        .iter()
+        .map(|pattern| {
+            Regex::new(pattern).map_err(|e| {
+                AppError::Validation(format!(
+                    "{}パターンの正規表現が不正です（{}）: {}",
+                    label, pattern, e
+                ))
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_config_detects_common_bots() {
+        let detector = BotDetector::new(&BotConfig::default(), HashSet::new()).unwrap();
+
+        assert!(detector.is_bot("renovate[bot]", Some("bot@renovateapp.com")));
+        assert!(detector.is_bot("Renovate Bot", None));
+        assert!(detector.is_bot(
+            "deploy token",
+            Some("project_42_bot_0a1b2c@noreply.gitlab.example.com")
+        ));
+        assert!(!detector.is_bot("Alice", Some("alice@example.com")));
+    }
+
+    #[test]
+    fn test_exact_email_and_gitlab_flag() {
+        let config = BotConfig {
+            exact_emails: vec!["CI@example.com".to_string()],
+            name_patterns: Vec::new(),
+            email_patterns: Vec::new(),
+            use_gitlab_bot_flag: true,
+        };
+        let gitlab_bots: HashSet<String> = ["svc-release@example.com".to_string()].into();
+
+        let detector = BotDetector::new(&config, gitlab_bots.clone()).unwrap();
+        assert!(detector.is_bot("CI", Some("ci@example.com")));
+        assert!(detector.is_bot("Release", Some("svc-release@example.com")));
+
+        let without_flag = BotConfig {
+            use_gitlab_bot_flag: false,
+            ..config
+        };
+        let detector = BotDetector::new(&without_flag, gitlab_bots).unwrap();
+        assert!(!detector.is_bot("Release", Some("svc-release@example.com")));
+    }
+
+    #[test]
+    fn test_invalid_pattern_is_rejected() {
+        let config = BotConfig {
+            name_patterns: vec!["(".to_string()],
+            ..BotConfig::default()
+        };
+
+        assert!(matches!(
+            BotDetector::new(&config, HashSet::new()),
+            Err(AppError::Validation(_))
+        ));
+    }
+}
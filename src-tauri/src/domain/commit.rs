@@ -1,7 +1,9 @@
 //! コミットエンティティ
 //!
 //! GitLab コミットを表す。stats 欠損時は 0 として扱い、欠損フラグを立てる。
+//! 日時が解釈できない場合は現在時刻で補わず、日時不正フラグを立てて UNIX エポックで保存する。
 
+use crate::domain::commit_category::{conventional_category, CATEGORY_OTHER};
 use crate::gitlab::GitLabCommit;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -21,9 +23,16 @@ pub struct Commit {
     /// コミットメッセージ
     pub message: String,
 
-    /// コミット日時（UTC）
+    /// コミット日時（UTC、日時不正の場合は UNIX エポック）
+    ///
+    /// 集計はこの日時で期間に振り分ける。作成日時で集計する場合は
+    /// [`Commit::with_date_basis`] で作成日時に置き換えたコミットを渡す。
     pub committed_date_utc: DateTime<Utc>,
 
+    /// 作成日時（UTC、GitLab が返さない/解釈できない場合は None）
+    #[serde(default)]
+    pub authored_date_utc: Option<DateTime<Utc>>,
+
     /// 作者名
     pub author_name: String,
 
@@ -39,6 +48,40 @@ pub struct Commit {
 
     /// stats が欠損していたかどうか
     pub stats_missing: bool,
+
+    /// カテゴリ（Conventional Commits / 分類ルールで判定、該当なしは "other"）
+    #[serde(default = "default_category")]
+    pub category: String,
+
+    /// ボット/サービスアカウントによるコミットか（保存時に判定）
+    #[serde(default)]
+    pub is_bot: bool,
+
+    /// コミット日時が解釈できなかったか（集計の期間フィルタには含まれない）
+    #[serde(default)]
+    pub date_invalid: bool,
+}
+
+/// 集計で期間に振り分けるコミットの日時
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CommitDateBasis {
+    /// コミット日時（committed_date、リベースやチェリーピックで更新される）
+    #[default]
+    Committed,
+    /// 作成日時（authored_date、作者が最初にコミットした日時）
+    Authored,
+}
+
+/// GitLab の日時文字列（ISO8601）を UTC に変換
+pub fn parse_gitlab_date(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+fn default_category() -> String {
+    CATEGORY_OTHER.to_string()
 }
 
 impl Commit {
@@ -54,11 +97,13 @@ impl Commit {
 
     /// ユーザーキー（集計用）
     ///
-    /// `author_email` があればそれを優先、なければ `author_name` を使用。
+    /// `author_email` があればそれを優先、なければ `author_name` を使用（空文字は未設定として扱う）。
     pub fn user_key(&self) -> String {
         self.author_email
-            .clone()
-            .unwrap_or_else(|| self.author_name.clone())
+            .as_deref()
+            .filter(|email| !email.is_empty())
+            .unwrap_or(&self.author_name)
+            .to_string()
     }
 
     /// 表示名
@@ -76,17 +121,37 @@ impl Commit {
         self.committed_date_utc.year()
     }
 
+    /// 指定した基準で期間に振り分ける日時（作成日時がなければコミット日時）
+    pub fn bucket_date(&self, basis: CommitDateBasis) -> DateTime<Utc> {
+        match basis {
+            CommitDateBasis::Committed => self.committed_date_utc,
+            CommitDateBasis::Authored => self.authored_date_utc.unwrap_or(self.committed_date_utc),
+        }
+    }
+
+    /// 集計用に、期間に振り分ける日時を `committed_date_utc` に置き換える
+    pub fn with_date_basis(mut self, basis: CommitDateBasis) -> Self {
+        self.committed_date_utc = self.bucket_date(basis);
+        self
+    }
+
     /// GitLab API レスポンスから変換
     pub fn from_gitlab(project_id: i64, branch_name: &str, commit: GitLabCommit) -> Self {
-        let (additions, deletions, stats_missing) = match commit.stats {
+        let (additions, deletions, stats_missing) = match &commit.stats {
             Some(stats) => (stats.additions, stats.deletions, false),
             None => (0, 0, true),
         };
 
-        // 日時のパース（失敗時は現在時刻）
-        let committed_date_utc = DateTime::parse_from_rfc3339(&commit.committed_date)
-            .map(|dt| dt.with_timezone(&Utc))
-            .unwrap_or_else(|_| Utc::now());
+        // 日時のパース（失敗時は日時不正として UNIX エポックで保存）
+        let parsed_date = parse_gitlab_date(&commit.committed_date);
+        let date_invalid = parsed_date.is_none();
+        let committed_date_utc = parsed_date.unwrap_or(DateTime::<Utc>::UNIX_EPOCH);
+        let authored_date_utc = commit.authored_date.as_deref().and_then(parse_gitlab_date);
+
+        // 分類ルールは保存時に適用する（ここでは Conventional Commits のみ）
+        let category = conventional_category(&commit.message)
+            .unwrap_or(CATEGORY_OTHER)
+            .to_string();
 
         Self {
             project_id,
@@ -94,11 +159,15 @@ impl Commit {
             sha: commit.id,
             message: commit.message,
             committed_date_utc,
+            authored_date_utc,
             author_name: commit.author_name,
             author_email: commit.author_email,
             additions,
             deletions,
             stats_missing,
+            category,
+            is_bot: false,
+            date_invalid,
         }
     }
 }
@@ -117,6 +186,7 @@ mod tests {
             id: "abc123".to_string(),
             message: "Initial commit".to_string(),
             committed_date: "2026-01-15T10:00:00Z".to_string(),
+            authored_date: None,
             author_name: "John Doe".to_string(),
             author_email: Some("john@example.com".to_string()),
             stats: Some(GitLabCommitStats {
@@ -142,6 +212,7 @@ mod tests {
             id: "abc123".to_string(),
             message: "Commit without stats".to_string(),
             committed_date: "2026-01-15T10:00:00Z".to_string(),
+            authored_date: None,
             author_name: "John Doe".to_string(),
             author_email: None,
             stats: None,
@@ -155,6 +226,53 @@ mod tests {
         assert!(commit.stats_missing);
     }
 
+    #[test]
+    fn test_user_key_falls_back_on_empty_email() {
+        let gitlab_commit = GitLabCommit {
+            id: "abc123".to_string(),
+            message: "No email".to_string(),
+            committed_date: "2026-01-15T10:00:00Z".to_string(),
+            authored_date: None,
+            author_name: "John Doe".to_string(),
+            author_email: None,
+            stats: None,
+        };
+        let mut commit = Commit::from_gitlab(1, "main", gitlab_commit);
+        assert_eq!(commit.user_key(), "John Doe");
+
+        // 保存時に空文字になったメールアドレスも未設定として扱う
+        commit.author_email = Some(String::new());
+        assert_eq!(commit.user_key(), "John Doe");
+
+        commit.author_email = Some("john@example.com".to_string());
+        assert_eq!(commit.user_key(), "john@example.com");
+    }
+
+    #[test]
+    fn test_from_gitlab_invalid_date() {
+        let gitlab_commit = GitLabCommit {
+            id: "abc123".to_string(),
+            message: "Broken date".to_string(),
+            committed_date: "not a date".to_string(),
+            authored_date: Some("2026-01-10T09:00:00+09:00".to_string()),
+            author_name: "John Doe".to_string(),
+            author_email: None,
+            stats: None,
+        };
+
+        // 現在時刻で補わず、日時不正として UNIX エポックで保存する
+        let commit = Commit::from_gitlab(1, "main", gitlab_commit);
+        assert!(commit.date_invalid);
+        assert_eq!(commit.committed_date_utc, DateTime::<Utc>::UNIX_EPOCH);
+
+        // 作成日時で集計する場合は作成日時に置き換える
+        let commit = commit.with_date_basis(CommitDateBasis::Authored);
+        assert_eq!(
+            commit.committed_date_utc.to_rfc3339(),
+            "2026-01-10T00:00:00+00:00"
+        );
+    }
+
     #[test]
     fn test_unique_key() {
         let commit = Commit {
@@ -163,11 +281,15 @@ mod tests {
             sha: "abc123".to_string(),
             message: "test".to_string(),
             committed_date_utc: Utc::now(),
+            authored_date_utc: None,
             author_name: "John".to_string(),
             author_email: Some("john@example.com".to_string()),
             additions: 10,
             deletions: 5,
             stats_missing: false,
+            date_invalid: false,
+            category: "other".to_string(),
+            is_bot: false,
         };
 
         assert_eq!(commit.unique_key(), "1:main:abc123");
@@ -181,11 +303,15 @@ mod tests {
             sha: "abc".to_string(),
             message: "test".to_string(),
             committed_date_utc: Utc::now(),
+            authored_date_utc: None,
             author_name: "John Doe".to_string(),
             author_email: Some("john@example.com".to_string()),
             additions: 0,
             deletions: 0,
             stats_missing: false,
+            date_invalid: false,
+            category: "other".to_string(),
+            is_bot: false,
         };
 
         assert_eq!(commit.user_key(), "john@example.com");
@@ -199,11 +325,15 @@ mod tests {
             sha: "abc".to_string(),
             message: "test".to_string(),
             committed_date_utc: Utc::now(),
+            authored_date_utc: None,
             author_name: "John Doe".to_string(),
             author_email: None,
             additions: 0,
             deletions: 0,
             stats_missing: false,
+            date_invalid: false,
+            category: "other".to_string(),
+            is_bot: false,
         };
 
         assert_eq!(commit.user_key(), "John Doe");
@@ -219,11 +349,15 @@ mod tests {
             committed_date_utc: DateTime::parse_from_rfc3339("2026-06-15T23:00:00Z")
                 .unwrap()
                 .with_timezone(&Utc),
+            authored_date_utc: None,
             author_name: "John".to_string(),
             author_email: None,
             additions: 0,
             deletions: 0,
             stats_missing: false,
+            date_invalid: false,
+            category: "other".to_string(),
+            is_bot: false,
         };
 
         assert_eq!(commit.month(), 6);
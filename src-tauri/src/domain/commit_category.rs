@@ -0,0 +1,183 @@
+//! コミットカテゴリ
+//!
+//! Conventional Commits のプレフィックスとユーザー定義ルール（正規表現）で
+//! コミットを分類する。分類は収集（保存）時に行い、commits.category に保存する。
+
+use crate::error::{AppError, AppResult};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// Conventional Commits の対象タイプ
+pub const CONVENTIONAL_CATEGORIES: [&str; 6] = ["feat", "fix", "refactor", "test", "docs", "chore"];
+
+/// どのルールにも一致しない場合のカテゴリ
+pub const CATEGORY_OTHER: &str = "other";
+
+/// ルールの照合対象
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CategoryRuleTarget {
+    /// コミットメッセージ
+    Message,
+    /// ブランチ名
+    Branch,
+}
+
+impl CategoryRuleTarget {
+    /// 保存用の文字列
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Message => "message",
+            Self::Branch => "branch",
+        }
+    }
+
+    /// 保存値からパース
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "message" => Some(Self::Message),
+            "branch" => Some(Self::Branch),
+            _ => None,
+        }
+    }
+}
+
+/// ユーザー定義の分類ルール（先頭から順に評価し、最初に一致したものを採用）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CategoryRule {
+    /// 一致時に付与するカテゴリ
+    pub category: String,
+    /// 照合対象
+    pub target: CategoryRuleTarget,
+    /// 正規表現
+    pub pattern: String,
+}
+
+/// コミット分類器
+#[derive(Debug, Clone, Default)]
+pub struct CategoryClassifier {
+    rules: Vec<(CategoryRule, Regex)>,
+}
+
+impl CategoryClassifier {
+    /// ルールをコンパイルして分類器を作成
+    pub fn new(rules: &[CategoryRule]) -> AppResult<Self> {
+        let rules = rules
+            .iter()
+            .enumerate()
+            .map(|(index, rule)| {
+                if rule.category.trim().is_empty() {
+                    return Err(AppError::Validation(format!(
+                        "ルール {} のカテゴリ名が空です",
+                        index + 1
+                    )));
+                }
+                let regex = Regex::new(&rule.pattern).map_err(|e| {
+                    AppError::Validation(format!(
+                        "ルール {} の正規表現が不正です: {}",
+                        index + 1,
+                        e
+                    ))
+                })?;
+                Ok((rule.clone(), regex))
+            })
+            .collect::<AppResult<Vec<_>>>()?;
+
+        Ok(Self { rules })
+    }
+
+    /// カテゴリを判定（ユーザー定義ルール → Conventional Commits → other）
+    pub fn classify(&self, message: &str, branch_name: &str) -> String {
+        let matched = self.rules.iter().find(|(rule, regex)| match rule.target {
+            CategoryRuleTarget::Message => regex.is_match(message),
+            CategoryRuleTarget::Branch => regex.is_match(branch_name),
+        });
+
+        if let Some((rule, _)) = matched {
+            return rule.category.clone();
+        }
+
+        conventional_category(message)
+            .unwrap_or(CATEGORY_OTHER)
+            .to_string()
+    }
+}
+
+/// Conventional Commits のプレフィックスからカテゴリを判定
+///
+/// `feat: ...` / `fix(api): ...` / `refactor!: ...` の形式を認識する（大文字小文字は区別しない）。
+pub fn conventional_category(message: &str) -> Option<&'static str> {
+    let subject = message.lines().next()?.trim_start();
+    let (prefix, _) = subject.split_once(':')?;
+
+    let prefix = prefix.trim_end().trim_end_matches('!');
+    let commit_type = match prefix.split_once('(') {
+        Some((commit_type, scope)) if scope.ends_with(')') => commit_type,
+        Some(_) => return None,
+        None => prefix,
+    };
+
+    CONVENTIONAL_CATEGORIES
+        .iter()
+        .find(|category| category.eq_ignore_ascii_case(commit_type))
+        .copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conventional_category() {
+        assert_eq!(conventional_category("feat: add login"), Some("feat"));
+        assert_eq!(conventional_category("Fix(api): null check"), Some("fix"));
+        assert_eq!(
+            conventional_category("refactor!: drop v1"),
+            Some("refactor")
+        );
+        assert_eq!(
+            conventional_category("docs(readme)!: rewrite\n\nbody"),
+            Some("docs")
+        );
+        assert_eq!(conventional_category("feature: not a type"), None);
+        assert_eq!(conventional_category("Merge branch 'main'"), None);
+        assert_eq!(conventional_category("fix(api: broken scope"), None);
+    }
+
+    #[test]
+    fn test_rules_take_precedence_in_order() {
+        let classifier = CategoryClassifier::new(&[
+            CategoryRule {
+                category: "release".to_string(),
+                target: CategoryRuleTarget::Branch,
+                pattern: "^release/".to_string(),
+            },
+            CategoryRule {
+                category: "deps".to_string(),
+                target: CategoryRuleTarget::Message,
+                pattern: "(?i)bump .* from".to_string(),
+            },
+        ])
+        .unwrap();
+
+        assert_eq!(classifier.classify("fix: typo", "release/1.0"), "release");
+        assert_eq!(
+            classifier.classify("chore: Bump serde from 1.0 to 1.1", "main"),
+            "deps"
+        );
+        assert_eq!(classifier.classify("test: add cases", "main"), "test");
+        assert_eq!(classifier.classify("WIP", "main"), CATEGORY_OTHER);
+    }
+
+    #[test]
+    fn test_invalid_rule_is_rejected() {
+        let result = CategoryClassifier::new(&[CategoryRule {
+            category: "broken".to_string(),
+            target: CategoryRuleTarget::Message,
+            pattern: "(".to_string(),
+        }]);
+
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+}
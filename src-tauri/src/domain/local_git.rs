@@ -0,0 +1,239 @@
+//! ローカル git リポジトリの取り込み
+//!
+//! GitLab 以外でホストされている（またはローカルにミラーした）リポジトリを、
+//! `git log --numstat` の出力からコミットとして読み込む。
+//! 取り込んだコミットは合成プロジェクト（負のプロジェクト ID、名前空間 `local`）に属し、
+//! 横断ビューで GitLab のコミットと並べて集計できる。
+
+use crate::domain::commit_category::{conventional_category, CATEGORY_OTHER};
+use crate::domain::{parse_gitlab_date, Commit, Project};
+use crate::error::{AppError, AppResult};
+use chrono::{DateTime, Utc};
+use std::path::Path;
+
+/// 合成プロジェクトの名前空間
+pub const LOCAL_NAMESPACE: &str = "local";
+
+/// `git log` に渡す出力形式（コミットごとに RS、項目ごとに US で区切る）
+///
+/// 項目は SHA / 作者名 / 作者メール / 作成日時 / コミット日時 / メッセージの順で、
+/// 最後の US の後に `--numstat` の行が続く。
+pub const GIT_LOG_FORMAT: &str = "--format=%x1e%H%x1f%an%x1f%ae%x1f%aI%x1f%cI%x1f%B%x1f";
+
+const RECORD_SEPARATOR: char = '\u{1e}';
+const FIELD_SEPARATOR: char = '\u{1f}';
+
+/// ローカル取り込みの合成プロジェクト ID か（GitLab のプロジェクト ID は正の値）
+pub fn is_local_project_id(project_id: i64) -> bool {
+    project_id < 0
+}
+
+/// リポジトリのパスから合成プロジェクト ID を決める
+///
+/// 同じパスを再取り込みしたときに同じプロジェクトになるよう、パスのハッシュ（FNV-1a）から
+/// 負の値を作る。SQLite 側のプロジェクト ID は 32bit のため、その範囲に収める。
+pub fn local_project_id(path: &Path) -> i64 {
+    let hash = path
+        .to_string_lossy()
+        .bytes()
+        .fold(0x811c_9dc5_u32, |hash, byte| {
+            (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+        });
+    -1 - i64::from(hash % i32::MAX as u32)
+}
+
+/// ローカルリポジトリの合成プロジェクトを作成
+///
+/// 名前を省略した場合はディレクトリ名（末尾の `.git` を除く）を使う。
+pub fn local_project(path: &Path, name: Option<&str>) -> AppResult<Project> {
+    let name = name
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .or_else(|| {
+            path.file_name().map(|name| {
+                let name = name.to_string_lossy();
+                name.strip_suffix(".git").unwrap_or(&name).to_string()
+            })
+        })
+        .filter(|name| !name.is_empty())
+        .ok_or_else(|| AppError::Validation("プロジェクト名を指定してください".to_string()))?;
+
+    Ok(Project {
+        project_id: local_project_id(path),
+        path_with_namespace: format!("{}/{}", LOCAL_NAMESPACE, name),
+        name,
+        web_url: format!("file://{}", path.display()),
+    })
+}
+
+/// [`GIT_LOG_FORMAT`] と `--numstat` を指定した `git log` の出力をコミットに変換
+///
+/// バイナリファイル（行数が `-`）は 0 行として数える。
+/// コミット日時が解釈できない場合は GitLab からの収集と同じく日時不正として扱う。
+pub fn parse_git_log_numstat(
+    project_id: i64,
+    branch_name: &str,
+    output: &str,
+) -> AppResult<Vec<Commit>> {
+    output
+        .split(RECORD_SEPARATOR)
+        .filter(|record| !record.trim().is_empty())
+        .map(|record| parse_record(project_id, branch_name, record))
+        .collect()
+}
+
+fn parse_record(project_id: i64, branch_name: &str, record: &str) -> AppResult<Commit> {
+    let fields: Vec<&str> = record.splitn(7, FIELD_SEPARATOR).collect();
+    let [sha, author_name, author_email, authored_date, committed_date, message, numstat] =
+        fields[..]
+    else {
+        return Err(AppError::Validation(format!(
+            "git log の出力を解釈できません: {}",
+            record.lines().next().unwrap_or_default()
+        )));
+    };
+
+    let (additions, deletions) = numstat
+        .lines()
+        .filter_map(|line| {
+            let mut columns = line.splitn(3, '\t');
+            let added = columns.next()?;
+            let deleted = columns.next()?;
+            columns.next()?;
+            Some((parse_line_count(added), parse_line_count(deleted)))
+        })
+        .fold((0, 0), |(additions, deletions), (added, deleted)| {
+            (additions + added, deletions + deleted)
+        });
+
+    let parsed_date = parse_gitlab_date(committed_date.trim());
+    let message = message.trim_end().to_string();
+    let category = conventional_category(&message)
+        .unwrap_or(CATEGORY_OTHER)
+        .to_string();
+
+    Ok(Commit {
+        project_id,
+        branch_name: branch_name.to_string(),
+        sha: sha.trim().to_string(),
+        message,
+        committed_date_utc: parsed_date.unwrap_or(DateTime::<Utc>::UNIX_EPOCH),
+        authored_date_utc: parse_gitlab_date(authored_date.trim()),
+        author_name: author_name.to_string(),
+        author_email: Some(author_email.trim())
+            .filter(|email| !email.is_empty())
+            .map(str::to_string),
+        additions,
+        deletions,
+        stats_missing: false,
+        category,
+        is_bot: false,
+        date_invalid: parsed_date.is_none(),
+    })
+}
+
+/// numstat の行数（バイナリファイルの `-` は 0）
+fn parse_line_count(value: &str) -> i64 {
+    value.trim().parse().unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(fields: [&str; 6], numstat: &str) -> String {
+        format!(
+            "{}{}{}{}",
+            RECORD_SEPARATOR,
+            fields.join(&FIELD_SEPARATOR.to_string()),
+            FIELD_SEPARATOR,
+            numstat
+        )
+    }
+
+    #[test]
+    fn test_parse_git_log_numstat() {
+        let output = [
+            record(
+                [
+                    "aaa111",
+                    "Alice",
+                    "alice@example.com",
+                    "2025-12-31T23:00:00+09:00",
+                    "2026-01-02T10:00:00+09:00",
+                    "feat: add parser\n\nlong body\n",
+                ],
+                "\n10\t2\tsrc/parser.rs\n-\t-\tassets/logo.png\n3\t0\tREADME.md\n",
+            ),
+            record(
+                [
+                    "bbb222",
+                    "Bob",
+                    "",
+                    "2026-01-03T00:00:00Z",
+                    "2026-01-03T00:00:00Z",
+                    "Merge branch 'topic'\n",
+                ],
+                "\n",
+            ),
+        ]
+        .concat();
+
+        let commits = parse_git_log_numstat(-5, "main", &output).unwrap();
+        assert_eq!(commits.len(), 2);
+
+        let first = &commits[0];
+        assert_eq!(first.project_id, -5);
+        assert_eq!(first.branch_name, "main");
+        assert_eq!(first.sha, "aaa111");
+        assert_eq!(first.message, "feat: add parser\n\nlong body");
+        assert_eq!(first.category, "feat");
+        assert_eq!((first.additions, first.deletions), (13, 2));
+        assert_eq!(first.author_email.as_deref(), Some("alice@example.com"));
+        assert_eq!(
+            first.committed_date_utc.to_rfc3339(),
+            "2026-01-02T01:00:00+00:00"
+        );
+        assert_eq!(
+            first.authored_date_utc.map(|date| date.to_rfc3339()),
+            Some("2025-12-31T14:00:00+00:00".to_string())
+        );
+        assert!(!first.stats_missing);
+
+        // マージコミット（差分なし）は 0 行、メールが空なら None で作者名がユーザーキー
+        let merge = &commits[1];
+        assert_eq!((merge.additions, merge.deletions), (0, 0));
+        assert_eq!(merge.author_email, None);
+        assert_eq!(merge.user_key(), "Bob");
+    }
+
+    #[test]
+    fn test_parse_git_log_numstat_invalid_record() {
+        let output = format!("{}aaa111{}Alice", RECORD_SEPARATOR, FIELD_SEPARATOR);
+        assert!(parse_git_log_numstat(-5, "main", &output).is_err());
+        assert!(parse_git_log_numstat(-5, "main", "").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_local_project() {
+        let path = Path::new("/srv/mirrors/tools.git");
+        let project = local_project(path, None).unwrap();
+        assert_eq!(project.name, "tools");
+        assert_eq!(project.path_with_namespace, "local/tools");
+        assert_eq!(project.web_url, "file:///srv/mirrors/tools.git");
+        assert!(project.is_in_namespace(LOCAL_NAMESPACE));
+
+        // 同じパスは同じ ID、GitLab の ID と衝突しない負の値
+        assert_eq!(project.project_id, local_project_id(path));
+        assert!(is_local_project_id(project.project_id));
+        assert!(project.project_id >= i64::from(i32::MIN));
+        assert_ne!(
+            project.project_id,
+            local_project_id(Path::new("/srv/mirrors/other.git"))
+        );
+
+        let named = local_project(path, Some("Internal Tools")).unwrap();
+        assert_eq!(named.name, "Internal Tools");
+    }
+}
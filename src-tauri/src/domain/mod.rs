@@ -2,12 +2,26 @@
 //!
 //! ビジネスエンティティを定義する。
 
+pub mod bot;
 pub mod branch;
 pub mod commit;
+pub mod commit_category;
 pub mod gitlab_connection;
+pub mod local_git;
 pub mod project;
+pub mod project_tag;
+pub mod reporting;
+pub mod team;
+pub mod work_calendar;
 
+pub use bot::*;
 pub use branch::*;
 pub use commit::*;
+pub use commit_category::*;
 pub use gitlab_connection::*;
+pub use local_git::*;
 pub use project::*;
+pub use project_tag::*;
+pub use reporting::*;
+pub use team::*;
+pub use work_calendar::*;
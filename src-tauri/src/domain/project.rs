@@ -35,6 +35,31 @@ impl From<GitLabProject> for Project {
     }
 }
 
+impl Project {
+    /// 名前空間（path_with_namespace の最後の要素を除いた部分、例: group/subgroup）
+    pub fn namespace(&self) -> &str {
+        self.path_with_namespace
+            .rsplit_once('/')
+            .map_or("", |(namespace, _)| namespace)
+    }
+
+    /// 指定した名前空間（サブグループを含む）に属するか（大文字小文字を区別しない）
+    pub fn is_in_namespace(&self, namespace: &str) -> bool {
+        let namespace = namespace.trim_matches('/');
+        let own = self.namespace();
+        !namespace.is_empty()
+            && (own.eq_ignore_ascii_case(namespace)
+                || own
+                    .get(..namespace.len() + 1)
+                    .is_some_and(|prefix| prefix.eq_ignore_ascii_case(&format!("{}/", namespace))))
+    }
+
+    /// コミットの Web UI URL（例: https://gitlab.example.com/group/project/-/commit/<sha>）
+    pub fn commit_url(&self, sha: &str) -> String {
+        format!("{}/-/commit/{}", self.web_url.trim_end_matches('/'), sha)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -46,6 +71,8 @@ mod tests {
             name: "my-project".to_string(),
             path_with_namespace: "group/my-project".to_string(),
             web_url: "https://gitlab.example.com/group/my-project".to_string(),
+            namespace: None,
+            topics: Vec::new(),
         };
 
         let project = Project::from(gitlab_project);
@@ -54,4 +81,36 @@ mod tests {
         assert_eq!(project.name, "my-project");
         assert_eq!(project.path_with_namespace, "group/my-project");
     }
+
+    #[test]
+    fn test_commit_url() {
+        let project = Project {
+            project_id: 1,
+            name: "my-project".to_string(),
+            path_with_namespace: "group/my-project".to_string(),
+            web_url: "https://gitlab.example.com/group/my-project/".to_string(),
+        };
+
+        assert_eq!(
+            project.commit_url("abc123"),
+            "https://gitlab.example.com/group/my-project/-/commit/abc123"
+        );
+    }
+
+    #[test]
+    fn test_namespace_matching_includes_subgroups() {
+        let project = Project {
+            project_id: 1,
+            name: "web".to_string(),
+            path_with_namespace: "Frontend/apps/web".to_string(),
+            web_url: "https://gitlab.example.com/Frontend/apps/web".to_string(),
+        };
+
+        assert_eq!(project.namespace(), "Frontend/apps");
+        assert!(project.is_in_namespace("frontend"));
+        assert!(project.is_in_namespace("frontend/apps/"));
+        assert!(!project.is_in_namespace("front"));
+        assert!(!project.is_in_namespace("frontend/apps/web"));
+        assert!(!project.is_in_namespace(""));
+    }
 }
@@ -0,0 +1,203 @@
+//! プロジェクトタグ
+//!
+//! 横断ビューの対象プロジェクトを絞り込むためのタグ。手動で付与するものと、
+//! プロジェクト同期時に GitLab の名前空間/トピックから自動で取り込むものがある。
+
+use crate::domain::Project;
+use crate::gitlab::GitLabProject;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// タグの付与元
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectTagSource {
+    /// 手動
+    Manual,
+    /// GitLab の名前空間（同期時に自動取り込み）
+    Namespace,
+    /// GitLab のトピック（同期時に自動取り込み）
+    Topic,
+}
+
+impl ProjectTagSource {
+    /// 保存用の文字列
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Manual => "manual",
+            Self::Namespace => "namespace",
+            Self::Topic => "topic",
+        }
+    }
+
+    /// 保存値からパース
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "manual" => Some(Self::Manual),
+            "namespace" => Some(Self::Namespace),
+            "topic" => Some(Self::Topic),
+            _ => None,
+        }
+    }
+}
+
+/// プロジェクトタグ
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectTag {
+    /// タグ名
+    pub tag: String,
+    /// 付与元
+    pub source: ProjectTagSource,
+}
+
+/// プロジェクトごとのタグ
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectTags {
+    /// プロジェクト ID
+    pub project_id: i64,
+    /// タグ（付与元ごと）
+    pub tags: Vec<ProjectTag>,
+}
+
+/// GitLab プロジェクトから自動取り込みするタグ（名前空間とトピック）
+pub fn auto_project_tags(project: &GitLabProject) -> Vec<ProjectTag> {
+    let namespace = match &project.namespace {
+        Some(namespace) => namespace.full_path.clone(),
+        None => project
+            .path_with_namespace
+            .rsplit_once('/')
+            .map_or(String::new(), |(namespace, _)| namespace.to_string()),
+    };
+
+    let mut seen = HashSet::new();
+    std::iter::once(ProjectTag {
+        tag: namespace,
+        source: ProjectTagSource::Namespace,
+    })
+    .chain(project.topics.iter().map(|topic| ProjectTag {
+        tag: topic.trim().to_string(),
+        source: ProjectTagSource::Topic,
+    }))
+    .filter(|tag| !tag.tag.is_empty() && seen.insert(tag.clone()))
+    .collect()
+}
+
+/// 横断ビューのプロジェクト絞り込み条件
+///
+/// いずれかの条件に一致するプロジェクトを対象とする（条件がすべて空なら全プロジェクト）。
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectFilter {
+    /// タグ（大文字小文字を区別しない、付与元は問わない）
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// 名前空間（サブグループを含む）
+    #[serde(default)]
+    pub namespaces: Vec<String>,
+    /// プロジェクト ID
+    #[serde(default)]
+    pub project_ids: Vec<i64>,
+}
+
+impl ProjectFilter {
+    /// 条件が指定されていないか
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty() && self.namespaces.is_empty() && self.project_ids.is_empty()
+    }
+
+    /// プロジェクトが条件に一致するか
+    pub fn matches(&self, project: &Project, tags: &[ProjectTag]) -> bool {
+        self.project_ids.contains(&project.project_id)
+            || self
+                .namespaces
+                .iter()
+                .any(|namespace| project.is_in_namespace(namespace))
+            || self.tags.iter().any(|wanted| {
+                tags.iter()
+                    .any(|tag| tag.tag.eq_ignore_ascii_case(wanted.trim()))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gitlab::GitLabNamespace;
+
+    fn tag(tag: &str, source: ProjectTagSource) -> ProjectTag {
+        ProjectTag {
+            tag: tag.to_string(),
+            source,
+        }
+    }
+
+    #[test]
+    fn test_auto_project_tags() {
+        let project = GitLabProject {
+            id: 1,
+            name: "web".to_string(),
+            path_with_namespace: "frontend/apps/web".to_string(),
+            web_url: "https://gitlab.example.com/frontend/apps/web".to_string(),
+            namespace: Some(GitLabNamespace {
+                full_path: "frontend/apps".to_string(),
+            }),
+            topics: vec!["react".to_string(), " react ".to_string(), "".to_string()],
+        };
+
+        assert_eq!(
+            auto_project_tags(&project),
+            vec![
+                tag("frontend/apps", ProjectTagSource::Namespace),
+                tag("react", ProjectTagSource::Topic),
+            ]
+        );
+
+        // namespace が返らない場合は path_with_namespace から求める
+        let project = GitLabProject {
+            namespace: None,
+            topics: Vec::new(),
+            ..project
+        };
+        assert_eq!(
+            auto_project_tags(&project),
+            vec![tag("frontend/apps", ProjectTagSource::Namespace)]
+        );
+    }
+
+    #[test]
+    fn test_filter_matches_any_condition() {
+        let project = Project {
+            project_id: 10,
+            name: "web".to_string(),
+            path_with_namespace: "frontend/apps/web".to_string(),
+            web_url: "https://gitlab.example.com/frontend/apps/web".to_string(),
+        };
+        let tags = vec![tag("Frontend-Repos", ProjectTagSource::Manual)];
+
+        assert!(ProjectFilter::default().is_empty());
+        assert!(ProjectFilter {
+            tags: vec!["frontend-repos".to_string()],
+            ..Default::default()
+        }
+        .matches(&project, &tags));
+        assert!(ProjectFilter {
+            namespaces: vec!["frontend".to_string()],
+            ..Default::default()
+        }
+        .matches(&project, &[]));
+        assert!(ProjectFilter {
+            tags: vec!["backend".to_string()],
+            project_ids: vec![10],
+            ..Default::default()
+        }
+        .matches(&project, &tags));
+        assert!(!ProjectFilter {
+            tags: vec!["backend".to_string()],
+            namespaces: vec!["backend".to_string()],
+            project_ids: vec![11],
+        }
+        .matches(&project, &tags));
+    }
+}
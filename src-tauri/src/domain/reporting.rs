@@ -0,0 +1,70 @@
+//! 集計レポート設定
+//!
+//! 曜日・時間帯など、ローカル時刻で見る集計に使うタイムゾーンと、
+//! どの日時（コミット日時/作成日時）で期間に振り分けるかを保持する。
+//! 保存済みコミットの日時は UTC のままで、集計時に変換する。
+
+use crate::domain::CommitDateBasis;
+use crate::error::{AppError, AppResult};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+/// 既定のタイムゾーン
+pub const DEFAULT_REPORTING_TIME_ZONE: &str = "UTC";
+
+/// 集計レポート設定
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportingSettings {
+    /// 集計に使うタイムゾーン（IANA 名、例: Asia/Tokyo）
+    #[serde(default = "default_time_zone")]
+    pub time_zone: String,
+    /// 集計で期間に振り分ける日時（既定: コミット日時、リクエストで上書き可能）
+    #[serde(default)]
+    pub date_basis: CommitDateBasis,
+}
+
+fn default_time_zone() -> String {
+    DEFAULT_REPORTING_TIME_ZONE.to_string()
+}
+
+impl Default for ReportingSettings {
+    fn default() -> Self {
+        Self {
+            time_zone: default_time_zone(),
+            date_basis: CommitDateBasis::default(),
+        }
+    }
+}
+
+impl ReportingSettings {
+    /// タイムゾーンを解決
+    pub fn tz(&self) -> AppResult<Tz> {
+        parse_time_zone(&self.time_zone)
+    }
+}
+
+/// IANA タイムゾーン名を解決
+pub fn parse_time_zone(name: &str) -> AppResult<Tz> {
+    name.trim().parse::<Tz>().map_err(|_| {
+        AppError::Validation(format!(
+            "タイムゾーン '{}' が不正です（例: Asia/Tokyo, UTC）",
+            name
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_time_zone() {
+        assert_eq!(parse_time_zone("Asia/Tokyo").unwrap(), Tz::Asia__Tokyo);
+        assert_eq!(ReportingSettings::default().tz().unwrap(), Tz::UTC);
+        assert!(matches!(
+            parse_time_zone("Mars/Olympus"),
+            Err(AppError::Validation(_))
+        ));
+    }
+}
@@ -0,0 +1,168 @@
+//! チーム
+//!
+//! ユーザーキーをまとめた名前付きグループ。メンバーの所属は期間付きにでき、
+//! チーム異動があっても異動前後のコミットをそれぞれのチームに集計できる。
+
+use crate::error::{AppError, AppResult};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// チーム
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Team {
+    /// チーム ID
+    pub id: i64,
+    /// チーム名（一意）
+    pub name: String,
+    /// メンバー（同じユーザーが複数期間所属してもよい）
+    pub members: Vec<TeamMember>,
+}
+
+/// チームメンバーの所属
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamMember {
+    /// ユーザーキー（コミットの author_email、なければ author_name）
+    pub user_key: String,
+    /// 所属開始日（この日を含む、None は無期限）
+    #[serde(default)]
+    pub valid_from: Option<NaiveDate>,
+    /// 所属終了日（この日を含む、None は無期限）
+    #[serde(default)]
+    pub valid_until: Option<NaiveDate>,
+}
+
+impl TeamMember {
+    /// 指定日に所属しているか
+    pub fn is_active_on(&self, date: NaiveDate) -> bool {
+        self.overlaps(date, date)
+    }
+
+    /// 期間 [start, end] と所属期間が重なるか
+    pub fn overlaps(&self, start: NaiveDate, end: NaiveDate) -> bool {
+        self.valid_from.is_none_or(|from| from <= end)
+            && self.valid_until.is_none_or(|until| until >= start)
+    }
+
+    /// 指定年月に一日でも所属しているか
+    pub fn is_active_in_month(&self, year: i32, month: u32) -> bool {
+        let Some(start) = NaiveDate::from_ymd_opt(year, month, 1) else {
+            return false;
+        };
+        let end = start
+            .checked_add_months(chrono::Months::new(1))
+            .and_then(|next| next.pred_opt())
+            .unwrap_or(start);
+        self.overlaps(start, end)
+    }
+}
+
+impl Team {
+    /// ユーザーが指定日にこのチームに所属しているか
+    pub fn includes(&self, user_key: &str, date: NaiveDate) -> bool {
+        self.members
+            .iter()
+            .any(|member| member.user_key == user_key && member.is_active_on(date))
+    }
+
+    /// 指定年月に所属しているメンバー数（重複なし）
+    pub fn member_count_in_month(&self, year: i32, month: u32) -> usize {
+        let mut keys: Vec<&str> = self
+            .members
+            .iter()
+            .filter(|member| member.is_active_in_month(year, month))
+            .map(|member| member.user_key.as_str())
+            .collect();
+        keys.sort_unstable();
+        keys.dedup();
+        keys.len()
+    }
+}
+
+/// チーム名とメンバーを検証し、前後の空白を除いた名前を返す
+pub fn validate_team(name: &str, members: &[TeamMember]) -> AppResult<String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(AppError::Validation("チーム名が空です".to_string()));
+    }
+
+    for (index, member) in members.iter().enumerate() {
+        if member.user_key.trim().is_empty() {
+            return Err(AppError::Validation(format!(
+                "メンバー {} のユーザーキーが空です",
+                index + 1
+            )));
+        }
+        if let (Some(from), Some(until)) = (member.valid_from, member.valid_until) {
+            if from > until {
+                return Err(AppError::Validation(format!(
+                    "メンバー {} の所属期間が不正です（{} > {}）",
+                    index + 1,
+                    from,
+                    until
+                )));
+            }
+        }
+    }
+
+    Ok(name.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn member(user_key: &str, from: Option<NaiveDate>, until: Option<NaiveDate>) -> TeamMember {
+        TeamMember {
+            user_key: user_key.to_string(),
+            valid_from: from,
+            valid_until: until,
+        }
+    }
+
+    #[test]
+    fn test_time_bounded_membership() {
+        let team = Team {
+            id: 1,
+            name: "Platform".to_string(),
+            members: vec![
+                member("alice@example.com", None, Some(date(2026, 3, 31))),
+                member("bob@example.com", Some(date(2026, 4, 15)), None),
+            ],
+        };
+
+        assert!(team.includes("alice@example.com", date(2026, 3, 31)));
+        assert!(!team.includes("alice@example.com", date(2026, 4, 1)));
+        assert!(!team.includes("bob@example.com", date(2026, 4, 14)));
+        assert!(team.includes("bob@example.com", date(2026, 4, 15)));
+
+        assert_eq!(team.member_count_in_month(2026, 3), 1);
+        assert_eq!(team.member_count_in_month(2026, 4), 1);
+        assert_eq!(team.member_count_in_month(2026, 12), 1);
+    }
+
+    #[test]
+    fn test_validate_team() {
+        assert_eq!(validate_team("  Platform ", &[]).unwrap(), "Platform");
+        assert!(matches!(
+            validate_team(" ", &[]),
+            Err(AppError::Validation(_))
+        ));
+        assert!(matches!(
+            validate_team(
+                "Platform",
+                &[member(
+                    "alice",
+                    Some(date(2026, 5, 1)),
+                    Some(date(2026, 4, 1))
+                )]
+            ),
+            Err(AppError::Validation(_))
+        ));
+    }
+}
@@ -0,0 +1,276 @@
+//! 稼働日カレンダー
+//!
+//! 週末・祝日（iCalendar ファイルから取り込み）・ユーザーごとの休暇から稼働日を判定する。
+//! 月次集計を稼働日数で割った「稼働日あたり」の値に使う。
+
+use crate::error::{AppError, AppResult};
+use chrono::{Datelike, NaiveDate, Weekday};
+use serde::{Deserialize, Serialize};
+
+/// 稼働日カレンダー
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkCalendar {
+    /// 休日とする曜日（既定: 土日）
+    #[serde(default = "default_weekend_days")]
+    pub weekend_days: Vec<Weekday>,
+    /// 祝日
+    #[serde(default)]
+    pub holidays: Vec<Holiday>,
+    /// ユーザーごとの休暇
+    #[serde(default)]
+    pub leaves: Vec<UserLeave>,
+}
+
+fn default_weekend_days() -> Vec<Weekday> {
+    vec![Weekday::Sat, Weekday::Sun]
+}
+
+impl Default for WorkCalendar {
+    fn default() -> Self {
+        Self {
+            weekend_days: default_weekend_days(),
+            holidays: Vec::new(),
+            leaves: Vec::new(),
+        }
+    }
+}
+
+/// 祝日
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Holiday {
+    /// 日付
+    pub date: NaiveDate,
+    /// 名称
+    #[serde(default)]
+    pub name: String,
+    /// 取り込み元（iCalendar ファイル名など、手動登録は None）
+    #[serde(default)]
+    pub source: Option<String>,
+}
+
+/// ユーザーの休暇（両端を含む）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserLeave {
+    /// ユーザーキー（コミットの author_email、なければ author_name）
+    pub user_key: String,
+    /// 開始日
+    pub from: NaiveDate,
+    /// 終了日
+    pub until: NaiveDate,
+}
+
+impl WorkCalendar {
+    /// カレンダー上の稼働日か（週末・祝日でない）
+    pub fn is_working_day(&self, date: NaiveDate) -> bool {
+        !self.weekend_days.contains(&date.weekday())
+            && !self.holidays.iter().any(|holiday| holiday.date == date)
+    }
+
+    /// ユーザーの稼働日か（カレンダー上の稼働日で、休暇でない）
+    pub fn is_user_working_day(&self, user_key: &str, date: NaiveDate) -> bool {
+        self.is_working_day(date)
+            && !self.leaves.iter().any(|leave| {
+                leave.user_key == user_key && leave.from <= date && date <= leave.until
+            })
+    }
+
+    /// 指定年月のカレンダー上の稼働日数
+    pub fn working_days_in_month(&self, year: i32, month: u32) -> u32 {
+        days_in_month(year, month)
+            .filter(|date| self.is_working_day(*date))
+            .count() as u32
+    }
+
+    /// 指定年月のユーザーの稼働日数（休暇を除く）
+    pub fn user_working_days_in_month(&self, user_key: &str, year: i32, month: u32) -> u32 {
+        days_in_month(year, month)
+            .filter(|date| self.is_user_working_day(user_key, *date))
+            .count() as u32
+    }
+
+    /// 設定を検証
+    pub fn validate(&self) -> AppResult<()> {
+        if self.weekend_days.len() >= 7 {
+            return Err(AppError::Validation(
+                "すべての曜日を休日にすることはできません".to_string(),
+            ));
+        }
+        for (index, leave) in self.leaves.iter().enumerate() {
+            if leave.user_key.trim().is_empty() {
+                return Err(AppError::Validation(format!(
+                    "休暇 {} のユーザーキーが空です",
+                    index + 1
+                )));
+            }
+            if leave.from > leave.until {
+                return Err(AppError::Validation(format!(
+                    "休暇 {} の期間が不正です（{} > {}）",
+                    index + 1,
+                    leave.from,
+                    leave.until
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// 祝日を取り込む（同じ取り込み元の祝日は置き換え、日付順に並べ替える）
+    ///
+    /// 戻り値は取り込んだ祝日の件数。
+    pub fn import_holidays(&mut self, source: &str, holidays: Vec<Holiday>) -> usize {
+        self.holidays
+            .retain(|holiday| holiday.source.as_deref() != Some(source));
+        let count = holidays.len();
+        self.holidays.extend(holidays);
+        self.holidays.sort_by_key(|holiday| holiday.date);
+        count
+    }
+}
+
+fn days_in_month(year: i32, month: u32) -> impl Iterator<Item = NaiveDate> {
+    let first = NaiveDate::from_ymd_opt(year, month, 1);
+    first
+        .into_iter()
+        .flat_map(|first| first.iter_days())
+        .take_while(move |date| date.month() == month)
+}
+
+/// iCalendar（.ics）の終日イベントを祝日として読み込む
+///
+/// VEVENT の DTSTART/DTEND（DTEND は含まない）/SUMMARY を使う。
+/// 繰り返し（RRULE）は展開せず、最初の日付のみを取り込む。
+pub fn parse_ical_holidays(content: &str, source: &str) -> AppResult<Vec<Holiday>> {
+    // 折り返し行（先頭が空白/タブ）を前の行に連結
+    let mut lines: Vec<String> = Vec::new();
+    for line in content.lines() {
+        let line = line.trim_end_matches('\r');
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ => lines.push(line.to_string()),
+        }
+    }
+
+    let mut holidays = Vec::new();
+    let mut event: Option<(Option<NaiveDate>, Option<NaiveDate>, String)> = None;
+    for line in &lines {
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        // プロパティ名からパラメータ（;VALUE=DATE など）を除く
+        let name = name
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_uppercase();
+        match (name.as_str(), event.as_mut()) {
+            ("BEGIN", _) if value.eq_ignore_ascii_case("VEVENT") => {
+                event = Some((None, None, String::new()));
+            }
+            ("END", Some(_)) if value.eq_ignore_ascii_case("VEVENT") => {
+                let (start, end, summary) = event.take().unwrap_or_default();
+                let start = start.ok_or_else(|| {
+                    AppError::Validation(format!(
+                        "iCalendar の DTSTART がないイベントがあります: {}",
+                        summary
+                    ))
+                })?;
+                // DTEND は含まない（省略時は 1 日）
+                let end = end
+                    .filter(|end| *end > start)
+                    .unwrap_or(start + chrono::Days::new(1));
+                for date in start.iter_days().take_while(|date| *date < end) {
+                    holidays.push(Holiday {
+                        date,
+                        name: summary.clone(),
+                        source: Some(source.to_string()),
+                    });
+                }
+            }
+            ("DTSTART", Some(event)) => event.0 = Some(parse_ical_date(value)?),
+            ("DTEND", Some(event)) => event.1 = Some(parse_ical_date(value)?),
+            ("SUMMARY", Some(event)) => event.2 = unescape_ical_text(value),
+            _ => {}
+        }
+    }
+
+    Ok(holidays)
+}
+
+/// `20260101` または `20260101T000000Z` 形式の日付（時刻は無視）
+fn parse_ical_date(value: &str) -> AppResult<NaiveDate> {
+    let date = value.trim().get(..8).unwrap_or_default();
+    NaiveDate::parse_from_str(date, "%Y%m%d")
+        .map_err(|_| AppError::Validation(format!("iCalendar の日付が不正です: {}", value.trim())))
+}
+
+fn unescape_ical_text(value: &str) -> String {
+    value
+        .trim()
+        .replace("\\n", " ")
+        .replace("\\N", " ")
+        .replace("\\,", ",")
+        .replace("\\;", ";")
+        .replace("\\\\", "\\")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_working_days_with_holidays_and_leave() {
+        let mut calendar = WorkCalendar::default();
+        // 2026-05: 平日 21 日、うち 5/4-5/6 が祝日
+        assert_eq!(calendar.working_days_in_month(2026, 5), 21);
+
+        let ics = "BEGIN:VCALENDAR\r\nBEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20260504\r\nDTEND;VALUE=DATE:20260507\r\nSUMMARY:Golden\r\n  Week\r\nEND:VEVENT\r\nBEGIN:VEVENT\r\nDTSTART:20260503T000000Z\r\nSUMMARY:憲法記念日\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n";
+        let holidays = parse_ical_holidays(ics, "jp.ics").unwrap();
+        assert_eq!(holidays.len(), 4);
+        assert_eq!(holidays[0].name, "Golden Week");
+        assert_eq!(holidays[2].date, date(2026, 5, 6));
+
+        assert_eq!(calendar.import_holidays("jp.ics", holidays.clone()), 4);
+        assert_eq!(calendar.working_days_in_month(2026, 5), 18);
+        // 同じ取り込み元は置き換え
+        calendar.import_holidays("jp.ics", holidays);
+        assert_eq!(calendar.holidays.len(), 4);
+
+        calendar.leaves.push(UserLeave {
+            user_key: "alice@example.com".to_string(),
+            from: date(2026, 5, 8),
+            until: date(2026, 5, 12),
+        });
+        // 5/8(金), 5/11(月), 5/12(火) の 3 日
+        assert_eq!(
+            calendar.user_working_days_in_month("alice@example.com", 2026, 5),
+            15
+        );
+        assert_eq!(
+            calendar.user_working_days_in_month("bob@example.com", 2026, 5),
+            18
+        );
+    }
+
+    #[test]
+    fn test_validate_and_invalid_ical() {
+        let calendar = WorkCalendar {
+            leaves: vec![UserLeave {
+                user_key: "alice@example.com".to_string(),
+                from: date(2026, 5, 12),
+                until: date(2026, 5, 8),
+            }],
+            ..Default::default()
+        };
+        assert!(matches!(calendar.validate(), Err(AppError::Validation(_))));
+
+        let ics = "BEGIN:VEVENT\nDTSTART:2026-05-04\nEND:VEVENT\n";
+        assert!(parse_ical_holidays(ics, "x.ics").is_err());
+    }
+}
@@ -15,6 +15,8 @@ pub enum AppError {
         message: String,
         /// ユーザーへのガイド（次に取るべき行動）
         guidance: String,
+        /// 一時的なエラー（通信失敗・レート制限・サーバーエラー）で、再試行で回復し得るか
+        retryable: bool,
     },
 
     /// 入力バリデーションエラー
@@ -67,13 +69,27 @@ impl AppError {
         Self::GitLabApi {
             message: msg,
             guidance,
+            retryable: matches!(status, 429 | 500..=599),
         }
     }
 
+    /// 再試行で回復し得る GitLab API エラーか
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            Self::GitLabApi {
+                retryable: true,
+                ..
+            }
+        )
+    }
+
     /// ユーザー向けのメッセージを取得（ガイダンス付き）
     pub fn user_message(&self) -> String {
         match self {
-            Self::GitLabApi { message, guidance } => {
+            Self::GitLabApi {
+                message, guidance, ..
+            } => {
                 format!("{}\n\n💡 {}", message, guidance)
             }
             _ => self.to_string(),
@@ -102,19 +118,33 @@ mod tests {
     fn test_from_gitlab_status_401() {
         let err = AppError::from_gitlab_status(401, "Unauthorized");
         match err {
-            AppError::GitLabApi { message, guidance } => {
+            AppError::GitLabApi {
+                message,
+                guidance,
+                retryable,
+            } => {
                 assert!(message.contains("認証"));
                 assert!(guidance.contains("トークン"));
+                assert!(!retryable);
             }
             _ => panic!("Expected GitLabApi error"),
         }
     }
 
+    #[test]
+    fn test_from_gitlab_status_retryable() {
+        assert!(AppError::from_gitlab_status(429, "Too Many Requests").is_retryable());
+        assert!(AppError::from_gitlab_status(503, "Service Unavailable").is_retryable());
+        assert!(!AppError::from_gitlab_status(404, "Not Found").is_retryable());
+        assert!(!AppError::Validation("invalid".to_string()).is_retryable());
+    }
+
     #[test]
     fn test_user_message_includes_guidance() {
         let err = AppError::GitLabApi {
             message: "テストエラー".to_string(),
             guidance: "再試行してください".to_string(),
+            retryable: false,
         };
         let msg = err.user_message();
         assert!(msg.contains("テストエラー"));
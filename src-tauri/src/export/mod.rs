@@ -0,0 +1,9 @@
+//! エクスポートモジュール
+//!
+//! 月次集計と集計元コミットを CSV / JSON Lines / XLSX に書き出す。
+
+pub mod table;
+pub mod writer;
+
+pub use table::*;
+pub use writer::*;
@@ -0,0 +1,346 @@
+//! エクスポート用テーブル
+//!
+//! 集計レスポンスとコミット一覧を、列選択と作者識別子のマスクを適用した
+//! 表形式に変換する。書き出し形式には依存しない。
+
+use crate::domain::Commit;
+use crate::logging::mask_email;
+use crate::stats::MonthlyStatsResponse;
+use serde::Deserialize;
+
+/// セルの値
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExportValue {
+    /// 文字列
+    Text(String),
+    /// 整数
+    Integer(i64),
+}
+
+impl ExportValue {
+    /// CSV 用の文字列表現
+    pub fn to_text(&self) -> String {
+        match self {
+            Self::Text(value) => value.clone(),
+            Self::Integer(value) => value.to_string(),
+        }
+    }
+}
+
+/// エクスポート用テーブル
+#[derive(Debug, Clone)]
+pub struct ExportTable {
+    /// テーブル名（CSV のファイル名接尾辞、XLSX のシート名、JSONL のレコード種別）
+    pub name: &'static str,
+    /// ヘッダー
+    pub headers: Vec<&'static str>,
+    /// データ行
+    pub rows: Vec<Vec<ExportValue>>,
+}
+
+/// 作者識別子の出力方法
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IdentityMode {
+    /// マスクして出力（既定、FR-019）
+    #[default]
+    Masked,
+    /// そのまま出力
+    Full,
+}
+
+impl IdentityMode {
+    /// ユーザーキーを出力用に変換
+    ///
+    /// メールアドレス形式のキーのみマスクする（作者名のキーは表示名と同じため）。
+    pub fn apply(&self, user_key: &str) -> String {
+        match self {
+            Self::Full => user_key.to_string(),
+            Self::Masked if user_key.contains('@') => mask_email(user_key),
+            Self::Masked => user_key.to_string(),
+        }
+    }
+}
+
+/// 集計テーブルの列
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SummaryColumn {
+    UserKey,
+    DisplayName,
+    Month,
+    TotalLines,
+    MissingCount,
+}
+
+impl SummaryColumn {
+    /// 全列（既定の並び順）
+    pub const ALL: [Self; 5] = [
+        Self::UserKey,
+        Self::DisplayName,
+        Self::Month,
+        Self::TotalLines,
+        Self::MissingCount,
+    ];
+
+    /// ヘッダー名
+    pub fn header(&self) -> &'static str {
+        match self {
+            Self::UserKey => "user_key",
+            Self::DisplayName => "display_name",
+            Self::Month => "month",
+            Self::TotalLines => "total_lines",
+            Self::MissingCount => "missing_count",
+        }
+    }
+}
+
+/// コミットテーブルの列
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CommitColumn {
+    ProjectId,
+    BranchName,
+    Sha,
+    CommittedDate,
+    UserKey,
+    AuthorName,
+    Additions,
+    Deletions,
+    TotalLines,
+    Message,
+    Category,
+}
+
+impl CommitColumn {
+    /// 全列（既定の並び順）
+    pub const ALL: [Self; 11] = [
+        Self::ProjectId,
+        Self::BranchName,
+        Self::Sha,
+        Self::CommittedDate,
+        Self::UserKey,
+        Self::AuthorName,
+        Self::Additions,
+        Self::Deletions,
+        Self::TotalLines,
+        Self::Message,
+        Self::Category,
+    ];
+
+    /// ヘッダー名
+    pub fn header(&self) -> &'static str {
+        match self {
+            Self::ProjectId => "project_id",
+            Self::BranchName => "branch_name",
+            Self::Sha => "sha",
+            Self::CommittedDate => "committed_date_utc",
+            Self::UserKey => "user_key",
+            Self::AuthorName => "author_name",
+            Self::Additions => "additions",
+            Self::Deletions => "deletions",
+            Self::TotalLines => "total_lines",
+            Self::Message => "message",
+            Self::Category => "category",
+        }
+    }
+}
+
+/// 集計レスポンスを縦持ち（ユーザー × 月）のテーブルに変換
+///
+/// # Arguments
+/// * `response` - 月次集計レスポンス
+/// * `columns` - 出力する列（空の場合は全列）
+/// * `identity` - ユーザーキーの出力方法
+pub fn build_summary_table(
+    response: &MonthlyStatsResponse,
+    columns: &[SummaryColumn],
+    identity: IdentityMode,
+) -> ExportTable {
+    let columns = if columns.is_empty() {
+        &SummaryColumn::ALL[..]
+    } else {
+        columns
+    };
+
+    let mut rows = Vec::new();
+    for series in &response.series {
+        let user_key = identity.apply(&series.user_key);
+        for (index, month) in response.months.iter().enumerate() {
+            let row = columns
+                .iter()
+                .map(|column| match column {
+                    SummaryColumn::UserKey => ExportValue::Text(user_key.clone()),
+                    SummaryColumn::DisplayName => ExportValue::Text(series.display_name.clone()),
+                    SummaryColumn::Month => ExportValue::Integer(*month as i64),
+                    SummaryColumn::TotalLines => {
+                        ExportValue::Integer(series.totals.get(index).copied().unwrap_or(0))
+                    }
+                    SummaryColumn::MissingCount => ExportValue::Integer(
+                        series.missing_counts.get(index).copied().unwrap_or(0) as i64,
+                    ),
+                })
+                .collect();
+            rows.push(row);
+        }
+    }
+
+    ExportTable {
+        name: "summary",
+        headers: columns
+            .iter()
+            .map(|c| match c {
+                // 値の列名は指標に合わせる
+                SummaryColumn::TotalLines => response.metric.column_name(),
+                c => c.header(),
+            })
+            .collect(),
+        rows,
+    }
+}
+
+/// 集計元コミットをテーブルに変換
+///
+/// # Arguments
+/// * `commits` - 対象コミット一覧（ユーザーフィルタ適用済み）
+/// * `columns` - 出力する列（空の場合は全列）
+/// * `identity` - ユーザーキーの出力方法
+pub fn build_commit_table(
+    commits: &[Commit],
+    columns: &[CommitColumn],
+    identity: IdentityMode,
+) -> ExportTable {
+    let columns = if columns.is_empty() {
+        &CommitColumn::ALL[..]
+    } else {
+        columns
+    };
+
+    let rows = commits
+        .iter()
+        .map(|commit| {
+            columns
+                .iter()
+                .map(|column| match column {
+                    CommitColumn::ProjectId => ExportValue::Integer(commit.project_id),
+                    CommitColumn::BranchName => ExportValue::Text(commit.branch_name.clone()),
+                    CommitColumn::Sha => ExportValue::Text(commit.sha.clone()),
+                    CommitColumn::CommittedDate => {
+                        ExportValue::Text(commit.committed_date_utc.to_rfc3339())
+                    }
+                    CommitColumn::UserKey => ExportValue::Text(identity.apply(&commit.user_key())),
+                    CommitColumn::AuthorName => ExportValue::Text(commit.author_name.clone()),
+                    CommitColumn::Additions => ExportValue::Integer(commit.additions),
+                    CommitColumn::Deletions => ExportValue::Integer(commit.deletions),
+                    CommitColumn::TotalLines => ExportValue::Integer(commit.total_lines()),
+                    CommitColumn::Message => ExportValue::Text(commit.message.clone()),
+                    CommitColumn::Category => ExportValue::Text(commit.category.clone()),
+                })
+                .collect()
+        })
+        .collect();
+
+    ExportTable {
+        name: "commits",
+        headers: columns.iter().map(|c| c.header()).collect(),
+        rows,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::{Metric, UserMonthlySeries};
+    use chrono::{TimeZone, Utc};
+
+    fn create_response() -> MonthlyStatsResponse {
+        MonthlyStatsResponse {
+            months: (1..=12).collect(),
+            metric: Metric::default(),
+            series: vec![UserMonthlySeries {
+                user_key: "alice@example.com".to_string(),
+                display_name: "Alice".to_string(),
+                totals: vec![10; 12],
+                missing_counts: vec![0; 12],
+                category_totals: None,
+                project_totals: None,
+                member_counts: None,
+                contributor_count: None,
+                per_working_day: None,
+            }],
+            excluded_bots: None,
+            outliers: None,
+            working_days: None,
+            trend: None,
+        }
+    }
+
+    #[test]
+    fn test_summary_table_masks_user_key_by_default() {
+        let table = build_summary_table(&create_response(), &[], IdentityMode::default());
+
+        assert_eq!(
+            table.headers,
+            vec![
+                "user_key",
+                "display_name",
+                "month",
+                "total_lines",
+                "missing_count"
+            ]
+        );
+        assert_eq!(table.rows.len(), 12);
+        assert_eq!(
+            table.rows[0][0],
+            ExportValue::Text("a***@e***.com".to_string())
+        );
+    }
+
+    #[test]
+    fn test_summary_table_column_selection() {
+        let table = build_summary_table(
+            &create_response(),
+            &[SummaryColumn::Month, SummaryColumn::UserKey],
+            IdentityMode::Full,
+        );
+
+        assert_eq!(table.headers, vec!["month", "user_key"]);
+        assert_eq!(
+            table.rows[0],
+            vec![
+                ExportValue::Integer(1),
+                ExportValue::Text("alice@example.com".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_commit_table_does_not_expose_email_when_masked() {
+        let commit = Commit {
+            project_id: 1,
+            branch_name: "main".to_string(),
+            sha: "abc".to_string(),
+            message: "test".to_string(),
+            committed_date_utc: Utc.with_ymd_and_hms(2026, 1, 15, 12, 0, 0).unwrap(),
+            authored_date_utc: None,
+            author_name: "Alice".to_string(),
+            author_email: Some("alice@example.com".to_string()),
+            additions: 3,
+            deletions: 2,
+            stats_missing: false,
+            date_invalid: false,
+            category: "other".to_string(),
+            is_bot: false,
+        };
+
+        let table = build_commit_table(&[commit], &[], IdentityMode::Masked);
+
+        let texts: Vec<String> = table.rows[0].iter().map(|v| v.to_text()).collect();
+        assert!(texts.iter().all(|t| !t.contains("alice@example.com")));
+        assert_eq!(table.rows[0].get(8), Some(&ExportValue::Integer(5)));
+        assert_eq!(
+            table.rows[0].get(9),
+            Some(&ExportValue::Text("test".to_string()))
+        );
+    }
+}
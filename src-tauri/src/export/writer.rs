@@ -0,0 +1,214 @@
+//! エクスポートファイル書き出し
+//!
+//! - CSV: 1 テーブル 1 ファイル（2 つ目以降は `<ファイル名>_<テーブル名>.csv`）
+//! - JSON Lines: 1 行 1 レコード、`record` にテーブル名を持つ
+//! - XLSX: 1 テーブル 1 シート
+
+use crate::error::{AppError, AppResult};
+use crate::export::{ExportTable, ExportValue};
+use rust_xlsxwriter::{Format, Workbook};
+use serde::Deserialize;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+/// エクスポート形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    Csv,
+    Jsonl,
+    Xlsx,
+}
+
+/// テーブルをファイルに書き出し、作成したファイルのパスを返す
+pub fn write_tables(
+    tables: &[ExportTable],
+    format: ExportFormat,
+    path: &Path,
+) -> AppResult<Vec<PathBuf>> {
+    match format {
+        ExportFormat::Csv => write_csv(tables, path),
+        ExportFormat::Jsonl => write_jsonl(tables, path).map(|()| vec![path.to_path_buf()]),
+        ExportFormat::Xlsx => write_xlsx(tables, path).map(|()| vec![path.to_path_buf()]),
+    }
+}
+
+fn write_csv(tables: &[ExportTable], path: &Path) -> AppResult<Vec<PathBuf>> {
+    let mut paths = Vec::new();
+
+    for (index, table) in tables.iter().enumerate() {
+        let table_path = if index == 0 {
+            path.to_path_buf()
+        } else {
+            sibling_path(path, table.name)
+        };
+
+        let mut writer = csv::Writer::from_path(&table_path)
+            .map_err(|e| AppError::Storage(format!("CSV ファイル作成失敗: {}", e)))?;
+        writer
+            .write_record(&table.headers)
+            .map_err(|e| AppError::Storage(format!("CSV 書き込み失敗: {}", e)))?;
+        for row in &table.rows {
+            writer
+                .write_record(row.iter().map(ExportValue::to_text))
+                .map_err(|e| AppError::Storage(format!("CSV 書き込み失敗: {}", e)))?;
+        }
+        writer
+            .flush()
+            .map_err(|e| AppError::Storage(format!("CSV 書き込み失敗: {}", e)))?;
+
+        paths.push(table_path);
+    }
+
+    Ok(paths)
+}
+
+fn write_jsonl(tables: &[ExportTable], path: &Path) -> AppResult<()> {
+    let file = File::create(path)
+        .map_err(|e| AppError::Storage(format!("JSONL ファイル作成失敗: {}", e)))?;
+    let mut writer = BufWriter::new(file);
+
+    for table in tables {
+        for row in &table.rows {
+            let mut record = serde_json::Map::new();
+            record.insert("record".to_string(), table.name.into());
+            for (header, value) in table.headers.iter().zip(row) {
+                let value = match value {
+                    ExportValue::Text(text) => serde_json::Value::from(text.as_str()),
+                    ExportValue::Integer(number) => serde_json::Value::from(*number),
+                };
+                record.insert(header.to_string(), value);
+            }
+
+            serde_json::to_writer(&mut writer, &record)
+                .map_err(|e| AppError::Storage(format!("JSONL 書き込み失敗: {}", e)))?;
+            writeln!(writer)
+                .map_err(|e| AppError::Storage(format!("JSONL 書き込み失敗: {}", e)))?;
+        }
+    }
+
+    writer
+        .flush()
+        .map_err(|e| AppError::Storage(format!("JSONL 書き込み失敗: {}", e)))?;
+    Ok(())
+}
+
+fn write_xlsx(tables: &[ExportTable], path: &Path) -> AppResult<()> {
+    let xlsx_error =
+        |e: rust_xlsxwriter::XlsxError| AppError::Storage(format!("XLSX 書き込み失敗: {}", e));
+
+    let mut workbook = Workbook::new();
+    let header_format = Format::new().set_bold();
+
+    for table in tables {
+        let worksheet = workbook.add_worksheet();
+        worksheet.set_name(table.name).map_err(xlsx_error)?;
+
+        for (col, header) in table.headers.iter().enumerate() {
+            worksheet
+                .write_string_with_format(0, col as u16, *header, &header_format)
+                .map_err(xlsx_error)?;
+        }
+        worksheet.set_freeze_panes(1, 0).map_err(xlsx_error)?;
+
+        for (row_index, row) in table.rows.iter().enumerate() {
+            let row_num = row_index as u32 + 1;
+            for (col, value) in row.iter().enumerate() {
+                match value {
+                    ExportValue::Text(text) => {
+                        worksheet.write_string(row_num, col as u16, text.as_str())
+                    }
+                    ExportValue::Integer(number) => {
+                        worksheet.write_number(row_num, col as u16, *number as f64)
+                    }
+                }
+                .map_err(xlsx_error)?;
+            }
+        }
+    }
+
+    workbook.save(path).map_err(xlsx_error)?;
+    Ok(())
+}
+
+/// `stats.csv` → `stats_commits.csv` のような兄弟ファイルのパスを作る
+fn sibling_path(path: &Path, suffix: &str) -> PathBuf {
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "export".to_string());
+    let file_name = match path.extension() {
+        Some(ext) => format!("{}_{}.{}", stem, suffix, ext.to_string_lossy()),
+        None => format!("{}_{}", stem, suffix),
+    };
+    path.with_file_name(file_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn create_tables() -> Vec<ExportTable> {
+        vec![
+            ExportTable {
+                name: "summary",
+                headers: vec!["user_key", "total_lines"],
+                rows: vec![vec![
+                    ExportValue::Text("Alice".to_string()),
+                    ExportValue::Integer(10),
+                ]],
+            },
+            ExportTable {
+                name: "commits",
+                headers: vec!["sha"],
+                rows: vec![vec![ExportValue::Text("abc".to_string())]],
+            },
+        ]
+    }
+
+    #[test]
+    fn test_write_csv_splits_tables_into_files() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("stats.csv");
+
+        let paths = write_tables(&create_tables(), ExportFormat::Csv, &path).unwrap();
+
+        assert_eq!(
+            paths,
+            vec![path.clone(), dir.path().join("stats_commits.csv")]
+        );
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(content, "user_key,total_lines\nAlice,10\n");
+    }
+
+    #[test]
+    fn test_write_jsonl_tags_records() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("stats.jsonl");
+
+        write_tables(&create_tables(), ExportFormat::Jsonl, &path).unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<serde_json::Value> = content
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["record"], "summary");
+        assert_eq!(lines[0]["total_lines"], 10);
+        assert_eq!(lines[1]["record"], "commits");
+    }
+
+    #[test]
+    fn test_write_xlsx_creates_workbook() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("stats.xlsx");
+
+        let paths = write_tables(&create_tables(), ExportFormat::Xlsx, &path).unwrap();
+
+        assert_eq!(paths, vec![path.clone()]);
+        assert!(std::fs::metadata(&path).unwrap().len() > 0);
+    }
+}
@@ -77,6 +77,7 @@ impl GitLabClient {
             .map_err(|e| AppError::GitLabApi {
                 message: format!("リクエスト失敗: {}", e),
                 guidance: "ネットワーク接続を確認してください。".to_string(),
+                retryable: true,
             })?;
 
         let status = response.status();
@@ -92,6 +93,7 @@ impl GitLabClient {
             .map_err(|e| AppError::GitLabApi {
                 message: format!("レスポンスパース失敗: {}", e),
                 guidance: "GitLab API のレスポンス形式が変更された可能性があります。".to_string(),
+                retryable: false,
             })?;
 
         Ok(data)
@@ -1,6 +1,6 @@
 //! GitLab コミット API
 //!
-//! コミット一覧の取得（ページング、期間指定、stats 付き）。
+//! コミット一覧の取得（ページング、期間指定、stats 付き）と単一コミットの取得。
 
 use crate::error::AppResult;
 use crate::gitlab::{GitLabClient, GitLabCommit};
@@ -37,6 +37,19 @@ impl GitLabClient {
         self.get_all_pages(&path).await
     }
 
+    /// 単一コミットを stats 付きで取得
+    ///
+    /// 一覧 API で stats が欠損したコミットの再取得に使う。
+    pub async fn get_commit(&self, project_id: i64, sha: &str) -> AppResult<GitLabCommit> {
+        let path = format!(
+            "/projects/{}/repository/commits/{}?stats=true",
+            project_id,
+            urlencoding::encode(sha)
+        );
+
+        self.get(&path).await
+    }
+
     /// アクセス可能なプロジェクト一覧を取得
     pub async fn list_projects(&self) -> AppResult<Vec<crate::gitlab::GitLabProject>> {
         let path = "/projects?membership=true&simple=true";
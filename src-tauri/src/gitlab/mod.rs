@@ -6,7 +6,8 @@ pub mod branches;
 pub mod client;
 pub mod commits;
 pub mod types;
+pub mod users;
 
 pub use client::*;
 pub use types::*;
-// NOTE: branches と commits は client の impl 拡張なので、client 経由で使用する
+// NOTE: branches / commits / users は client の impl 拡張なので、client 経由で使用する
@@ -18,6 +18,21 @@ pub struct GitLabProject {
 
     /// Web UI の URL
     pub web_url: String,
+
+    /// 所属名前空間（グループ/ユーザー）
+    #[serde(default)]
+    pub namespace: Option<GitLabNamespace>,
+
+    /// トピック
+    #[serde(default)]
+    pub topics: Vec<String>,
+}
+
+/// GitLab 名前空間（プロジェクトの namespace フィールド）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitLabNamespace {
+    /// 名前空間のフルパス（例: group/subgroup）
+    pub full_path: String,
 }
 
 /// GitLab ブランチ（API レスポンス）
@@ -44,6 +59,10 @@ pub struct GitLabCommit {
     /// コミット日時（ISO8601）
     pub committed_date: String,
 
+    /// 作成日時（ISO8601、古い GitLab では返らない場合あり）
+    #[serde(default)]
+    pub authored_date: Option<String>,
+
     /// 作者名
     pub author_name: String,
 
@@ -66,3 +85,28 @@ pub struct GitLabCommitStats {
     /// 総変更行数
     pub total: i64,
 }
+
+/// GitLab ユーザー（API レスポンス）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitLabUser {
+    /// ユーザー ID
+    pub id: i64,
+
+    /// ユーザー名
+    pub username: String,
+
+    /// 表示名
+    pub name: String,
+
+    /// ボットユーザーかどうか（プロジェクト/グループアクセストークン等）
+    #[serde(default)]
+    pub bot: bool,
+
+    /// 公開メールアドレス（未設定の場合あり）
+    #[serde(default)]
+    pub public_email: Option<String>,
+
+    /// メールアドレス（管理者トークンの場合のみ返る）
+    #[serde(default)]
+    pub email: Option<String>,
+}
@@ -0,0 +1,17 @@
+//! GitLab ユーザー API
+//!
+//! ユーザー検索（ボット判定用）。
+
+use crate::error::AppResult;
+use crate::gitlab::{GitLabClient, GitLabUser};
+
+impl GitLabClient {
+    /// ユーザーを検索
+    ///
+    /// # Arguments
+    /// * `query` - 名前/ユーザー名/メールアドレス（メールは管理者トークンのみ一致）
+    pub async fn search_users(&self, query: &str) -> AppResult<Vec<GitLabUser>> {
+        let path = format!("/users?search={}", urlencoding::encode(query));
+        self.get(&path).await
+    }
+}
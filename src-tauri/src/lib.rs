@@ -5,6 +5,7 @@
 pub mod commands;
 pub mod domain;
 pub mod error;
+pub mod export;
 pub mod gitlab;
 pub mod logging;
 pub mod paths;
@@ -12,10 +13,20 @@ pub mod stats;
 pub mod storage;
 
 use commands::{
-    cancel_bulk_collection, collect_commits, collect_commits_bulk, delete_branch_commits,
-    get_branch_delete_impact, get_bulk_collection_status, get_gitlab_connection,
-    get_monthly_stats_cross_view, get_monthly_stats_project_view, get_projects, list_branches,
-    retry_failed_targets, set_gitlab_connection, sync_projects, user_filter_get, user_filter_set,
+    acknowledge_alerts, backup_database, cancel_bulk_collection, collect_commits,
+    collect_commits_bulk, compare_stats, create_team, delete_branch_commits, delete_team,
+    evaluate_alerts, export_archive, export_stats, get_alert_rules, get_bot_config,
+    get_branch_delete_impact, get_bulk_collection_status, get_bus_factor_report,
+    get_category_rules, get_commit_size_distribution, get_contribution_heatmap,
+    get_contributor_lifecycle, get_database_health, get_gitlab_connection,
+    get_monthly_stats_cross_view, get_monthly_stats_project_view, get_projects,
+    get_reporting_settings, get_work_calendar, import_archive, import_holidays,
+    import_local_repository, list_alerts, list_branches, list_commits_detail,
+    list_database_backups, list_project_tags, list_teams, optimize_database, purge_old_commits,
+    repair_missing_stats, restore_database, retry_failed_targets, search_commits, set_alert_rules,
+    set_bot_config, set_category_rules, set_gitlab_connection, set_project_tags,
+    set_reporting_settings, set_work_calendar, sync_bot_users, sync_projects, update_team,
+    user_filter_get, user_filter_set,
 };
 
 /// Tauri アプリケーションのエントリーポイント
@@ -37,6 +48,8 @@ pub fn run() {
             set_gitlab_connection,
             get_projects,
             sync_projects,
+            list_project_tags,
+            set_project_tags,
             // US2: コミット収集
             list_branches,
             collect_commits,
@@ -44,15 +57,61 @@ pub fn run() {
             cancel_bulk_collection,
             get_bulk_collection_status,
             retry_failed_targets,
+            repair_missing_stats,
+            import_local_repository,
             // US3: 月次集計
             get_monthly_stats_project_view,
             get_monthly_stats_cross_view,
+            compare_stats,
+            get_contribution_heatmap,
+            get_commit_size_distribution,
+            get_bus_factor_report,
+            get_contributor_lifecycle,
+            export_stats,
+            list_commits_detail,
+            search_commits,
             // ブランチ削除
             get_branch_delete_impact,
             delete_branch_commits,
             // ユーザーフィルタ
             user_filter_get,
             user_filter_set,
+            // コミット分類
+            get_category_rules,
+            set_category_rules,
+            // ボット判定
+            get_bot_config,
+            set_bot_config,
+            sync_bot_users,
+            // チーム
+            list_teams,
+            create_team,
+            update_team,
+            delete_team,
+            // 集計レポート設定
+            get_reporting_settings,
+            set_reporting_settings,
+            // 稼働日カレンダー
+            get_work_calendar,
+            set_work_calendar,
+            import_holidays,
+            // アラート
+            get_alert_rules,
+            set_alert_rules,
+            list_alerts,
+            acknowledge_alerts,
+            evaluate_alerts,
+            // データ移行
+            export_archive,
+            import_archive,
+            // バックアップ
+            backup_database,
+            list_database_backups,
+            restore_database,
+            // データベース保守
+            get_database_health,
+            optimize_database,
+            purge_old_commits,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -60,10 +119,18 @@ pub fn run() {
 /// SQLite データベース初期化
 ///
 /// アプリケーション起動時にデータベース接続を確立し、
-/// マイグレーションを実行します。
-fn initialize_sqlite() -> Result<(), Box<dyn std::error::Error>> {
+/// マイグレーションを実行します。CLI からも起動時に呼び出す。
+/// 既存データベースのマイグレーション前には自動スナップショットを作成する。
+pub fn initialize_sqlite() -> Result<(), Box<dyn std::error::Error>> {
     let conn = storage::sqlite::DatabaseConnection::create_connection()?;
-    storage::sqlite::run_migrations(&conn)?;
+    if storage::sqlite::has_pending_migrations(&conn)? {
+        storage::create_snapshot_with_connection(
+            &conn,
+            &paths::ensure_backup_dir()?,
+            storage::SnapshotReason::PreMigration,
+        )?;
+    }
+    storage::migrate_with_connection(&conn)?;
     tracing::info!("SQLite database initialized successfully");
     Ok(())
 }
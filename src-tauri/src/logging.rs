@@ -18,6 +18,21 @@ pub fn init_logging() {
         .init();
 }
 
+/// CLI 用のログ初期化
+///
+/// 標準出力はコマンド結果（JSON/CSV）に使うため、ログは標準エラー出力に書き出す。
+/// 環境変数 `RUST_LOG` でレベル制御可能。デフォルトは `warn`。
+pub fn init_cli_logging() {
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("warn"));
+
+    fmt()
+        .with_env_filter(filter)
+        .with_target(true)
+        .with_level(true)
+        .with_writer(std::io::stderr)
+        .init();
+}
+
 /// 機密情報をマスクした文字列を返す
 ///
 /// トークンやメールアドレスを安全にログ出力するために使用する。
@@ -8,6 +8,9 @@ use std::path::PathBuf;
 /// アプリデータディレクトリ名
 const APP_DATA_DIR_NAME: &str = "gitlab-analyzer";
 
+/// バックアップ保存先のディレクトリ名（アプリデータディレクトリ内）
+const BACKUP_DIR_NAME: &str = "backups";
+
 /// アプリデータディレクトリのパスを取得
 ///
 /// Windows: `%APPDATA%/gitlab-analyzer`
@@ -33,6 +36,18 @@ pub fn ensure_app_data_dir() -> AppResult<PathBuf> {
     Ok(dir)
 }
 
+/// バックアップ保存先ディレクトリを確保（存在しなければ作成）
+pub fn ensure_backup_dir() -> AppResult<PathBuf> {
+    let dir = ensure_app_data_dir()?.join(BACKUP_DIR_NAME);
+
+    if !dir.exists() {
+        std::fs::create_dir_all(&dir)
+            .map_err(|e| AppError::Storage(format!("ディレクトリ作成失敗: {}", e)))?;
+    }
+
+    Ok(dir)
+}
+
 /// データファイルのパスを取得
 ///
 /// 指定されたファイル名に対して、アプリデータディレクトリ内のパスを返す。
@@ -0,0 +1,246 @@
+//! 月次集計の共通処理
+//!
+//! プロジェクトビュー/横断ビューで共通のユーザー別（またはチーム別/プロジェクト別）月次集計を行う。
+//! コミットをどのバケット（月、比較期間など）に入れるかは呼び出し側が決める。
+
+use crate::domain::{Commit, Team};
+use crate::stats::types::metric_values;
+use crate::stats::{
+    AggregateOptions, ExcludedBotStats, GroupBy, MonthMetrics, MonthlyStatsResponse,
+    UserMonthlySeries, UserStats,
+};
+use std::collections::{HashMap, HashSet};
+
+/// バケット別の集計結果（系列キー → 中間データ）
+#[derive(Debug, Default)]
+pub(crate) struct Accumulated {
+    /// 系列キー → 集計（ユーザーキー / team:<id> / project:<id>）
+    pub stats: HashMap<String, UserStats>,
+    /// ボット（除外時のみ）
+    pub bot_stats: HashMap<String, UserStats>,
+    /// 除外したボットのコミット数
+    pub bot_commit_count: usize,
+    /// チーム/プロジェクト集計時: 系列キー → コミットが集計されたユーザー
+    pub contributors: HashMap<String, HashSet<String>>,
+    /// 全系列の合計（複数チームに所属していても 1 コミットとして数える）
+    pub total: [MonthMetrics; 12],
+}
+
+/// フィルタを適用してコミットをバケットごとに集計する
+///
+/// `bucket_of` が None を返したコミットは集計しない。バケットは 0-11。
+pub(crate) fn accumulate(
+    commits: &[Commit],
+    user_keys: &[String],
+    options: &AggregateOptions,
+    bucket_of: impl Fn(&Commit) -> Option<usize>,
+) -> Accumulated {
+    let mut acc = Accumulated::default();
+
+    for commit in commits {
+        let user_key = commit.user_key();
+
+        if !is_selected(commit, &user_key, user_keys, options) {
+            continue;
+        }
+
+        let Some(bucket) = bucket_of(commit).filter(|b| *b < 12) else {
+            continue;
+        };
+
+        let breakdown_key = options.breakdown_key(commit.project_id);
+
+        // ボットは別枠で集計
+        if options.exclude_bots && commit.is_bot {
+            acc.bot_commit_count += 1;
+            let stats = acc
+                .bot_stats
+                .entry(user_key)
+                .or_insert_with(|| UserStats::for_options(commit.display_name(), options));
+            add_to_stats(stats, breakdown_key, bucket, commit);
+            continue;
+        }
+
+        let targets = series_targets(commit, &user_key, options);
+        for (key, display_name) in &targets {
+            let stats = acc
+                .stats
+                .entry(key.clone())
+                .or_insert_with(|| UserStats::for_options(display_name, options));
+            add_to_stats(stats, breakdown_key.clone(), bucket, commit);
+            if options.group_by != GroupBy::User {
+                acc.contributors
+                    .entry(key.clone())
+                    .or_default()
+                    .insert(user_key.clone());
+            }
+        }
+
+        if !targets.is_empty() {
+            acc.total[bucket].add_commit(commit);
+        }
+    }
+
+    acc
+}
+
+/// ユーザーフィルタとカテゴリフィルタを通過するか（ボット除外は呼び出し側で扱う）
+pub(crate) fn is_selected(
+    commit: &Commit,
+    user_key: &str,
+    user_keys: &[String],
+    options: &AggregateOptions,
+) -> bool {
+    (user_keys.is_empty() || user_keys.iter().any(|key| key == user_key))
+        && options.includes(commit)
+}
+
+/// コミットを加算する系列（系列キー, 表示名）
+///
+/// チーム集計ではコミット日時点で所属しているチームすべて（所属なしなら空）。
+pub(crate) fn series_targets(
+    commit: &Commit,
+    user_key: &str,
+    options: &AggregateOptions,
+) -> Vec<(String, String)> {
+    match options.group_by {
+        GroupBy::User => vec![(user_key.to_string(), commit.display_name().to_string())],
+        GroupBy::Team => {
+            let date = commit.committed_date_utc.date_naive();
+            options
+                .teams
+                .iter()
+                .filter(|team| team.includes(user_key, date))
+                .map(|team| (team_key(team), team.name.clone()))
+                .collect()
+        }
+        GroupBy::Project => vec![(
+            format!("project:{}", commit.project_id),
+            options.project_label(commit.project_id),
+        )],
+    }
+}
+
+impl Accumulated {
+    /// 系列キーに対応するコントリビューター数
+    pub fn contributor_count(&self, key: &str) -> usize {
+        self.contributors.get(key).map_or(0, |c| c.len())
+    }
+
+    /// 系列キーと集計を出力順に列挙（チーム集計時はチーム定義順）
+    pub fn ordered_stats<'a>(
+        &'a self,
+        options: &'a AggregateOptions,
+    ) -> Vec<(String, &'a UserStats, Option<&'a Team>)> {
+        match options.group_by {
+            GroupBy::Team => options
+                .teams
+                .iter()
+                .filter_map(|team| {
+                    let key = team_key(team);
+                    let stats = self.stats.get(&key)?;
+                    Some((key, stats, Some(team)))
+                })
+                .collect(),
+            GroupBy::User | GroupBy::Project => self
+                .stats
+                .iter()
+                .map(|(key, stats)| (key.clone(), stats, None))
+                .collect(),
+        }
+    }
+}
+
+/// ユーザー別（またはチーム別/プロジェクト別）の月次集計を行う（ビュー固有の絞り込みは呼び出し側で適用済み）
+pub(crate) fn aggregate_monthly(
+    commits: &[Commit],
+    user_keys: &[String],
+    options: &AggregateOptions,
+) -> MonthlyStatsResponse {
+    let acc = accumulate(commits, user_keys, options, |commit| {
+        Some((commit.month() as usize).saturating_sub(1))
+    });
+
+    let series: Vec<_> = acc
+        .ordered_stats(options)
+        .into_iter()
+        .map(|(key, stats, team)| {
+            let series = stats.to_series(&key);
+            match (options.group_by, team) {
+                (GroupBy::Team, Some(team)) => {
+                    team_series(series, team, options.year, acc.contributor_count(&key))
+                }
+                (GroupBy::Project, _) => UserMonthlySeries {
+                    contributor_count: Some(acc.contributor_count(&key)),
+                    ..series
+                },
+                _ => series,
+            }
+        })
+        .collect();
+
+    let excluded_bots = options.exclude_bots.then(|| {
+        // 平均・中央値・稼働日数も正しく出るよう、ボットのコミットを合算してから指標値にする
+        let mut months: [MonthMetrics; 12] = Default::default();
+        for stats in acc.bot_stats.values() {
+            for (month, bot_month) in months.iter_mut().zip(&stats.monthly_metrics) {
+                month.merge(bot_month);
+            }
+        }
+        ExcludedBotStats {
+            commit_count: acc.bot_commit_count,
+            totals: metric_values(&months, options.metric),
+            series: acc
+                .bot_stats
+                .iter()
+                .map(|(key, stats)| stats.to_series(key))
+                .collect(),
+        }
+    });
+
+    MonthlyStatsResponse {
+        months: (1..=12).collect(),
+        metric: options.metric,
+        series,
+        excluded_bots,
+        outliers: None,
+        working_days: None,
+        trend: None,
+    }
+}
+
+/// 系列にコミットを加算（内訳指定時は内訳にも加算）
+fn add_to_stats(
+    stats: &mut UserStats,
+    breakdown_key: Option<String>,
+    bucket: usize,
+    commit: &Commit,
+) {
+    stats.add_commit(bucket, commit);
+    if let Some(key) = breakdown_key {
+        stats.add_breakdown(key, bucket, commit);
+    }
+}
+
+/// チーム系列のキー（user_key 欄に入る）
+fn team_key(team: &Team) -> String {
+    format!("team:{}", team.id)
+}
+
+/// チーム系列にメンバー数を付与
+fn team_series(
+    series: UserMonthlySeries,
+    team: &Team,
+    year: i32,
+    contributor_count: usize,
+) -> UserMonthlySeries {
+    UserMonthlySeries {
+        member_counts: Some(
+            (1..=12)
+                .map(|month| team.member_count_in_month(year, month))
+                .collect(),
+        ),
+        contributor_count: Some(contributor_count),
+        ..series
+    }
+}
@@ -0,0 +1,433 @@
+//! 異常検知アラート
+//!
+//! 一括収集の完了後に評価するアラートルール（前月比の急減/急増、巨大コミット）を定義し、
+//! 保存済みコミットから発火したアラートを求める。
+
+use crate::domain::Commit;
+use crate::error::{AppError, AppResult};
+use crate::stats::{compare_periods, AggregateOptions, GroupBy, Metric, StatsPeriod};
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// アラートルール
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlertRule {
+    /// ルール名（一意）
+    pub name: String,
+    /// 有効か（既定: 有効）
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// ボットのコミットも対象にするか（既定: 除外）
+    #[serde(default)]
+    pub include_bots: bool,
+    /// 発火条件
+    pub condition: AlertCondition,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// アラートの発火条件
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(
+    tag = "kind",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub enum AlertCondition {
+    /// 直近の完了月の指標が前月から一定割合以上変化した系列
+    MonthOverMonth {
+        /// 集計単位（user / team / project）
+        #[serde(default)]
+        group_by: GroupBy,
+        /// 比較する指標（既定: totalLines）
+        #[serde(default)]
+        metric: Metric,
+        /// 減少/増加のどちらを検知するか
+        direction: ChangeDirection,
+        /// 変化率の閾値（%、例: 40 で 40% 以上の変化）
+        percent: f64,
+        /// 前月の値がこれ未満の系列は判定しない（小さな値の揺れを無視する）
+        #[serde(default)]
+        min_base_value: i64,
+    },
+    /// 変更行数（追加 + 削除）が閾値以上のコミット
+    LargeCommit {
+        /// 変更行数の閾値
+        min_lines: i64,
+    },
+}
+
+impl AlertCondition {
+    /// 種別名（保存用）
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::MonthOverMonth { .. } => "monthOverMonth",
+            Self::LargeCommit { .. } => "largeCommit",
+        }
+    }
+}
+
+/// 変化の向き
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ChangeDirection {
+    /// 減少
+    Drop,
+    /// 増加
+    Spike,
+}
+
+/// 評価で発火したアラート（保存前）
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlertOccurrence {
+    /// ルール名
+    pub rule_name: String,
+    /// 条件の種別
+    pub kind: String,
+    /// 重複判定用のキー（同じルール・同じキーのアラートは 1 回だけ記録する）
+    pub fingerprint: String,
+    /// 対象（系列キー、またはコミットの project_id:sha）
+    pub subject_key: String,
+    /// 対象の表示名
+    pub subject_name: String,
+    /// 対象月（YYYY-MM）
+    pub period: String,
+    /// 観測値（前月比は変化率 %、巨大コミットは変更行数）
+    pub value: f64,
+    /// 通知用のメッセージ
+    pub message: String,
+}
+
+/// アラートルールを検証
+pub fn validate_alert_rules(rules: &[AlertRule]) -> AppResult<()> {
+    let mut names = HashSet::new();
+    for rule in rules {
+        let name = rule.name.trim();
+        if name.is_empty() {
+            return Err(AppError::Validation(
+                "アラートルール名を入力してください".to_string(),
+            ));
+        }
+        if !names.insert(name) {
+            return Err(AppError::Validation(format!(
+                "アラートルール名が重複しています: {}",
+                name
+            )));
+        }
+        match &rule.condition {
+            AlertCondition::MonthOverMonth { percent, .. } => {
+                if !(percent.is_finite() && *percent > 0.0) {
+                    return Err(AppError::Validation(format!(
+                        "変化率の閾値は 0 より大きい値で指定してください: {} ({})",
+                        percent, name
+                    )));
+                }
+            }
+            AlertCondition::LargeCommit { min_lines } => {
+                if *min_lines <= 0 {
+                    return Err(AppError::Validation(format!(
+                        "行数の閾値は 1 以上で指定してください: {} ({})",
+                        min_lines, name
+                    )));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// アラートルールを評価
+///
+/// 前月比は `(year, month)` とその前月を比較する。
+/// 巨大コミットは `(year, month)` の月初以降のコミットを対象にする。
+/// `options` のチーム/プロジェクト定義を系列の振り分けに使用する。
+pub fn evaluate_alert_rules(
+    rules: &[AlertRule],
+    commits: &[Commit],
+    options: &AggregateOptions,
+    year: i32,
+    month: u32,
+) -> AppResult<Vec<AlertOccurrence>> {
+    let mut occurrences = Vec::new();
+
+    for rule in rules.iter().filter(|rule| rule.enabled) {
+        match &rule.condition {
+            AlertCondition::MonthOverMonth {
+                group_by,
+                metric,
+                direction,
+                percent,
+                min_base_value,
+            } => {
+                let (base_year, base_month) = previous_month(year, month);
+                let rule_options = AggregateOptions {
+                    exclude_bots: !rule.include_bots,
+                    group_by: *group_by,
+                    metric: *metric,
+                    teams: options.teams.clone(),
+                    projects: options.projects.clone(),
+                    ..Default::default()
+                };
+                let comparison = compare_periods(
+                    commits,
+                    &[],
+                    &rule_options,
+                    single_month(base_year, base_month),
+                    single_month(year, month),
+                )?;
+
+                let period = format_month(year, month);
+                for series in comparison.series {
+                    let value = series.value;
+                    if value.base <= 0 || value.base < *min_base_value {
+                        continue;
+                    }
+                    let Some(change) = value.percent_change else {
+                        continue;
+                    };
+                    let triggered = match direction {
+                        ChangeDirection::Drop => change <= -percent,
+                        ChangeDirection::Spike => change >= *percent,
+                    };
+                    if !triggered {
+                        continue;
+                    }
+
+                    let label = match direction {
+                        ChangeDirection::Drop => "減少",
+                        ChangeDirection::Spike => "増加",
+                    };
+                    occurrences.push(AlertOccurrence {
+                        rule_name: rule.name.clone(),
+                        kind: rule.condition.kind().to_string(),
+                        fingerprint: format!("{}:{}", series.user_key, period),
+                        message: format!(
+                            "{}: {} の {} が {} に前月比 {:.0}% {}しました（{} → {}）",
+                            rule.name,
+                            series.display_name,
+                            metric.column_name(),
+                            period,
+                            change.abs(),
+                            label,
+                            value.base,
+                            value.target
+                        ),
+                        subject_key: series.user_key,
+                        subject_name: series.display_name,
+                        period: period.clone(),
+                        value: change,
+                    });
+                }
+            }
+            AlertCondition::LargeCommit { min_lines } => {
+                let since = NaiveDate::from_ymd_opt(year, month, 1).ok_or_else(|| {
+                    AppError::Validation(format!("対象月が不正です: {}-{}", year, month))
+                })?;
+                // 複数ブランチに含まれる同じコミットは 1 回だけ通知する
+                let mut seen = HashSet::new();
+                for commit in commits {
+                    if commit.committed_date_utc.date_naive() < since
+                        || (commit.is_bot && !rule.include_bots)
+                        || commit.total_lines() < *min_lines
+                        || !seen.insert((commit.project_id, commit.sha.as_str()))
+                    {
+                        continue;
+                    }
+
+                    let subject_key = format!("{}:{}", commit.project_id, commit.sha);
+                    let short_sha: String = commit.sha.chars().take(8).collect();
+                    let date = commit.committed_date_utc;
+                    occurrences.push(AlertOccurrence {
+                        rule_name: rule.name.clone(),
+                        kind: rule.condition.kind().to_string(),
+                        fingerprint: subject_key.clone(),
+                        subject_key,
+                        subject_name: commit.display_name().to_string(),
+                        period: format_month(date.year(), date.month()),
+                        value: commit.total_lines() as f64,
+                        message: format!(
+                            "{}: {} のコミット {} の変更行数が {} 行です",
+                            rule.name,
+                            commit.display_name(),
+                            short_sha,
+                            commit.total_lines()
+                        ),
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(occurrences)
+}
+
+/// 指定日から見た直近の完了月
+pub fn last_completed_month(today: NaiveDate) -> (i32, u32) {
+    previous_month(today.year(), today.month())
+}
+
+fn previous_month(year: i32, month: u32) -> (i32, u32) {
+    if month <= 1 {
+        (year - 1, 12)
+    } else {
+        (year, month - 1)
+    }
+}
+
+fn single_month(year: i32, month: u32) -> StatsPeriod {
+    StatsPeriod {
+        year,
+        from_month: month,
+        to_month: month,
+    }
+}
+
+fn format_month(year: i32, month: u32) -> String {
+    format!("{:04}-{:02}", year, month)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    fn create_commit(year: i32, month: u32, user: &str, index: u32, additions: i64) -> Commit {
+        Commit {
+            project_id: 1,
+            branch_name: "main".to_string(),
+            sha: format!("sha-{}-{}-{}-{}", year, month, user, index),
+            message: String::new(),
+            committed_date_utc: Utc.with_ymd_and_hms(year, month, 10, 12, 0, 0).unwrap(),
+            authored_date_utc: None,
+            author_name: user.to_string(),
+            author_email: Some(format!("{}@example.com", user.to_lowercase())),
+            additions,
+            deletions: 0,
+            stats_missing: false,
+            date_invalid: false,
+            category: "other".to_string(),
+            is_bot: false,
+        }
+    }
+
+    fn month_over_month(direction: ChangeDirection, percent: f64) -> AlertRule {
+        AlertRule {
+            name: "output change".to_string(),
+            enabled: true,
+            include_bots: false,
+            condition: AlertCondition::MonthOverMonth {
+                group_by: GroupBy::User,
+                metric: Metric::TotalLines,
+                direction,
+                percent,
+                min_base_value: 50,
+            },
+        }
+    }
+
+    #[test]
+    fn test_month_over_month_drop_across_year_boundary() {
+        let commits = vec![
+            create_commit(2025, 12, "Alice", 1, 100),
+            create_commit(2026, 1, "Alice", 1, 50),
+            create_commit(2025, 12, "Bob", 1, 100),
+            create_commit(2026, 1, "Bob", 1, 90),
+            // 前月の値が min_base_value 未満
+            create_commit(2025, 12, "Carol", 1, 10),
+        ];
+
+        let alerts = evaluate_alert_rules(
+            &[month_over_month(ChangeDirection::Drop, 40.0)],
+            &commits,
+            &AggregateOptions::default(),
+            2026,
+            1,
+        )
+        .unwrap();
+
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].subject_key, "alice@example.com");
+        assert_eq!(alerts[0].period, "2026-01");
+        assert_eq!(alerts[0].fingerprint, "alice@example.com:2026-01");
+        assert_eq!(alerts[0].value, -50.0);
+    }
+
+    #[test]
+    fn test_month_over_month_spike_and_disabled_rule() {
+        let commits = vec![
+            create_commit(2026, 2, "Alice", 1, 100),
+            create_commit(2026, 3, "Alice", 1, 300),
+        ];
+        let mut disabled = month_over_month(ChangeDirection::Spike, 100.0);
+        disabled.name = "disabled".to_string();
+        disabled.enabled = false;
+
+        let alerts = evaluate_alert_rules(
+            &[month_over_month(ChangeDirection::Spike, 100.0), disabled],
+            &commits,
+            &AggregateOptions::default(),
+            2026,
+            3,
+        )
+        .unwrap();
+
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].rule_name, "output change");
+        assert_eq!(alerts[0].value, 200.0);
+    }
+
+    #[test]
+    fn test_large_commit_since_month_start() {
+        let mut bot_commit = create_commit(2026, 3, "renovate", 1, 50_000);
+        bot_commit.is_bot = true;
+        let mut other_branch = create_commit(2026, 3, "Alice", 1, 25_000);
+        other_branch.branch_name = "develop".to_string();
+        let commits = vec![
+            create_commit(2026, 2, "Alice", 2, 30_000),
+            create_commit(2026, 3, "Alice", 1, 25_000),
+            other_branch,
+            create_commit(2026, 4, "Bob", 1, 100),
+            bot_commit,
+        ];
+        let rule = AlertRule {
+            name: "huge commit".to_string(),
+            enabled: true,
+            include_bots: false,
+            condition: AlertCondition::LargeCommit { min_lines: 20_000 },
+        };
+
+        let alerts =
+            evaluate_alert_rules(&[rule], &commits, &AggregateOptions::default(), 2026, 3).unwrap();
+
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].fingerprint, "1:sha-2026-3-Alice-1");
+        assert_eq!(alerts[0].value, 25_000.0);
+    }
+
+    #[test]
+    fn test_validate_rules() {
+        let rule = month_over_month(ChangeDirection::Drop, 40.0);
+        assert!(validate_alert_rules(std::slice::from_ref(&rule)).is_ok());
+        assert!(validate_alert_rules(&[rule.clone(), rule.clone()]).is_err());
+        assert!(validate_alert_rules(&[month_over_month(ChangeDirection::Drop, 0.0)]).is_err());
+
+        let json = r#"{"name":"big","condition":{"kind":"largeCommit","minLines":20000}}"#;
+        let parsed: AlertRule = serde_json::from_str(json).unwrap();
+        assert!(parsed.enabled);
+        assert_eq!(
+            parsed.condition,
+            AlertCondition::LargeCommit { min_lines: 20_000 }
+        );
+    }
+
+    #[test]
+    fn test_last_completed_month() {
+        let today = NaiveDate::from_ymd_opt(2026, 1, 15).unwrap();
+        assert_eq!(last_completed_month(today), (2025, 12));
+    }
+}
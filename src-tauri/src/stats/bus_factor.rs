@@ -0,0 +1,379 @@
+//! バスファクター（知識の集中度）
+//!
+//! 期間内の変更行数に占める作者ごとのシェアをプロジェクト単位で集計し、
+//! 変更行数の 50% / 80% を何人で占めているか、上位の作者、1 人に偏っているかを求める。
+//! 同じコミットが複数ブランチに保存されていても 1 回だけ数える。
+
+use crate::domain::Commit;
+use crate::stats::AggregateOptions;
+use chrono::{Datelike, NaiveDate};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// 集計期間（両端を含む、UTC の日付）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DateWindow {
+    /// 開始日
+    pub from: NaiveDate,
+    /// 終了日
+    pub to: NaiveDate,
+}
+
+impl DateWindow {
+    /// 終了日から遡る月数で期間を作る（例: 12 ヶ月 → 前年同日の翌日から終了日まで）
+    pub fn months_until(to: NaiveDate, months: u32) -> Self {
+        let from = to
+            .checked_sub_months(chrono::Months::new(months))
+            .and_then(|date| date.succ_opt())
+            .unwrap_or(to);
+        Self { from, to }
+    }
+
+    /// コミットが期間内か
+    pub fn contains(&self, commit: &Commit) -> bool {
+        let date = commit.committed_date_utc.date_naive();
+        self.from <= date && date <= self.to
+    }
+
+    /// 期間にかかる暦月（年, 月）
+    pub fn months(&self) -> Vec<(i32, u32)> {
+        let mut months = Vec::new();
+        let (mut year, mut month) = (self.from.year(), self.from.month());
+        while (year, month) <= (self.to.year(), self.to.month()) {
+            months.push((year, month));
+            (year, month) = if month == 12 {
+                (year + 1, 1)
+            } else {
+                (year, month + 1)
+            };
+        }
+        months
+    }
+}
+
+/// 作者のシェア
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContributorShare {
+    /// ユーザーキー
+    pub user_key: String,
+    /// 表示名
+    pub display_name: String,
+    /// 変更行数（追加 + 削除）
+    pub lines: i64,
+    /// コミット数
+    pub commit_count: usize,
+    /// 変更行数に占める割合（0.0-1.0）
+    pub share: f64,
+}
+
+/// 月ごとの集中度
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConcentrationPoint {
+    /// 年
+    pub year: i32,
+    /// 月
+    pub month: u32,
+    /// 変更行数
+    pub total_lines: i64,
+    /// 作者数
+    pub author_count: usize,
+    /// 変更行数の 50% を占める作者数（バスファクター）
+    pub bus_factor: usize,
+    /// 最大シェアの作者の割合（0.0-1.0）
+    pub top_share: f64,
+}
+
+/// プロジェクトごとの集中度
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectConcentration {
+    /// プロジェクト ID
+    pub project_id: i64,
+    /// プロジェクト名（path_with_namespace）
+    pub project_name: String,
+    /// 変更行数
+    pub total_lines: i64,
+    /// コミット数
+    pub commit_count: usize,
+    /// 作者数
+    pub author_count: usize,
+    /// 変更行数の 50% を占める作者数（バスファクター）
+    pub bus_factor: usize,
+    /// 変更行数の 80% を占める作者数
+    pub authors_for_80_percent: usize,
+    /// 最大シェアの作者の割合（0.0-1.0）
+    pub top_share: f64,
+    /// 1 人の作者のシェアが閾値以上か
+    pub dominated: bool,
+    /// 上位の作者（シェアの大きい順）
+    pub top_contributors: Vec<ContributorShare>,
+    /// 月ごとの推移
+    pub trend: Vec<ConcentrationPoint>,
+}
+
+/// 知識の集中度レポート
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KnowledgeConcentrationReport {
+    /// 集計期間
+    pub window: DateWindow,
+    /// 「1 人に偏っている」と判定するシェアの閾値
+    pub dominance_threshold: f64,
+    /// プロジェクトごとの集中度（バスファクターの小さい順）
+    pub projects: Vec<ProjectConcentration>,
+}
+
+/// 集中度の集計設定
+#[derive(Debug, Clone, Copy)]
+pub struct ConcentrationOptions {
+    /// 集計期間
+    pub window: DateWindow,
+    /// 「1 人に偏っている」と判定するシェアの閾値（0.0-1.0）
+    pub dominance_threshold: f64,
+    /// 出力する上位作者の人数
+    pub top_contributors: usize,
+}
+
+/// プロジェクトごとの知識の集中度を集計する
+///
+/// # Arguments
+/// * `commits` - 対象コミット（期間外のコミットは無視する）
+/// * `options` - カテゴリフィルタ/ボット除外とプロジェクト定義（表示名に使用）
+/// * `settings` - 期間、偏りの閾値、上位作者の人数
+pub fn knowledge_concentration(
+    commits: &[Commit],
+    options: &AggregateOptions,
+    settings: ConcentrationOptions,
+) -> KnowledgeConcentrationReport {
+    let mut seen: HashSet<(i64, &str)> = HashSet::new();
+    let mut by_project: BTreeMap<i64, Vec<&Commit>> = BTreeMap::new();
+    for commit in commits {
+        if !settings.window.contains(commit)
+            || commit.stats_missing
+            || !options.includes(commit)
+            || (options.exclude_bots && commit.is_bot)
+        {
+            continue;
+        }
+        // 複数ブランチに保存されている同一コミットは 1 回だけ数える
+        if !seen.insert((commit.project_id, commit.sha.as_str())) {
+            continue;
+        }
+        by_project
+            .entry(commit.project_id)
+            .or_default()
+            .push(commit);
+    }
+
+    let months = settings.window.months();
+    let mut projects: Vec<ProjectConcentration> = by_project
+        .into_iter()
+        .map(|(project_id, commits)| {
+            project_concentration(project_id, &commits, options, &settings, &months)
+        })
+        .collect();
+    projects.sort_by(|a, b| {
+        a.bus_factor
+            .cmp(&b.bus_factor)
+            .then_with(|| b.top_share.total_cmp(&a.top_share))
+            .then_with(|| a.project_name.cmp(&b.project_name))
+    });
+
+    KnowledgeConcentrationReport {
+        window: settings.window,
+        dominance_threshold: settings.dominance_threshold,
+        projects,
+    }
+}
+
+fn project_concentration(
+    project_id: i64,
+    commits: &[&Commit],
+    options: &AggregateOptions,
+    settings: &ConcentrationOptions,
+    months: &[(i32, u32)],
+) -> ProjectConcentration {
+    let shares = contributor_shares(commits.iter().copied());
+    let total_lines: i64 = shares.iter().map(|s| s.lines).sum();
+    let top_share = shares.first().map_or(0.0, |s| s.share);
+
+    let trend = months
+        .iter()
+        .map(|&(year, month)| {
+            let shares = contributor_shares(
+                commits
+                    .iter()
+                    .copied()
+                    .filter(|c| c.year() == year && c.month() == month),
+            );
+            ConcentrationPoint {
+                year,
+                month,
+                total_lines: shares.iter().map(|s| s.lines).sum(),
+                author_count: shares.len(),
+                bus_factor: authors_for_share(&shares, 0.5),
+                top_share: shares.first().map_or(0.0, |s| s.share),
+            }
+        })
+        .collect();
+
+    ProjectConcentration {
+        project_id,
+        project_name: options.project_label(project_id),
+        total_lines,
+        commit_count: commits.len(),
+        author_count: shares.len(),
+        bus_factor: authors_for_share(&shares, 0.5),
+        authors_for_80_percent: authors_for_share(&shares, 0.8),
+        top_share,
+        dominated: total_lines > 0 && top_share >= settings.dominance_threshold,
+        top_contributors: shares.into_iter().take(settings.top_contributors).collect(),
+        trend,
+    }
+}
+
+/// 作者ごとのシェア（変更行数の大きい順）
+fn contributor_shares<'a>(commits: impl Iterator<Item = &'a Commit>) -> Vec<ContributorShare> {
+    let mut by_user: HashMap<String, ContributorShare> = HashMap::new();
+    for commit in commits {
+        let share = by_user
+            .entry(commit.user_key())
+            .or_insert_with(|| ContributorShare {
+                user_key: commit.user_key(),
+                display_name: commit.display_name().to_string(),
+                lines: 0,
+                commit_count: 0,
+                share: 0.0,
+            });
+        share.lines += commit.total_lines();
+        share.commit_count += 1;
+    }
+
+    let total: i64 = by_user.values().map(|s| s.lines).sum();
+    let mut shares: Vec<ContributorShare> = by_user
+        .into_values()
+        .map(|s| ContributorShare {
+            share: if total > 0 {
+                s.lines as f64 / total as f64
+            } else {
+                0.0
+            },
+            ..s
+        })
+        .collect();
+    shares.sort_by(|a, b| {
+        b.lines
+            .cmp(&a.lines)
+            .then_with(|| b.commit_count.cmp(&a.commit_count))
+            .then_with(|| a.user_key.cmp(&b.user_key))
+    });
+    shares
+}
+
+/// シェアの大きい順に足して `ratio` 以上に達するまでの作者数（変更行数が 0 なら 0）
+fn authors_for_share(shares: &[ContributorShare], ratio: f64) -> usize {
+    let total: i64 = shares.iter().map(|s| s.lines).sum();
+    if total == 0 {
+        return 0;
+    }
+    let mut cumulative = 0;
+    for (index, share) in shares.iter().enumerate() {
+        cumulative += share.lines;
+        if cumulative as f64 >= total as f64 * ratio {
+            return index + 1;
+        }
+    }
+    shares.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    fn create_commit(project_id: i64, month: u32, user: &str, additions: i64) -> Commit {
+        Commit {
+            project_id,
+            branch_name: "main".to_string(),
+            sha: format!("sha-{}-{}-{}-{}", project_id, month, user, additions),
+            message: String::new(),
+            committed_date_utc: Utc.with_ymd_and_hms(2026, month, 10, 12, 0, 0).unwrap(),
+            authored_date_utc: None,
+            author_name: user.to_string(),
+            author_email: Some(format!("{}@example.com", user.to_lowercase())),
+            additions,
+            deletions: 0,
+            stats_missing: false,
+            date_invalid: false,
+            category: "other".to_string(),
+            is_bot: false,
+        }
+    }
+
+    fn settings() -> ConcentrationOptions {
+        ConcentrationOptions {
+            window: DateWindow {
+                from: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+                to: NaiveDate::from_ymd_opt(2026, 3, 31).unwrap(),
+            },
+            dominance_threshold: 0.7,
+            top_contributors: 2,
+        }
+    }
+
+    #[test]
+    fn test_bus_factor_per_project() {
+        let mut duplicated = create_commit(1, 1, "Alice", 800);
+        duplicated.branch_name = "develop".to_string();
+        let commits = vec![
+            // project 1: Alice 80%, Bob 10%, Carol 10%
+            create_commit(1, 1, "Alice", 800),
+            duplicated, // 別ブランチの同一コミット
+            create_commit(1, 2, "Bob", 100),
+            create_commit(1, 3, "Carol", 100),
+            // project 2: 4 人で均等
+            create_commit(2, 1, "Alice", 100),
+            create_commit(2, 1, "Bob", 100),
+            create_commit(2, 2, "Carol", 100),
+            create_commit(2, 2, "Dave", 100),
+            // 期間外
+            create_commit(2, 5, "Alice", 10000),
+        ];
+
+        let report = knowledge_concentration(&commits, &AggregateOptions::default(), settings());
+
+        assert_eq!(report.projects.len(), 2);
+        let dominated = &report.projects[0];
+        assert_eq!(dominated.project_id, 1);
+        assert_eq!(dominated.project_name, "project:1");
+        assert_eq!(dominated.total_lines, 1000);
+        assert_eq!(dominated.bus_factor, 1);
+        assert_eq!(dominated.authors_for_80_percent, 1);
+        assert!(dominated.dominated);
+        assert_eq!(dominated.top_contributors.len(), 2);
+        assert_eq!(dominated.top_contributors[0].display_name, "Alice");
+        assert_eq!(dominated.top_contributors[0].share, 0.8);
+        assert_eq!(dominated.trend.len(), 3);
+        assert_eq!(dominated.trend[1].author_count, 1);
+        assert_eq!(dominated.trend[1].top_share, 1.0);
+
+        let shared = &report.projects[1];
+        assert_eq!(shared.bus_factor, 2);
+        assert_eq!(shared.authors_for_80_percent, 4);
+        assert!(!shared.dominated);
+        assert_eq!(shared.trend[2].total_lines, 0);
+        assert_eq!(shared.trend[2].bus_factor, 0);
+    }
+
+    #[test]
+    fn test_window_months_until() {
+        let window = DateWindow::months_until(NaiveDate::from_ymd_opt(2026, 3, 15).unwrap(), 12);
+        assert_eq!(window.from, NaiveDate::from_ymd_opt(2025, 3, 16).unwrap());
+        assert_eq!(window.months().len(), 13);
+        assert_eq!(window.months()[0], (2025, 3));
+        assert_eq!(window.months()[12], (2026, 3));
+    }
+}
@@ -0,0 +1,567 @@
+//! コミットサイズの分布と外れ値
+//!
+//! コミットサイズ（追加 + 削除行数）のヒストグラムを系列ごとに集計し、
+//! パーセンタイルまたは IQR の閾値を超えるコミットを外れ値として検出する。
+//! 集計コマンドでは外れ値を除外するか閾値で頭打ちにして、取り込みや生成コードによる
+//! 巨大コミットが月次合計を歪めないようにできる。
+
+use crate::domain::Commit;
+use crate::error::{AppError, AppResult};
+use crate::stats::aggregate::{is_selected, series_targets};
+use crate::stats::AggregateOptions;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// ヒストグラムの区切り（各区間の下限、最後の区間は上限なし）
+const SIZE_BIN_LOWER_BOUNDS: [i64; 9] = [0, 10, 50, 100, 250, 500, 1000, 5000, 10000];
+
+/// 外れ値の判定方法
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "method", rename_all = "camelCase")]
+pub enum OutlierRule {
+    /// 指定パーセンタイル（0-100）を超えるコミット
+    Percentile {
+        /// パーセンタイル（例: 99）
+        percentile: f64,
+    },
+    /// 第 3 四分位数 + 倍率 × 四分位範囲を超えるコミット
+    Iqr {
+        /// 四分位範囲の倍率（既定: 1.5）
+        #[serde(default = "default_iqr_multiplier")]
+        multiplier: f64,
+    },
+}
+
+fn default_iqr_multiplier() -> f64 {
+    1.5
+}
+
+impl Default for OutlierRule {
+    fn default() -> Self {
+        Self::Iqr {
+            multiplier: default_iqr_multiplier(),
+        }
+    }
+}
+
+impl OutlierRule {
+    /// 判定パラメータを検証
+    pub fn validate(&self) -> AppResult<()> {
+        match *self {
+            Self::Percentile { percentile } if !(percentile > 0.0 && percentile < 100.0) => {
+                Err(AppError::Validation(format!(
+                    "パーセンタイルは 0 より大きく 100 未満で指定してください: {}",
+                    percentile
+                )))
+            }
+            Self::Iqr { multiplier } if !(multiplier >= 0.0 && multiplier.is_finite()) => {
+                Err(AppError::Validation(format!(
+                    "IQR の倍率は 0 以上で指定してください: {}",
+                    multiplier
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// コミットサイズの一覧から閾値を求める（このサイズを超えると外れ値、空なら None）
+    pub fn threshold(&self, sizes: &[i64]) -> Option<i64> {
+        if sizes.is_empty() {
+            return None;
+        }
+        let mut sorted = sizes.to_vec();
+        sorted.sort_unstable();
+
+        let threshold = match *self {
+            Self::Percentile { percentile } => quantile(&sorted, percentile / 100.0),
+            Self::Iqr { multiplier } => {
+                let q1 = quantile(&sorted, 0.25);
+                let q3 = quantile(&sorted, 0.75);
+                q3 + multiplier * (q3 - q1)
+            }
+        };
+        Some(threshold.floor() as i64)
+    }
+}
+
+/// 外れ値の扱い
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OutlierMode {
+    /// 集計から除外する
+    Exclude,
+    /// 行数を閾値で頭打ちにする（追加/削除の比率は維持）
+    Cap,
+}
+
+/// 集計コマンドでの外れ値の扱い
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutlierHandling {
+    /// 判定方法（既定: IQR × 1.5）
+    #[serde(default)]
+    pub rule: OutlierRule,
+    /// 扱い
+    pub mode: OutlierMode,
+}
+
+/// 外れ値と判定されたコミット
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutlierCommit {
+    /// プロジェクト ID
+    pub project_id: i64,
+    /// ブランチ名
+    pub branch_name: String,
+    /// コミット SHA
+    pub sha: String,
+    /// コミットメッセージの 1 行目
+    pub title: String,
+    /// ユーザーキー
+    pub user_key: String,
+    /// 作者名
+    pub display_name: String,
+    /// コミット日時（UTC）
+    pub committed_date_utc: DateTime<Utc>,
+    /// コミットサイズ（追加 + 削除）
+    pub size: i64,
+}
+
+impl OutlierCommit {
+    fn from_commit(commit: &Commit) -> Self {
+        Self {
+            project_id: commit.project_id,
+            branch_name: commit.branch_name.clone(),
+            sha: commit.sha.clone(),
+            title: commit
+                .message
+                .lines()
+                .next()
+                .unwrap_or_default()
+                .to_string(),
+            user_key: commit.user_key(),
+            display_name: commit.display_name().to_string(),
+            committed_date_utc: commit.committed_date_utc,
+            size: commit.total_lines(),
+        }
+    }
+}
+
+/// 外れ値の除外/頭打ちの結果
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutlierReport {
+    /// 判定方法
+    pub rule: OutlierRule,
+    /// 扱い
+    pub mode: OutlierMode,
+    /// 閾値（このサイズを超えるコミットが対象、対象コミットがなければ None）
+    pub threshold: Option<i64>,
+    /// 除外/頭打ちしたコミット（サイズの大きい順）
+    pub commits: Vec<OutlierCommit>,
+}
+
+/// ヒストグラムの区間
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SizeBin {
+    /// 下限（この値を含む）
+    pub min: i64,
+    /// 上限（この値を含まない、最後の区間は None）
+    pub max: Option<i64>,
+    /// コミット数
+    pub count: usize,
+}
+
+/// 系列ごとのコミットサイズ分布
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SizeDistribution {
+    /// 系列キー（ユーザーキー / team:<id> / project:<id>）
+    pub user_key: String,
+    /// 表示名
+    pub display_name: String,
+    /// 行数が取得できたコミット数
+    pub commit_count: usize,
+    /// 行数が欠損しているコミット数（分布には含めない）
+    pub missing_count: usize,
+    /// ヒストグラム
+    pub histogram: Vec<SizeBin>,
+    /// 中央値
+    pub median: i64,
+    /// 90 パーセンタイル
+    pub p90: i64,
+    /// 最大値
+    pub max: i64,
+    /// 外れ値のコミット数
+    pub outlier_count: usize,
+}
+
+/// コミットサイズ分布の集計結果
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SizeDistributionResponse {
+    /// 外れ値の判定方法
+    pub rule: OutlierRule,
+    /// 外れ値の閾値（対象コミットがなければ None）
+    pub threshold: Option<i64>,
+    /// 系列ごとの分布（コミット数の多い順）
+    pub series: Vec<SizeDistribution>,
+    /// 全体の分布
+    pub total: SizeDistribution,
+    /// 外れ値のコミット（サイズの大きい順）
+    pub outliers: Vec<OutlierCommit>,
+}
+
+/// コミットサイズの分布を集計し、外れ値を検出する（ビュー固有の絞り込みは呼び出し側で適用済み）
+///
+/// 閾値は全系列の対象コミットから求める。行数が欠損しているコミットは分布に含めない。
+pub fn commit_size_distribution(
+    commits: &[Commit],
+    user_keys: &[String],
+    options: &AggregateOptions,
+    rule: OutlierRule,
+) -> AppResult<SizeDistributionResponse> {
+    rule.validate()?;
+
+    let targets: Vec<&Commit> = commits
+        .iter()
+        .filter(|c| is_counted(c, user_keys, options))
+        .collect();
+    let threshold = rule.threshold(&sizes(&targets));
+    let is_outlier = |commit: &Commit| threshold.is_some_and(|t| commit.total_lines() > t);
+
+    let mut grouped: HashMap<String, (String, Vec<&Commit>)> = HashMap::new();
+    for commit in &targets {
+        for (key, display_name) in series_targets(commit, &commit.user_key(), options) {
+            grouped
+                .entry(key)
+                .or_insert_with(|| (display_name, Vec::new()))
+                .1
+                .push(commit);
+        }
+    }
+
+    let mut series: Vec<SizeDistribution> = grouped
+        .into_iter()
+        .map(|(key, (display_name, commits))| {
+            distribution(key, display_name, &commits, &is_outlier)
+        })
+        .collect();
+    series.sort_by(|a, b| {
+        b.commit_count
+            .cmp(&a.commit_count)
+            .then_with(|| a.display_name.cmp(&b.display_name))
+    });
+
+    Ok(SizeDistributionResponse {
+        rule,
+        threshold,
+        series,
+        total: distribution(String::new(), "全体".to_string(), &targets, &is_outlier),
+        outliers: outlier_commits(targets.into_iter().filter(|c| is_outlier(c))),
+    })
+}
+
+/// 外れ値を除外または頭打ちにしたコミット一覧と、対象にしたコミットの一覧を返す
+///
+/// 閾値は集計対象になるコミット（ユーザー/カテゴリフィルタ、ボット除外を適用後）から求める。
+/// 集計対象外のコミットはそのまま返す。
+pub fn apply_outlier_handling(
+    commits: Vec<Commit>,
+    user_keys: &[String],
+    options: &AggregateOptions,
+    handling: OutlierHandling,
+) -> AppResult<(Vec<Commit>, OutlierReport)> {
+    handling.rule.validate()?;
+
+    let targets: Vec<&Commit> = commits
+        .iter()
+        .filter(|c| is_counted(c, user_keys, options))
+        .collect();
+    let threshold = handling.rule.threshold(&sizes(&targets));
+    let Some(limit) = threshold else {
+        return Ok((
+            commits,
+            OutlierReport {
+                rule: handling.rule,
+                mode: handling.mode,
+                threshold,
+                commits: Vec::new(),
+            },
+        ));
+    };
+
+    let outliers = outlier_commits(targets.into_iter().filter(|c| c.total_lines() > limit));
+    let outlier_keys: HashSet<(i64, &str)> = outliers
+        .iter()
+        .map(|c| (c.project_id, c.sha.as_str()))
+        .collect();
+
+    let commits = commits
+        .into_iter()
+        .filter_map(|mut commit| {
+            if !outlier_keys.contains(&(commit.project_id, commit.sha.as_str())) {
+                return Some(commit);
+            }
+            match handling.mode {
+                OutlierMode::Exclude => None,
+                OutlierMode::Cap => {
+                    cap_commit(&mut commit, limit);
+                    Some(commit)
+                }
+            }
+        })
+        .collect();
+
+    Ok((
+        commits,
+        OutlierReport {
+            rule: handling.rule,
+            mode: handling.mode,
+            threshold,
+            commits: outliers,
+        },
+    ))
+}
+
+/// 外れ値の扱いが指定されていれば適用する
+pub fn handle_outliers(
+    commits: Vec<Commit>,
+    user_keys: &[String],
+    options: &AggregateOptions,
+    handling: Option<OutlierHandling>,
+) -> AppResult<(Vec<Commit>, Option<OutlierReport>)> {
+    match handling {
+        Some(handling) => {
+            let (commits, report) = apply_outlier_handling(commits, user_keys, options, handling)?;
+            Ok((commits, Some(report)))
+        }
+        None => Ok((commits, None)),
+    }
+}
+
+/// 集計対象か（ユーザー/カテゴリフィルタ、ボット除外）
+fn is_counted(commit: &Commit, user_keys: &[String], options: &AggregateOptions) -> bool {
+    !(options.exclude_bots && commit.is_bot)
+        && is_selected(commit, &commit.user_key(), user_keys, options)
+}
+
+/// 行数が取得できているコミットのサイズ
+fn sizes(commits: &[&Commit]) -> Vec<i64> {
+    commits
+        .iter()
+        .filter(|c| !c.stats_missing)
+        .map(|c| c.total_lines())
+        .collect()
+}
+
+/// 行数を上限に収める（追加/削除の比率は維持）
+fn cap_commit(commit: &mut Commit, limit: i64) {
+    let size = commit.total_lines();
+    if size <= limit || size == 0 {
+        return;
+    }
+    let additions = (commit.additions as f64 * limit as f64 / size as f64).round() as i64;
+    commit.additions = additions;
+    commit.deletions = limit - additions;
+}
+
+fn outlier_commits<'a>(commits: impl Iterator<Item = &'a Commit>) -> Vec<OutlierCommit> {
+    let mut outliers: Vec<OutlierCommit> = commits.map(OutlierCommit::from_commit).collect();
+    outliers.sort_by(|a, b| b.size.cmp(&a.size).then_with(|| a.sha.cmp(&b.sha)));
+    outliers
+}
+
+fn distribution(
+    user_key: String,
+    display_name: String,
+    commits: &[&Commit],
+    is_outlier: &impl Fn(&Commit) -> bool,
+) -> SizeDistribution {
+    let mut sorted = sizes(commits);
+    sorted.sort_unstable();
+
+    let histogram = SIZE_BIN_LOWER_BOUNDS
+        .iter()
+        .enumerate()
+        .map(|(index, &min)| {
+            let max = SIZE_BIN_LOWER_BOUNDS.get(index + 1).copied();
+            SizeBin {
+                min,
+                max,
+                count: sorted
+                    .iter()
+                    .filter(|&&size| size >= min && max.is_none_or(|max| size < max))
+                    .count(),
+            }
+        })
+        .collect();
+
+    SizeDistribution {
+        user_key,
+        display_name,
+        commit_count: sorted.len(),
+        missing_count: commits.iter().filter(|c| c.stats_missing).count(),
+        histogram,
+        median: quantile_or_zero(&sorted, 0.5),
+        p90: quantile_or_zero(&sorted, 0.9),
+        max: sorted.last().copied().unwrap_or_default(),
+        outlier_count: commits.iter().filter(|c| is_outlier(c)).count(),
+    }
+}
+
+/// 分位数（線形補間、昇順ソート済み・空でないこと）
+fn quantile(sorted: &[i64], q: f64) -> f64 {
+    let position = (sorted.len() - 1) as f64 * q.clamp(0.0, 1.0);
+    let lower = position.floor() as usize;
+    let upper = position.ceil() as usize;
+    let fraction = position - lower as f64;
+    sorted[lower] as f64 + (sorted[upper] - sorted[lower]) as f64 * fraction
+}
+
+fn quantile_or_zero(sorted: &[i64], q: f64) -> i64 {
+    if sorted.is_empty() {
+        0
+    } else {
+        quantile(sorted, q).round() as i64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::{aggregate_cross_view, GroupBy};
+    use chrono::TimeZone;
+
+    fn create_commit(user: &str, index: u32, additions: i64, deletions: i64) -> Commit {
+        Commit {
+            project_id: 1,
+            branch_name: "main".to_string(),
+            sha: format!("sha-{}-{}", user, index),
+            message: format!("commit {}\n\nbody", index),
+            committed_date_utc: Utc
+                .with_ymd_and_hms(2026, 1, index % 28 + 1, 12, 0, 0)
+                .unwrap(),
+            authored_date_utc: None,
+            author_name: user.to_string(),
+            author_email: Some(format!("{}@example.com", user.to_lowercase())),
+            additions,
+            deletions,
+            stats_missing: false,
+            date_invalid: false,
+            category: "other".to_string(),
+            is_bot: false,
+        }
+    }
+
+    /// 10〜100 行の通常コミット 10 件と巨大コミット 1 件
+    fn create_commits() -> Vec<Commit> {
+        let mut commits: Vec<Commit> = (1..=10)
+            .map(|i| create_commit("Alice", i, i as i64 * 10, 0))
+            .collect();
+        commits.push(create_commit("Bob", 11, 30000, 10000));
+        commits
+    }
+
+    #[test]
+    fn test_thresholds() {
+        let sizes: Vec<i64> = (1..=100).collect();
+        assert_eq!(
+            OutlierRule::Percentile { percentile: 90.0 }.threshold(&sizes),
+            Some(90)
+        );
+        // Q1 = 25.75, Q3 = 75.25, IQR = 49.5
+        assert_eq!(OutlierRule::default().threshold(&sizes), Some(149));
+        assert_eq!(OutlierRule::default().threshold(&[]), None);
+
+        assert!(OutlierRule::Percentile { percentile: 100.0 }
+            .validate()
+            .is_err());
+        assert!(OutlierRule::Iqr { multiplier: -1.0 }.validate().is_err());
+    }
+
+    #[test]
+    fn test_distribution_flags_outliers() {
+        let mut commits = create_commits();
+        let mut missing = create_commit("Alice", 12, 0, 0);
+        missing.stats_missing = true;
+        commits.push(missing);
+
+        let result = commit_size_distribution(
+            &commits,
+            &[],
+            &AggregateOptions::default(),
+            OutlierRule::default(),
+        )
+        .unwrap();
+
+        assert_eq!(result.outliers.len(), 1);
+        assert_eq!(result.outliers[0].sha, "sha-Bob-11");
+        assert_eq!(result.outliers[0].title, "commit 11");
+        assert_eq!(result.outliers[0].size, 40000);
+
+        let alice = &result.series[0];
+        assert_eq!(alice.display_name, "Alice");
+        assert_eq!(alice.commit_count, 10);
+        assert_eq!(alice.missing_count, 1);
+        assert_eq!(alice.outlier_count, 0);
+        assert_eq!(alice.max, 100);
+        // 10-49: 10,20,30,40 / 50-99: 50..90 / 100-249: 100
+        let counts: Vec<usize> = alice.histogram.iter().map(|b| b.count).collect();
+        assert_eq!(counts, vec![0, 4, 5, 1, 0, 0, 0, 0, 0]);
+
+        let bob = &result.series[1];
+        assert_eq!(bob.outlier_count, 1);
+        assert_eq!(bob.histogram.last().unwrap().count, 1);
+        assert_eq!(result.total.commit_count, 11);
+
+        // プロジェクト別
+        let options = AggregateOptions {
+            group_by: GroupBy::Project,
+            ..Default::default()
+        };
+        let result =
+            commit_size_distribution(&commits, &[], &options, OutlierRule::default()).unwrap();
+        assert_eq!(result.series.len(), 1);
+        assert_eq!(result.series[0].user_key, "project:1");
+    }
+
+    #[test]
+    fn test_exclude_and_cap_outliers() {
+        let options = AggregateOptions::default();
+
+        let (commits, report) = apply_outlier_handling(
+            create_commits(),
+            &[],
+            &options,
+            OutlierHandling {
+                rule: OutlierRule::default(),
+                mode: OutlierMode::Exclude,
+            },
+        )
+        .unwrap();
+        assert_eq!(commits.len(), 10);
+        assert_eq!(report.commits.len(), 1);
+        let result = aggregate_cross_view(&commits, &[], &options);
+        assert!(result.series.iter().all(|s| s.display_name != "Bob"));
+
+        let (commits, report) = apply_outlier_handling(
+            create_commits(),
+            &[],
+            &options,
+            OutlierHandling {
+                rule: OutlierRule::default(),
+                mode: OutlierMode::Cap,
+            },
+        )
+        .unwrap();
+        let limit = report.threshold.unwrap();
+        let bob = commits.iter().find(|c| c.author_name == "Bob").unwrap();
+        assert_eq!(bob.total_lines(), limit);
+        // 追加/削除の比率は 3:1 のまま
+        assert_eq!(bob.additions, (limit as f64 * 0.75).round() as i64);
+        assert_eq!(report.commits[0].size, 40000);
+    }
+}
@@ -0,0 +1,341 @@
+//! 期間比較
+//!
+//! 2 つの期間（例: 2025 年と 2026 年、Q1 と Q2）の指標を系列ごとに比較する。
+//! 月次集計と同じ集計処理を、月の代わりに「比較元/比較先」の 2 バケットで実行する。
+
+use crate::domain::Commit;
+use crate::error::{AppError, AppResult};
+use crate::stats::aggregate::accumulate;
+use crate::stats::{AggregateOptions, Metric, MonthMetrics, OutlierReport, UserStats};
+use chrono::Datelike;
+use serde::{Deserialize, Serialize};
+
+/// 比較元のバケット
+const BASE_BUCKET: usize = 0;
+/// 比較先のバケット
+const TARGET_BUCKET: usize = 1;
+
+/// 比較する期間（年内の月範囲、両端を含む）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsPeriod {
+    /// 対象年
+    pub year: i32,
+    /// 開始月（既定: 1）
+    #[serde(default = "default_from_month")]
+    pub from_month: u32,
+    /// 終了月（既定: 12）
+    #[serde(default = "default_to_month")]
+    pub to_month: u32,
+}
+
+fn default_from_month() -> u32 {
+    1
+}
+
+fn default_to_month() -> u32 {
+    12
+}
+
+impl StatsPeriod {
+    /// 年全体
+    pub fn year(year: i32) -> Self {
+        Self {
+            year,
+            from_month: 1,
+            to_month: 12,
+        }
+    }
+
+    /// 月範囲を検証
+    pub fn validate(&self) -> AppResult<()> {
+        if !(1..=12).contains(&self.from_month)
+            || !(1..=12).contains(&self.to_month)
+            || self.from_month > self.to_month
+        {
+            return Err(AppError::Validation(format!(
+                "期間の月指定が不正です: {}年 {}月〜{}月",
+                self.year, self.from_month, self.to_month
+            )));
+        }
+        Ok(())
+    }
+
+    /// コミットが期間内か（UTC 基準）
+    pub fn contains(&self, commit: &Commit) -> bool {
+        let date = commit.committed_date_utc;
+        date.year() == self.year && (self.from_month..=self.to_month).contains(&date.month())
+    }
+
+    /// 期間が重なるか
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.year == other.year
+            && self.from_month <= other.to_month
+            && other.from_month <= self.to_month
+    }
+}
+
+/// 系列が含まれる期間
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PeriodPresence {
+    /// 両方の期間にコミットあり
+    Both,
+    /// 比較元の期間のみ
+    BaseOnly,
+    /// 比較先の期間のみ
+    TargetOnly,
+}
+
+/// 比較値
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComparisonValue {
+    /// 比較元の値
+    pub base: i64,
+    /// 比較先の値
+    pub target: i64,
+    /// 差分（比較先 - 比較元）
+    pub delta: i64,
+    /// 変化率（%、比較元が 0 の場合は None）
+    pub percent_change: Option<f64>,
+}
+
+impl ComparisonValue {
+    /// 2 つの値から作成
+    pub fn new(base: i64, target: i64) -> Self {
+        Self {
+            base,
+            target,
+            delta: target - base,
+            percent_change: (base != 0)
+                .then(|| (target - base) as f64 / base.unsigned_abs() as f64 * 100.0),
+        }
+    }
+}
+
+/// 系列ごとの比較結果
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SeriesComparison {
+    /// 系列キー（ユーザーキー / team:<id> / project:<id>）
+    pub user_key: String,
+    /// 表示名
+    pub display_name: String,
+    /// 含まれる期間
+    pub presence: PeriodPresence,
+    /// 比較値
+    #[serde(flatten)]
+    pub value: ComparisonValue,
+}
+
+/// 期間比較の結果
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsComparison {
+    /// 指標
+    pub metric: Metric,
+    /// 比較元の期間
+    pub base_period: StatsPeriod,
+    /// 比較先の期間
+    pub target_period: StatsPeriod,
+    /// 系列ごとの比較（差分の大きい順）
+    pub series: Vec<SeriesComparison>,
+    /// 全体の比較（複数チームに所属するメンバーのコミットも 1 回だけ数える）
+    pub total: ComparisonValue,
+    /// 除外/頭打ちした外れ値コミット（outliers 指定時のみ）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outliers: Option<OutlierReport>,
+}
+
+/// 2 つの期間を比較する
+///
+/// # Arguments
+/// * `commits` - 両期間のコミット（期間外のコミットは無視する）
+/// * `user_keys` - フィルタするユーザーキー（空の場合は全ユーザー）
+/// * `options` - 集計オプション（ボット除外、チーム/プロジェクト集計、指標など）
+pub fn compare_periods(
+    commits: &[Commit],
+    user_keys: &[String],
+    options: &AggregateOptions,
+    base: StatsPeriod,
+    target: StatsPeriod,
+) -> AppResult<StatsComparison> {
+    base.validate()?;
+    target.validate()?;
+    if base.overlaps(&target) {
+        return Err(AppError::Validation(
+            "比較する 2 つの期間が重なっています".to_string(),
+        ));
+    }
+
+    let acc = accumulate(commits, user_keys, options, |commit| {
+        if base.contains(commit) {
+            Some(BASE_BUCKET)
+        } else if target.contains(commit) {
+            Some(TARGET_BUCKET)
+        } else {
+            None
+        }
+    });
+
+    let mut series: Vec<SeriesComparison> = acc
+        .ordered_stats(options)
+        .into_iter()
+        .map(|(key, stats, _)| series_comparison(key, stats, options.metric))
+        .collect();
+    series.sort_by(|a, b| {
+        b.value
+            .delta
+            .abs()
+            .cmp(&a.value.delta.abs())
+            .then_with(|| a.display_name.cmp(&b.display_name))
+    });
+
+    Ok(StatsComparison {
+        metric: options.metric,
+        base_period: base,
+        target_period: target,
+        series,
+        total: bucket_comparison(&acc.total, options.metric),
+        outliers: None,
+    })
+}
+
+fn series_comparison(user_key: String, stats: &UserStats, metric: Metric) -> SeriesComparison {
+    let buckets = &stats.monthly_metrics;
+    let presence = match (
+        buckets[BASE_BUCKET].commit_count > 0,
+        buckets[TARGET_BUCKET].commit_count > 0,
+    ) {
+        (true, false) => PeriodPresence::BaseOnly,
+        (false, true) => PeriodPresence::TargetOnly,
+        _ => PeriodPresence::Both,
+    };
+
+    SeriesComparison {
+        user_key,
+        display_name: stats.display_name.clone(),
+        presence,
+        value: bucket_comparison(buckets, metric),
+    }
+}
+
+fn bucket_comparison(buckets: &[MonthMetrics; 12], metric: Metric) -> ComparisonValue {
+    ComparisonValue::new(
+        buckets[BASE_BUCKET].value(metric),
+        buckets[TARGET_BUCKET].value(metric),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    fn create_commit(year: i32, month: u32, user: &str, additions: i64) -> Commit {
+        Commit {
+            project_id: 1,
+            branch_name: "main".to_string(),
+            sha: format!("sha-{}-{}-{}-{}", year, month, user, additions),
+            message: String::new(),
+            committed_date_utc: Utc.with_ymd_and_hms(year, month, 10, 12, 0, 0).unwrap(),
+            authored_date_utc: None,
+            author_name: user.to_string(),
+            author_email: Some(format!("{}@example.com", user.to_lowercase())),
+            additions,
+            deletions: 0,
+            stats_missing: false,
+            date_invalid: false,
+            category: "other".to_string(),
+            is_bot: false,
+        }
+    }
+
+    #[test]
+    fn test_year_over_year_with_users_in_one_period() {
+        let commits = vec![
+            create_commit(2025, 3, "Alice", 100),
+            create_commit(2026, 3, "Alice", 150),
+            create_commit(2025, 6, "Bob", 40),
+            create_commit(2026, 1, "Carol", 70),
+        ];
+
+        let result = compare_periods(
+            &commits,
+            &[],
+            &AggregateOptions::default(),
+            StatsPeriod::year(2025),
+            StatsPeriod::year(2026),
+        )
+        .unwrap();
+
+        let find = |name: &str| {
+            result
+                .series
+                .iter()
+                .find(|s| s.display_name == name)
+                .unwrap()
+        };
+        let alice = find("Alice");
+        assert_eq!(alice.presence, PeriodPresence::Both);
+        assert_eq!(alice.value.delta, 50);
+        assert_eq!(alice.value.percent_change, Some(50.0));
+
+        let bob = find("Bob");
+        assert_eq!(bob.presence, PeriodPresence::BaseOnly);
+        assert_eq!(bob.value.percent_change, Some(-100.0));
+
+        let carol = find("Carol");
+        assert_eq!(carol.presence, PeriodPresence::TargetOnly);
+        assert_eq!(carol.value.percent_change, None);
+
+        assert_eq!(result.total, ComparisonValue::new(140, 220));
+    }
+
+    #[test]
+    fn test_quarter_comparison_and_validation() {
+        let commits = vec![
+            create_commit(2026, 2, "Alice", 10),
+            create_commit(2026, 3, "Alice", 20),
+            create_commit(2026, 5, "Alice", 5),
+            create_commit(2026, 7, "Alice", 999), // どちらの期間にも含まれない
+        ];
+        let q1 = StatsPeriod {
+            year: 2026,
+            from_month: 1,
+            to_month: 3,
+        };
+        let q2 = StatsPeriod {
+            year: 2026,
+            from_month: 4,
+            to_month: 6,
+        };
+        let options = AggregateOptions {
+            metric: Metric::CommitCount,
+            ..Default::default()
+        };
+
+        let result = compare_periods(&commits, &[], &options, q1, q2).unwrap();
+        assert_eq!(result.series[0].value, ComparisonValue::new(2, 1));
+
+        assert!(matches!(
+            compare_periods(&commits, &[], &options, q1, StatsPeriod::year(2026)),
+            Err(AppError::Validation(_))
+        ));
+        assert!(matches!(
+            compare_periods(
+                &commits,
+                &[],
+                &options,
+                StatsPeriod {
+                    from_month: 4,
+                    to_month: 3,
+                    ..q1
+                },
+                q2
+            ),
+            Err(AppError::Validation(_))
+        ));
+    }
+}
@@ -0,0 +1,221 @@
+//! 曜日×時間帯ヒートマップ
+//!
+//! コミット日時（`committed_date_utc`）を集計用タイムゾーンのローカル時刻に変換し、
+//! 曜日（7 行）× 時（24 列）のコミット数と行数を系列ごとに集計する。
+//! 時間外・週末の作業傾向を見るためのもの。
+
+use crate::domain::Commit;
+use crate::stats::aggregate::{is_selected, series_targets};
+use crate::stats::AggregateOptions;
+use chrono::{Datelike, Timelike};
+use chrono_tz::Tz;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// 曜日×時間帯の行列（行は月曜始まりの曜日 0-6、列は時 0-23）
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeatmapMatrix {
+    /// コミット数
+    pub commit_counts: [[usize; 24]; 7],
+    /// 行数（追加 + 削除）
+    pub lines: [[i64; 24]; 7],
+    /// 行数が欠損しているコミット数
+    pub missing_count: usize,
+}
+
+impl HeatmapMatrix {
+    /// コミットを加算
+    pub fn add_commit(&mut self, commit: &Commit, tz: Tz) {
+        let local = commit.committed_date_utc.with_timezone(&tz);
+        let weekday = local.weekday().num_days_from_monday() as usize;
+        let hour = local.hour() as usize;
+
+        self.commit_counts[weekday][hour] += 1;
+        self.lines[weekday][hour] += commit.additions + commit.deletions;
+        if commit.stats_missing {
+            self.missing_count += 1;
+        }
+    }
+
+    /// 総コミット数
+    pub fn commit_count(&self) -> usize {
+        self.commit_counts.iter().flatten().sum()
+    }
+}
+
+/// 系列ごとのヒートマップ
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeatmapSeries {
+    /// 系列キー（ユーザーキー / team:<id> / project:<id>）
+    pub user_key: String,
+    /// 表示名
+    pub display_name: String,
+    /// 行列
+    #[serde(flatten)]
+    pub matrix: HeatmapMatrix,
+}
+
+/// ヒートマップの集計結果
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeatmapResponse {
+    /// 集計に使ったタイムゾーン（IANA 名）
+    pub time_zone: String,
+    /// 系列ごとの行列（コミット数の多い順）
+    pub series: Vec<HeatmapSeries>,
+    /// 全体の行列（複数チームに所属するメンバーのコミットも 1 回だけ数える）
+    pub total: HeatmapMatrix,
+    /// 除外したボットのコミット数（ボット除外時のみ）
+    pub excluded_bot_commit_count: Option<usize>,
+}
+
+/// 曜日×時間帯のヒートマップを集計する（ビュー固有の絞り込みは呼び出し側で適用済み）
+///
+/// # Arguments
+/// * `commits` - 対象コミット一覧
+/// * `user_keys` - フィルタするユーザーキー（空の場合は全ユーザー）
+/// * `options` - 集計オプション（カテゴリ、ボット除外、チーム/プロジェクト集計）
+/// * `tz` - 曜日・時の判定に使うタイムゾーン
+pub fn aggregate_heatmap(
+    commits: &[Commit],
+    user_keys: &[String],
+    options: &AggregateOptions,
+    tz: Tz,
+) -> HeatmapResponse {
+    let mut series: HashMap<String, HeatmapSeries> = HashMap::new();
+    let mut total = HeatmapMatrix::default();
+    let mut bot_commit_count = 0;
+
+    for commit in commits {
+        let user_key = commit.user_key();
+        if !is_selected(commit, &user_key, user_keys, options) {
+            continue;
+        }
+
+        if options.exclude_bots && commit.is_bot {
+            bot_commit_count += 1;
+            continue;
+        }
+
+        let targets = series_targets(commit, &user_key, options);
+        if targets.is_empty() {
+            continue;
+        }
+        for (key, display_name) in targets {
+            series
+                .entry(key.clone())
+                .or_insert_with(|| HeatmapSeries {
+                    user_key: key,
+                    display_name,
+                    matrix: HeatmapMatrix::default(),
+                })
+                .matrix
+                .add_commit(commit, tz);
+        }
+        total.add_commit(commit, tz);
+    }
+
+    let mut series: Vec<HeatmapSeries> = series.into_values().collect();
+    series.sort_by(|a, b| {
+        b.matrix
+            .commit_count()
+            .cmp(&a.matrix.commit_count())
+            .then_with(|| a.display_name.cmp(&b.display_name))
+    });
+
+    HeatmapResponse {
+        time_zone: tz.name().to_string(),
+        series,
+        total,
+        excluded_bot_commit_count: options.exclude_bots.then_some(bot_commit_count),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Team, TeamMember};
+    use crate::stats::GroupBy;
+    use chrono::{TimeZone, Utc};
+
+    fn create_commit(user: &str, day: u32, hour: u32, additions: i64) -> Commit {
+        Commit {
+            project_id: 1,
+            branch_name: "main".to_string(),
+            sha: format!("sha-{}-{}-{}", user, day, hour),
+            message: String::new(),
+            committed_date_utc: Utc.with_ymd_and_hms(2026, 3, day, hour, 0, 0).unwrap(),
+            authored_date_utc: None,
+            author_name: user.to_string(),
+            author_email: Some(format!("{}@example.com", user.to_lowercase())),
+            additions,
+            deletions: 0,
+            stats_missing: false,
+            date_invalid: false,
+            category: "other".to_string(),
+            is_bot: false,
+        }
+    }
+
+    #[test]
+    fn test_buckets_in_reporting_time_zone() {
+        // 2026-03-06 は金曜日。UTC 16時は東京で土曜 1時
+        let commits = vec![
+            create_commit("Alice", 6, 16, 10),
+            create_commit("Alice", 6, 1, 5),
+            create_commit("Bob", 9, 0, 7), // 月曜 UTC 0時 = 東京 9時
+        ];
+
+        let result =
+            aggregate_heatmap(&commits, &[], &AggregateOptions::default(), Tz::Asia__Tokyo);
+
+        assert_eq!(result.time_zone, "Asia/Tokyo");
+        let alice = &result.series[0];
+        assert_eq!(alice.display_name, "Alice");
+        assert_eq!(alice.matrix.commit_counts[5][1], 1);
+        assert_eq!(alice.matrix.lines[5][1], 10);
+        assert_eq!(alice.matrix.commit_counts[4][10], 1);
+        assert_eq!(result.total.commit_counts[0][9], 1);
+        assert_eq!(result.total.commit_count(), 3);
+
+        // UTC では金曜 16時
+        let result = aggregate_heatmap(&commits, &[], &AggregateOptions::default(), Tz::UTC);
+        assert_eq!(result.series[0].matrix.commit_counts[4][16], 1);
+    }
+
+    #[test]
+    fn test_team_series_and_bot_exclusion() {
+        let team = Team {
+            id: 3,
+            name: "Backend".to_string(),
+            members: vec![TeamMember {
+                user_key: "alice@example.com".to_string(),
+                valid_from: None,
+                valid_until: None,
+            }],
+        };
+        let mut bot = create_commit("renovate[bot]", 7, 3, 1);
+        bot.is_bot = true;
+        let commits = vec![
+            create_commit("Alice", 7, 22, 10),
+            create_commit("Carol", 7, 22, 10), // 未所属
+            bot,
+        ];
+
+        let options = AggregateOptions {
+            group_by: GroupBy::Team,
+            teams: vec![team],
+            exclude_bots: true,
+            ..Default::default()
+        };
+        let result = aggregate_heatmap(&commits, &[], &options, Tz::UTC);
+
+        assert_eq!(result.series.len(), 1);
+        assert_eq!(result.series[0].user_key, "team:3");
+        assert_eq!(result.series[0].matrix.commit_counts[5][22], 1);
+        assert_eq!(result.total.commit_count(), 1);
+        assert_eq!(result.excluded_bot_commit_count, Some(1));
+    }
+}
@@ -0,0 +1,308 @@
+//! コントリビューターのライフサイクル
+//!
+//! 作者（`Commit::user_key` で識別）ごとの最初/最後のコミット日時を求め、
+//! 月ごとの新規参加者と、一定期間コミットのない離脱者をプロジェクト単位と全体で集計する。
+
+use crate::domain::Commit;
+use crate::stats::{AggregateOptions, DateWindow};
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// 作者の活動期間
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContributorActivity {
+    /// ユーザーキー
+    pub user_key: String,
+    /// 表示名（最新のコミットの作者名）
+    pub display_name: String,
+    /// 最初のコミット日時
+    pub first_commit_utc: DateTime<Utc>,
+    /// 最後のコミット日時
+    pub last_commit_utc: DateTime<Utc>,
+    /// コミット数（複数ブランチの同一コミットは 1 回）
+    pub commit_count: usize,
+    /// 基準日時点で活動中か（最後のコミットから inactiveMonths 未満）
+    pub active: bool,
+}
+
+/// 月ごとの参加/離脱
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LifecyclePeriod {
+    /// 年
+    pub year: i32,
+    /// 月
+    pub month: u32,
+    /// この月に最初のコミットをしたユーザーキー
+    pub newcomers: Vec<String>,
+    /// この月を最後にコミットがなく、基準日時点で離脱扱いのユーザーキー
+    pub leavers: Vec<String>,
+}
+
+/// プロジェクト（または全体）のライフサイクル
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LifecycleGroup {
+    /// プロジェクト ID（全体の場合は None）
+    pub project_id: Option<i64>,
+    /// プロジェクト名（全体の場合は None）
+    pub project_name: Option<String>,
+    /// 活動中の作者数
+    pub active_count: usize,
+    /// 離脱扱いの作者数
+    pub inactive_count: usize,
+    /// 作者ごとの活動期間（最後のコミットの新しい順）
+    pub contributors: Vec<ContributorActivity>,
+    /// 月ごとの参加/離脱（集計期間内の各月）
+    pub periods: Vec<LifecyclePeriod>,
+}
+
+/// ライフサイクルの集計結果
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContributorLifecycleReport {
+    /// 参加/離脱を月ごとに集計する期間（終了日が基準日）
+    pub window: DateWindow,
+    /// 離脱扱いにするコミットのない月数
+    pub inactive_months: u32,
+    /// 全プロジェクト横断
+    pub overall: LifecycleGroup,
+    /// プロジェクトごと（プロジェクト名順）
+    pub projects: Vec<LifecycleGroup>,
+}
+
+/// 作者のライフサイクルを集計する
+///
+/// # Arguments
+/// * `commits` - 対象コミット（最初のコミットを判定するため全期間。基準日より後のコミットと日時不正のコミットは無視する）
+/// * `options` - ボット除外とプロジェクト定義（表示名に使用）
+/// * `window` - 参加/離脱を月ごとに集計する期間（`window.to` が基準日）
+/// * `inactive_months` - 最後のコミットからこの月数以上経過した作者を離脱扱いにする
+pub fn contributor_lifecycle(
+    commits: &[Commit],
+    options: &AggregateOptions,
+    window: DateWindow,
+    inactive_months: u32,
+) -> ContributorLifecycleReport {
+    let mut seen: HashSet<(i64, &str)> = HashSet::new();
+    let mut targets: Vec<&Commit> = Vec::new();
+    for commit in commits {
+        if commit.date_invalid
+            || commit.committed_date_utc.date_naive() > window.to
+            || (options.exclude_bots && commit.is_bot)
+            || !seen.insert((commit.project_id, commit.sha.as_str()))
+        {
+            continue;
+        }
+        targets.push(commit);
+    }
+
+    // この日より前に最後のコミットをした作者は離脱扱い
+    let inactive_before = window
+        .to
+        .checked_sub_months(chrono::Months::new(inactive_months))
+        .unwrap_or(window.to);
+    let months = window.months();
+
+    let mut by_project: BTreeMap<i64, Vec<&Commit>> = BTreeMap::new();
+    for commit in &targets {
+        by_project
+            .entry(commit.project_id)
+            .or_default()
+            .push(commit);
+    }
+
+    let mut projects: Vec<LifecycleGroup> = by_project
+        .into_iter()
+        .map(|(project_id, commits)| LifecycleGroup {
+            project_id: Some(project_id),
+            project_name: Some(options.project_label(project_id)),
+            ..lifecycle_group(&commits, inactive_before, &months)
+        })
+        .collect();
+    projects.sort_by(|a, b| a.project_name.cmp(&b.project_name));
+
+    ContributorLifecycleReport {
+        window,
+        inactive_months,
+        overall: lifecycle_group(&targets, inactive_before, &months),
+        projects,
+    }
+}
+
+fn lifecycle_group(
+    commits: &[&Commit],
+    inactive_before: NaiveDate,
+    months: &[(i32, u32)],
+) -> LifecycleGroup {
+    let mut by_user: HashMap<String, ContributorActivity> = HashMap::new();
+    for commit in commits {
+        let date = commit.committed_date_utc;
+        let activity = by_user
+            .entry(commit.user_key())
+            .or_insert_with(|| ContributorActivity {
+                user_key: commit.user_key(),
+                display_name: commit.display_name().to_string(),
+                first_commit_utc: date,
+                last_commit_utc: date,
+                commit_count: 0,
+                active: true,
+            });
+        activity.commit_count += 1;
+        activity.first_commit_utc = activity.first_commit_utc.min(date);
+        if date > activity.last_commit_utc {
+            activity.last_commit_utc = date;
+            activity.display_name = commit.display_name().to_string();
+        }
+    }
+
+    let mut contributors: Vec<ContributorActivity> = by_user
+        .into_values()
+        .map(|activity| ContributorActivity {
+            active: activity.last_commit_utc.date_naive() >= inactive_before,
+            ..activity
+        })
+        .collect();
+    contributors.sort_by(|a, b| {
+        b.last_commit_utc
+            .cmp(&a.last_commit_utc)
+            .then_with(|| a.user_key.cmp(&b.user_key))
+    });
+
+    let in_month =
+        |date: DateTime<Utc>, year: i32, month: u32| date.year() == year && date.month() == month;
+    let periods = months
+        .iter()
+        .map(|&(year, month)| LifecyclePeriod {
+            year,
+            month,
+            newcomers: contributors
+                .iter()
+                .filter(|c| in_month(c.first_commit_utc, year, month))
+                .map(|c| c.user_key.clone())
+                .collect(),
+            leavers: contributors
+                .iter()
+                .filter(|c| !c.active && in_month(c.last_commit_utc, year, month))
+                .map(|c| c.user_key.clone())
+                .collect(),
+        })
+        .collect();
+
+    let active_count = contributors.iter().filter(|c| c.active).count();
+    LifecycleGroup {
+        project_id: None,
+        project_name: None,
+        active_count,
+        inactive_count: contributors.len() - active_count,
+        contributors,
+        periods,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn create_commit(project_id: i64, year: i32, month: u32, user: &str) -> Commit {
+        Commit {
+            project_id,
+            branch_name: "main".to_string(),
+            sha: format!("sha-{}-{}-{}-{}", project_id, year, month, user),
+            message: String::new(),
+            committed_date_utc: Utc.with_ymd_and_hms(year, month, 10, 12, 0, 0).unwrap(),
+            authored_date_utc: None,
+            author_name: user.to_string(),
+            author_email: Some(format!("{}@example.com", user.to_lowercase())),
+            additions: 10,
+            deletions: 0,
+            stats_missing: false,
+            date_invalid: false,
+            category: "other".to_string(),
+            is_bot: false,
+        }
+    }
+
+    #[test]
+    fn test_newcomers_and_leavers() {
+        let commits = vec![
+            create_commit(1, 2024, 5, "Alice"),
+            create_commit(1, 2026, 5, "Alice"),
+            create_commit(1, 2025, 9, "Bob"), // 2025-09 が最後 → 離脱
+            create_commit(1, 2025, 8, "Bob"),
+            create_commit(2, 2026, 4, "Carol"), // 新規参加
+            create_commit(2, 2026, 4, "Bob"),   // 別プロジェクトでは活動中
+            create_commit(2, 2026, 8, "Dave"),  // 基準日より後
+        ];
+        let window = DateWindow {
+            from: NaiveDate::from_ymd_opt(2025, 7, 1).unwrap(),
+            to: NaiveDate::from_ymd_opt(2026, 6, 30).unwrap(),
+        };
+
+        let report = contributor_lifecycle(&commits, &AggregateOptions::default(), window, 3);
+
+        // 全体: Bob は project 2 で活動中
+        assert_eq!(report.overall.contributors.len(), 3);
+        assert_eq!(report.overall.inactive_count, 0);
+        let alice = report
+            .overall
+            .contributors
+            .iter()
+            .find(|c| c.display_name == "Alice")
+            .unwrap();
+        assert_eq!(alice.first_commit_utc.year(), 2024);
+        assert_eq!(alice.commit_count, 2);
+        assert_eq!(report.overall.periods.len(), 12);
+        // 2025-08: Bob が新規参加、2026-04: Carol が新規参加
+        assert_eq!(report.overall.periods[1].newcomers, vec!["bob@example.com"]);
+        assert_eq!(
+            report.overall.periods[9].newcomers,
+            vec!["carol@example.com"]
+        );
+
+        // project 1: Bob は離脱扱い
+        let project1 = &report.projects[0];
+        assert_eq!(project1.project_id, Some(1));
+        assert_eq!(project1.inactive_count, 1);
+        assert_eq!(project1.periods[2].leavers, vec!["bob@example.com"]);
+        assert!(project1.periods.iter().all(|p| p.newcomers.len() <= 1));
+    }
+
+    #[test]
+    fn test_date_invalid_commit_is_ignored() {
+        // 日時不正のコミットは UNIX エポックで保存されるが、最初のコミットにも離脱判定にも使わない
+        let mut broken = create_commit(1, 2026, 5, "Alice");
+        broken.sha = "broken".to_string();
+        broken.committed_date_utc = DateTime::<Utc>::UNIX_EPOCH;
+        broken.date_invalid = true;
+        let commits = vec![
+            broken,
+            create_commit(1, 2026, 5, "Alice"),
+            create_commit(1, 1970, 1, "Bob"),
+        ];
+        let window = DateWindow {
+            from: NaiveDate::from_ymd_opt(2025, 7, 1).unwrap(),
+            to: NaiveDate::from_ymd_opt(2026, 6, 30).unwrap(),
+        };
+
+        let report = contributor_lifecycle(&commits, &AggregateOptions::default(), window, 3);
+
+        let alice = report
+            .overall
+            .contributors
+            .iter()
+            .find(|c| c.display_name == "Alice")
+            .unwrap();
+        assert_eq!(alice.first_commit_utc.year(), 2026);
+        assert_eq!(alice.commit_count, 1);
+        assert_eq!(
+            report.overall.periods[10].newcomers,
+            vec!["alice@example.com"]
+        );
+        // 日時が正しい 1970 年のコミットは対象のまま
+        assert_eq!(report.overall.inactive_count, 1);
+    }
+}
@@ -2,10 +2,27 @@
 //!
 //! 保存済みコミットから月次の行数集計を行う。
 
+mod aggregate;
+pub mod alert;
+pub mod bus_factor;
+pub mod commit_size;
+pub mod comparison;
+pub mod heatmap;
+pub mod lifecycle;
 pub mod monthly_cross_view;
 pub mod monthly_project_view;
+pub mod trend;
 pub mod types;
+pub mod working_days;
 
+pub use alert::*;
+pub use bus_factor::*;
+pub use commit_size::*;
+pub use comparison::*;
+pub use heatmap::*;
+pub use lifecycle::*;
 pub use monthly_cross_view::*;
 pub use monthly_project_view::*;
+pub use trend::*;
 pub use types::*;
+pub use working_days::*;
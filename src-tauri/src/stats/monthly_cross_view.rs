@@ -3,54 +3,27 @@
 //! 全プロジェクト横断で月次コミット行数を集計する。
 
 use crate::domain::Commit;
-use crate::stats::{MonthlyStatsResponse, UserStats};
-use std::collections::HashMap;
+use crate::stats::aggregate::aggregate_monthly;
+use crate::stats::{AggregateOptions, MonthlyStatsResponse};
 
 /// 横断ビューの月次集計を行う
 ///
 /// # Arguments
 /// * `commits` - 対象コミット一覧（既に年でフィルタ済み）
 /// * `user_keys` - フィルタするユーザーキー（空の場合は全ユーザー）
-pub fn aggregate_cross_view(commits: &[Commit], user_keys: &[String]) -> MonthlyStatsResponse {
-    let mut user_stats_map: HashMap<String, UserStats> = HashMap::new();
-
-    for commit in commits {
-        let user_key = commit.user_key();
-
-        // ユーザーフィルタ
-        if !user_keys.is_empty() && !user_keys.contains(&user_key) {
-            continue;
-        }
-
-        let month_index = (commit.month() as usize).saturating_sub(1);
-        if month_index >= 12 {
-            continue;
-        }
-
-        let stats = user_stats_map
-            .entry(user_key.clone())
-            .or_insert_with(|| UserStats::new(commit.display_name()));
-
-        stats.monthly_totals[month_index] += commit.total_lines();
-        if commit.stats_missing {
-            stats.monthly_missing[month_index] += 1;
-        }
-    }
-
-    let series: Vec<_> = user_stats_map
-        .iter()
-        .map(|(key, stats)| stats.to_series(key))
-        .collect();
-
-    MonthlyStatsResponse {
-        months: (1..=12).collect(),
-        series,
-    }
+/// * `options` - カテゴリフィルタ/カテゴリ分割/ボット除外
+pub fn aggregate_cross_view(
+    commits: &[Commit],
+    user_keys: &[String],
+    options: &AggregateOptions,
+) -> MonthlyStatsResponse {
+    aggregate_monthly(commits, user_keys, options)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::stats::Metric;
     use chrono::{TimeZone, Utc};
 
     fn create_commit(
@@ -66,11 +39,15 @@ mod tests {
             sha: format!("sha-{}-{}-{}", project_id, user, month),
             message: "test".to_string(),
             committed_date_utc: Utc.with_ymd_and_hms(2026, month, 15, 12, 0, 0).unwrap(),
+            authored_date_utc: None,
             author_name: user.to_string(),
             author_email: Some(format!("{}@example.com", user.to_lowercase())),
             additions,
             deletions: 0,
             stats_missing: missing,
+            date_invalid: false,
+            category: "other".to_string(),
+            is_bot: false,
         }
     }
 
@@ -82,7 +59,7 @@ mod tests {
             create_commit(1, 1, "Bob", 80, false),
         ];
 
-        let result = aggregate_cross_view(&commits, &[]);
+        let result = aggregate_cross_view(&commits, &[], &AggregateOptions::default());
 
         // Alice の 1月は project 1 + project 2 の合計
         let alice = result
@@ -101,7 +78,7 @@ mod tests {
             create_commit(1, 2, "Alice", 100, false),
         ];
 
-        let result = aggregate_cross_view(&commits, &[]);
+        let result = aggregate_cross_view(&commits, &[], &AggregateOptions::default());
 
         let alice = result
             .series
@@ -111,4 +88,175 @@ mod tests {
         // 2月に2件欠損
         assert_eq!(alice.missing_counts[1], 2);
     }
+
+    #[test]
+    fn test_cross_view_excludes_bots_separately() {
+        let mut bot = create_commit(1, 3, "renovate[bot]", 40, false);
+        bot.is_bot = true;
+        let commits = vec![create_commit(1, 3, "Alice", 100, false), bot];
+
+        let options = AggregateOptions {
+            exclude_bots: true,
+            ..Default::default()
+        };
+        let result = aggregate_cross_view(&commits, &[], &options);
+
+        assert_eq!(result.series.len(), 1);
+        let excluded = result.excluded_bots.unwrap();
+        assert_eq!(excluded.commit_count, 1);
+        assert_eq!(excluded.totals[2], 40);
+        assert_eq!(excluded.series[0].display_name, "renovate[bot]");
+
+        // 除外しない場合は通常のユーザーとして集計
+        let result = aggregate_cross_view(&commits, &[], &AggregateOptions::default());
+        assert_eq!(result.series.len(), 2);
+        assert!(result.excluded_bots.is_none());
+    }
+
+    #[test]
+    fn test_cross_view_excluded_bot_totals_follow_metric() {
+        let bot = |name: &str, lines: i64| {
+            let mut commit = create_commit(1, 3, name, lines, false);
+            commit.sha = format!("{}-{}", commit.sha, lines);
+            commit.is_bot = true;
+            commit
+        };
+        let commits = vec![
+            create_commit(1, 3, "Alice", 100, false),
+            bot("renovate[bot]", 40),
+            bot("renovate[bot]", 10),
+            bot("dependabot[bot]", 30),
+        ];
+
+        let options = AggregateOptions {
+            exclude_bots: true,
+            metric: Metric::CommitCount,
+            ..Default::default()
+        };
+        let excluded = aggregate_cross_view(&commits, &[], &options)
+            .excluded_bots
+            .unwrap();
+        assert_eq!(excluded.totals[2], 3);
+
+        // 平均コミットサイズは全ボットのコミットで平均する（ボットごとの平均の和ではない）
+        let options = AggregateOptions {
+            metric: Metric::AvgCommitSize,
+            ..options
+        };
+        let excluded = aggregate_cross_view(&commits, &[], &options)
+            .excluded_bots
+            .unwrap();
+        assert_eq!(excluded.totals[2], 27);
+    }
+
+    #[test]
+    fn test_cross_view_groups_by_team_membership_period() {
+        use crate::domain::{Team, TeamMember};
+        use crate::stats::GroupBy;
+        use chrono::NaiveDate;
+
+        let team = Team {
+            id: 7,
+            name: "Platform".to_string(),
+            members: vec![
+                TeamMember {
+                    user_key: "alice@example.com".to_string(),
+                    valid_from: None,
+                    valid_until: None,
+                },
+                // Bob は 3月から所属
+                TeamMember {
+                    user_key: "bob@example.com".to_string(),
+                    valid_from: NaiveDate::from_ymd_opt(2026, 3, 1),
+                    valid_until: None,
+                },
+            ],
+        };
+        let commits = vec![
+            create_commit(1, 2, "Alice", 100, false),
+            create_commit(1, 2, "Bob", 30, false), // 所属前
+            create_commit(2, 3, "Bob", 50, false),
+            create_commit(1, 3, "Carol", 70, false), // 未所属
+        ];
+
+        let options = AggregateOptions {
+            group_by: GroupBy::Team,
+            teams: vec![team],
+            year: 2026,
+            ..Default::default()
+        };
+        let result = aggregate_cross_view(&commits, &[], &options);
+
+        assert_eq!(result.series.len(), 1);
+        let platform = &result.series[0];
+        assert_eq!(platform.user_key, "team:7");
+        assert_eq!(platform.display_name, "Platform");
+        assert_eq!(platform.totals[1], 100);
+        assert_eq!(platform.totals[2], 50);
+        assert_eq!(platform.contributor_count, Some(2));
+        let member_counts = platform.member_counts.as_ref().unwrap();
+        assert_eq!(member_counts[1], 1);
+        assert_eq!(member_counts[2], 2);
+    }
+
+    fn create_project(project_id: i64, path: &str) -> (i64, crate::domain::Project) {
+        (
+            project_id,
+            crate::domain::Project {
+                project_id,
+                name: path.rsplit('/').next().unwrap().to_string(),
+                path_with_namespace: path.to_string(),
+                web_url: format!("https://gitlab.example.com/{}", path),
+            },
+        )
+    }
+
+    #[test]
+    fn test_cross_view_breakdown_and_project_mode() {
+        use crate::stats::{GroupBy, ProjectBreakdown};
+
+        let projects = [
+            create_project(1, "web/app"),
+            create_project(2, "web/lib"),
+            create_project(3, "infra/ops"),
+        ]
+        .into_iter()
+        .collect();
+        let commits = vec![
+            create_commit(1, 1, "Alice", 100, false),
+            create_commit(2, 1, "Alice", 50, false),
+            create_commit(3, 2, "Alice", 10, false),
+            create_commit(1, 1, "Bob", 80, false),
+        ];
+
+        let options = AggregateOptions {
+            breakdown: Some(ProjectBreakdown::Namespace),
+            projects,
+            ..Default::default()
+        };
+        let result = aggregate_cross_view(&commits, &[], &options);
+        let alice = result
+            .series
+            .iter()
+            .find(|s| s.display_name == "Alice")
+            .unwrap();
+        let project_totals = alice.project_totals.as_ref().unwrap();
+        assert_eq!(project_totals["web"][0], 150);
+        assert_eq!(project_totals["infra"][1], 10);
+
+        let options = AggregateOptions {
+            group_by: GroupBy::Project,
+            ..options
+        };
+        let result = aggregate_cross_view(&commits, &[], &options);
+        assert_eq!(result.series.len(), 3);
+        let app = result
+            .series
+            .iter()
+            .find(|s| s.user_key == "project:1")
+            .unwrap();
+        assert_eq!(app.display_name, "web/app");
+        assert_eq!(app.totals[0], 180);
+        assert_eq!(app.contributor_count, Some(2));
+    }
 }
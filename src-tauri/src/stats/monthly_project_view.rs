@@ -3,49 +3,21 @@
 //! 特定プロジェクト/ブランチの月次コミット行数を集計する。
 
 use crate::domain::Commit;
-use crate::stats::{MonthlyStatsResponse, UserStats};
-use std::collections::HashMap;
+use crate::stats::aggregate::aggregate_monthly;
+use crate::stats::{AggregateOptions, MonthlyStatsResponse};
 
 /// プロジェクトビューの月次集計を行う
 ///
 /// # Arguments
 /// * `commits` - 対象コミット一覧（既にプロジェクト/ブランチ/年でフィルタ済み）
 /// * `user_keys` - フィルタするユーザーキー（空の場合は全ユーザー）
-pub fn aggregate_project_view(commits: &[Commit], user_keys: &[String]) -> MonthlyStatsResponse {
-    let mut user_stats_map: HashMap<String, UserStats> = HashMap::new();
-
-    for commit in commits {
-        let user_key = commit.user_key();
-
-        // ユーザーフィルタ
-        if !user_keys.is_empty() && !user_keys.contains(&user_key) {
-            continue;
-        }
-
-        let month_index = (commit.month() as usize).saturating_sub(1);
-        if month_index >= 12 {
-            continue;
-        }
-
-        let stats = user_stats_map
-            .entry(user_key.clone())
-            .or_insert_with(|| UserStats::new(commit.display_name()));
-
-        stats.monthly_totals[month_index] += commit.total_lines();
-        if commit.stats_missing {
-            stats.monthly_missing[month_index] += 1;
-        }
-    }
-
-    let series: Vec<_> = user_stats_map
-        .iter()
-        .map(|(key, stats)| stats.to_series(key))
-        .collect();
-
-    MonthlyStatsResponse {
-        months: (1..=12).collect(),
-        series,
-    }
+/// * `options` - カテゴリフィルタ/カテゴリ分割/ボット除外
+pub fn aggregate_project_view(
+    commits: &[Commit],
+    user_keys: &[String],
+    options: &AggregateOptions,
+) -> MonthlyStatsResponse {
+    aggregate_monthly(commits, user_keys, options)
 }
 
 #[cfg(test)]
@@ -67,11 +39,15 @@ mod tests {
             sha: format!("sha-{}-{}", user, month),
             message: "test".to_string(),
             committed_date_utc: Utc.with_ymd_and_hms(2026, month, 15, 12, 0, 0).unwrap(),
+            authored_date_utc: None,
             author_name: user.to_string(),
             author_email: email.map(|s| s.to_string()),
             additions,
             deletions,
             stats_missing: missing,
+            date_invalid: false,
+            category: "other".to_string(),
+            is_bot: false,
         }
     }
 
@@ -83,7 +59,7 @@ mod tests {
             create_commit(2, "Alice", Some("alice@example.com"), 60, 40, false),
         ];
 
-        let result = aggregate_project_view(&commits, &[]);
+        let result = aggregate_project_view(&commits, &[], &AggregateOptions::default());
 
         assert_eq!(result.months.len(), 12);
         assert_eq!(result.series.len(), 2);
@@ -113,13 +89,47 @@ mod tests {
             create_commit(1, "Bob", None, 50, 0, false),
         ];
 
-        let result = aggregate_project_view(&commits, &["alice@example.com".to_string()]);
+        let result = aggregate_project_view(
+            &commits,
+            &["alice@example.com".to_string()],
+            &AggregateOptions::default(),
+        );
 
         // Alice のみ
         assert_eq!(result.series.len(), 1);
         assert_eq!(result.series[0].display_name, "Alice");
     }
 
+    #[test]
+    fn test_aggregate_with_category_filter_and_split() {
+        let mut feat = create_commit(1, "Alice", None, 100, 0, false);
+        feat.category = "feat".to_string();
+        let mut fix = create_commit(1, "Alice", None, 30, 0, false);
+        fix.category = "fix".to_string();
+        let other = create_commit(2, "Alice", None, 5, 0, false);
+        let commits = vec![feat, fix, other];
+
+        let split = AggregateOptions {
+            split_by_category: true,
+            ..Default::default()
+        };
+        let result = aggregate_project_view(&commits, &[], &split);
+        let category_totals = result.series[0].category_totals.as_ref().unwrap();
+        assert_eq!(result.series[0].totals[0], 130);
+        assert_eq!(category_totals["feat"][0], 100);
+        assert_eq!(category_totals["fix"][0], 30);
+        assert_eq!(category_totals["other"][1], 5);
+
+        let filtered = AggregateOptions {
+            categories: vec!["fix".to_string(), "other".to_string()],
+            ..Default::default()
+        };
+        let result = aggregate_project_view(&commits, &[], &filtered);
+        assert_eq!(result.series[0].totals[0], 30);
+        assert_eq!(result.series[0].totals[1], 5);
+        assert!(result.series[0].category_totals.is_none());
+    }
+
     #[test]
     fn test_aggregate_missing_stats_count() {
         let commits = vec![
@@ -127,7 +137,7 @@ mod tests {
             create_commit(3, "Alice", None, 100, 0, false),
         ];
 
-        let result = aggregate_project_view(&commits, &[]);
+        let result = aggregate_project_view(&commits, &[], &AggregateOptions::default());
 
         let alice = result
             .series
@@ -146,14 +156,18 @@ mod tests {
             sha: "test".to_string(),
             message: "test".to_string(),
             committed_date_utc: Utc.with_ymd_and_hms(2026, 6, 30, 23, 59, 59).unwrap(),
+            authored_date_utc: None,
             author_name: "Test".to_string(),
             author_email: None,
             additions: 10,
             deletions: 0,
             stats_missing: false,
+            date_invalid: false,
+            category: "other".to_string(),
+            is_bot: false,
         };
 
-        let result = aggregate_project_view(&[commit], &[]);
+        let result = aggregate_project_view(&[commit], &[], &AggregateOptions::default());
 
         let test_user = result
             .series
@@ -0,0 +1,406 @@
+//! トレンドと簡易予測
+//!
+//! 月次集計の系列ごとに移動平均、線形回帰の傾き、季節ナイーブ予測（前の季節の同じ月の値）を求める。
+//! 当年の集計では未経過の月を含めないよう、観測する月を指定できる。
+//! 季節 1 周期分の実績がない場合（前年のデータがなく当年も途中など）は、直近の値をそのまま使う
+//! ナイーブ予測に切り替え、どちらで予測したかをレスポンスに含める。
+
+use crate::error::{AppError, AppResult};
+use crate::stats::MonthlyStatsResponse;
+use serde::{Deserialize, Serialize};
+
+/// トレンドの計算設定
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrendOptions {
+    /// 移動平均の期間（月数、既定: 3）
+    #[serde(default = "default_moving_average_window")]
+    pub moving_average_window: usize,
+    /// 予測する月数（既定: 3）
+    #[serde(default = "default_forecast_periods")]
+    pub forecast_periods: usize,
+    /// 季節の長さ（月数、既定: 12）
+    #[serde(default = "default_season_length")]
+    pub season_length: usize,
+    /// 観測する最後の月（省略時は集計コマンドが決める。当年なら前月まで）
+    #[serde(default)]
+    pub through_month: Option<u32>,
+}
+
+fn default_moving_average_window() -> usize {
+    3
+}
+
+fn default_forecast_periods() -> usize {
+    3
+}
+
+fn default_season_length() -> usize {
+    12
+}
+
+impl Default for TrendOptions {
+    fn default() -> Self {
+        Self {
+            moving_average_window: default_moving_average_window(),
+            forecast_periods: default_forecast_periods(),
+            season_length: default_season_length(),
+            through_month: None,
+        }
+    }
+}
+
+impl TrendOptions {
+    /// 設定を検証
+    pub fn validate(&self) -> AppResult<()> {
+        if !(1..=12).contains(&self.moving_average_window) {
+            return Err(AppError::Validation(format!(
+                "移動平均の期間は 1〜12 ヶ月で指定してください: {}",
+                self.moving_average_window
+            )));
+        }
+        if self.forecast_periods > 24 {
+            return Err(AppError::Validation(format!(
+                "予測する月数は 24 ヶ月以下で指定してください: {}",
+                self.forecast_periods
+            )));
+        }
+        if self.season_length == 0 {
+            return Err(AppError::Validation(
+                "季節の長さは 1 以上で指定してください".to_string(),
+            ));
+        }
+        if self.through_month.is_some_and(|m| !(1..=12).contains(&m)) {
+            return Err(AppError::Validation(format!(
+                "観測する最後の月が不正です: {:?}",
+                self.through_month
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// 予測の方法
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ForecastMethod {
+    /// 季節ナイーブ（1 季節前の同じ月の値）
+    SeasonalNaive,
+    /// ナイーブ（直近の値。季節 1 周期分の実績がない場合）
+    Naive,
+}
+
+/// 予測値
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ForecastPoint {
+    /// 年
+    pub year: i32,
+    /// 月
+    pub month: u32,
+    /// 予測値
+    pub value: f64,
+}
+
+/// 系列ごとのトレンド
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SeriesTrend {
+    /// 系列キー（series の userKey に対応）
+    pub user_key: String,
+    /// 月別の移動平均（months 配列に対応、期間に満たない月と未観測の月は null）
+    pub moving_average: Vec<Option<f64>>,
+    /// 線形回帰の傾き（1 ヶ月あたりの増減）
+    pub slope: f64,
+    /// 線形回帰の切片（1 月時点の値）
+    pub intercept: f64,
+    /// 観測した最後の月の翌月からの予測
+    pub forecast: Vec<ForecastPoint>,
+}
+
+/// トレンドのセクション
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrendSection {
+    /// 移動平均の期間（月数）
+    pub moving_average_window: usize,
+    /// 季節の長さ（月数）
+    pub season_length: usize,
+    /// 予測の方法（季節 1 周期分の実績がなければナイーブ）
+    pub forecast_method: ForecastMethod,
+    /// 観測した最後の月（0 は当年の実績なし）
+    pub through_month: u32,
+    /// 系列ごとのトレンド（series と同じ順）
+    pub series: Vec<SeriesTrend>,
+}
+
+/// 月次集計の各系列のトレンドを求める
+///
+/// # Arguments
+/// * `response` - 月次集計（months は `year` の 1-12 月）
+/// * `previous_year` - 前年の同じ条件の月次集計（季節ナイーブ予測で前年の同じ月を参照する。None なら当年のみ）
+/// * `year` - 対象年
+/// * `options` - 移動平均の期間、予測する月数、季節の長さ、観測する最後の月（None なら 12 月まで、0 なら当年の実績なし）
+pub fn compute_trends(
+    response: &MonthlyStatsResponse,
+    previous_year: Option<&MonthlyStatsResponse>,
+    year: i32,
+    options: &TrendOptions,
+) -> TrendSection {
+    let through_month = options.through_month.unwrap_or(12).min(12);
+    let observed = (through_month as usize).min(response.months.len());
+    // 前年にコミットがなければ前年の実績はないものとして扱う
+    let previous_year = previous_year.filter(|previous| !previous.series.is_empty());
+    let history_length = observed + previous_year.map_or(0, |previous| previous.months.len());
+    let forecast_method = if history_length >= options.season_length {
+        ForecastMethod::SeasonalNaive
+    } else {
+        ForecastMethod::Naive
+    };
+
+    let series = response
+        .series
+        .iter()
+        .map(|series| {
+            let values: Vec<f64> = series.totals[..observed.min(series.totals.len())]
+                .iter()
+                .map(|&v| v as f64)
+                .collect();
+            let (slope, intercept) = linear_regression(&values);
+
+            // 前年の同じ系列（前年に活動がなければ 0）に当年の実績を続けた履歴
+            let mut history: Vec<f64> = match previous_year {
+                Some(previous) => previous
+                    .series
+                    .iter()
+                    .find(|s| s.user_key == series.user_key)
+                    .map(|s| s.totals.iter().map(|&v| v as f64).collect())
+                    .unwrap_or_else(|| vec![0.0; previous.months.len()]),
+                None => Vec::new(),
+            };
+            history.extend(&values);
+            let forecast = match forecast_method {
+                ForecastMethod::SeasonalNaive => {
+                    seasonal_naive(&history, options.season_length, options.forecast_periods)
+                }
+                ForecastMethod::Naive => naive(&history, options.forecast_periods),
+            };
+
+            SeriesTrend {
+                user_key: series.user_key.clone(),
+                moving_average: (0..response.months.len())
+                    .map(|index| moving_average(&values, index, options.moving_average_window))
+                    .collect(),
+                slope,
+                intercept,
+                forecast: forecast
+                    .into_iter()
+                    .enumerate()
+                    .map(|(step, value)| {
+                        let offset = through_month as usize + step;
+                        ForecastPoint {
+                            year: year + (offset / 12) as i32,
+                            month: (offset % 12) as u32 + 1,
+                            value,
+                        }
+                    })
+                    .collect(),
+            }
+        })
+        .collect();
+
+    TrendSection {
+        moving_average_window: options.moving_average_window,
+        season_length: options.season_length,
+        forecast_method,
+        through_month,
+        series,
+    }
+}
+
+/// `index` の月で終わる移動平均（期間に満たない/未観測なら None）
+fn moving_average(values: &[f64], index: usize, window: usize) -> Option<f64> {
+    if window == 0 || index >= values.len() || index + 1 < window {
+        return None;
+    }
+    let slice = &values[index + 1 - window..=index];
+    Some(slice.iter().sum::<f64>() / window as f64)
+}
+
+/// 最小二乗法の (傾き, 切片)。x は 0 始まりの月インデックス
+fn linear_regression(values: &[f64]) -> (f64, f64) {
+    let n = values.len() as f64;
+    if values.len() < 2 {
+        return (0.0, values.first().copied().unwrap_or_default());
+    }
+    let mean_x = (n - 1.0) / 2.0;
+    let mean_y = values.iter().sum::<f64>() / n;
+    let (mut covariance, mut variance) = (0.0, 0.0);
+    for (index, value) in values.iter().enumerate() {
+        let dx = index as f64 - mean_x;
+        covariance += dx * (value - mean_y);
+        variance += dx * dx;
+    }
+    let slope = covariance / variance;
+    (slope, mean_y - slope * mean_x)
+}
+
+/// 季節ナイーブ予測（h 期先 = 直近の季節の同じ位置の値。`values` は季節 1 周期分以上）
+fn seasonal_naive(values: &[f64], season_length: usize, periods: usize) -> Vec<f64> {
+    let season = &values[values.len() - season_length..];
+    (0..periods).map(|h| season[h % season_length]).collect()
+}
+
+/// ナイーブ予測（直近の値が続く。実績がなければ 0）
+fn naive(values: &[f64], periods: usize) -> Vec<f64> {
+    vec![values.last().copied().unwrap_or_default(); periods]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::UserMonthlySeries;
+
+    fn response(totals: Vec<i64>) -> MonthlyStatsResponse {
+        MonthlyStatsResponse {
+            series: vec![UserMonthlySeries {
+                user_key: "alice@example.com".to_string(),
+                display_name: "Alice".to_string(),
+                totals,
+                missing_counts: vec![0; 12],
+                category_totals: None,
+                project_totals: None,
+                member_counts: None,
+                contributor_count: None,
+                per_working_day: None,
+            }],
+            ..MonthlyStatsResponse::empty()
+        }
+    }
+
+    #[test]
+    fn test_linear_trend_and_moving_average() {
+        let totals: Vec<i64> = (1..=12).map(|m| m * 10).collect();
+        let section = compute_trends(&response(totals), None, 2026, &TrendOptions::default());
+
+        let trend = &section.series[0];
+        assert!((trend.slope - 10.0).abs() < 1e-9);
+        assert!((trend.intercept - 10.0).abs() < 1e-9);
+        assert_eq!(trend.moving_average[0], None);
+        assert_eq!(trend.moving_average[2], Some(20.0));
+        assert_eq!(trend.moving_average[11], Some(110.0));
+
+        // 季節の長さ 12: 翌年 1〜3 月は当年 1〜3 月の値
+        assert_eq!(
+            trend.forecast,
+            vec![
+                ForecastPoint {
+                    year: 2027,
+                    month: 1,
+                    value: 10.0
+                },
+                ForecastPoint {
+                    year: 2027,
+                    month: 2,
+                    value: 20.0
+                },
+                ForecastPoint {
+                    year: 2027,
+                    month: 3,
+                    value: 30.0
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_partial_year_uses_observed_months_only() {
+        // 6 月までの実績（7 月以降は未経過で 0）
+        let totals = vec![10, 20, 10, 20, 10, 20, 0, 0, 0, 0, 0, 0];
+        let options = TrendOptions {
+            season_length: 2,
+            forecast_periods: 3,
+            through_month: Some(6),
+            ..Default::default()
+        };
+        let section = compute_trends(&response(totals), None, 2026, &options);
+
+        assert_eq!(section.through_month, 6);
+        assert_eq!(section.forecast_method, ForecastMethod::SeasonalNaive);
+        let trend = &section.series[0];
+        assert_eq!(trend.moving_average[6], None);
+        let forecast: Vec<(u32, f64)> = trend.forecast.iter().map(|p| (p.month, p.value)).collect();
+        assert_eq!(forecast, vec![(7, 10.0), (8, 20.0), (9, 10.0)]);
+
+        assert!(TrendOptions {
+            moving_average_window: 0,
+            ..Default::default()
+        }
+        .validate()
+        .is_err());
+    }
+
+    #[test]
+    fn test_forecast_uses_previous_year_same_months() {
+        // 当年は 3 月まで、前年は毎月 (月 × 100)
+        let previous: Vec<i64> = (1..=12).map(|m| m * 100).collect();
+        let totals = vec![5, 6, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        let options = TrendOptions {
+            through_month: Some(3),
+            ..Default::default()
+        };
+
+        let section = compute_trends(
+            &response(totals.clone()),
+            Some(&response(previous)),
+            2026,
+            &options,
+        );
+        assert_eq!(section.season_length, 12);
+        assert_eq!(section.forecast_method, ForecastMethod::SeasonalNaive);
+        let forecast: Vec<(u32, f64)> = section.series[0]
+            .forecast
+            .iter()
+            .map(|p| (p.month, p.value))
+            .collect();
+        assert_eq!(forecast, vec![(4, 400.0), (5, 500.0), (6, 600.0)]);
+
+        // 前年のデータがなければ直近の値で予測し、方法をナイーブとして返す
+        let section = compute_trends(
+            &response(totals),
+            Some(&MonthlyStatsResponse::empty()),
+            2026,
+            &options,
+        );
+        assert_eq!(section.forecast_method, ForecastMethod::Naive);
+        assert!(section.series[0].forecast.iter().all(|p| p.value == 7.0));
+    }
+
+    #[test]
+    fn test_january_has_no_observed_months() {
+        // 1 月時点では当年の実績を含めず、前年の同じ月から予測する
+        let previous: Vec<i64> = (1..=12).map(|m| m * 100).collect();
+        let options = TrendOptions {
+            through_month: Some(0),
+            forecast_periods: 2,
+            ..Default::default()
+        };
+
+        let section = compute_trends(
+            &response(vec![3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]),
+            Some(&response(previous)),
+            2026,
+            &options,
+        );
+        assert_eq!(section.through_month, 0);
+        let trend = &section.series[0];
+        assert!(trend.moving_average.iter().all(Option::is_none));
+        assert_eq!(trend.slope, 0.0);
+        let forecast: Vec<(i32, u32, f64)> = trend
+            .forecast
+            .iter()
+            .map(|p| (p.year, p.month, p.value))
+            .collect();
+        assert_eq!(forecast, vec![(2026, 1, 100.0), (2026, 2, 200.0)]);
+    }
+}
@@ -2,7 +2,11 @@
 //!
 //! 集計レスポンスとユーザー別データ系列を定義する。
 
-use serde::Serialize;
+use crate::domain::{Commit, Project, Team};
+use crate::stats::{OutlierReport, TrendSection};
+use chrono::Datelike;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
 /// 月次集計レスポンス
 #[derive(Debug, Clone, Serialize)]
@@ -10,8 +14,34 @@ use serde::Serialize;
 pub struct MonthlyStatsResponse {
     /// 対象月の配列（1-12）
     pub months: Vec<u32>,
+    /// series の値の指標
+    pub metric: Metric,
     /// ユーザー別データ系列
     pub series: Vec<UserMonthlySeries>,
+    /// 除外したボットの集計（excludeBots 指定時のみ）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub excluded_bots: Option<ExcludedBotStats>,
+    /// 除外/頭打ちした外れ値コミット（outliers 指定時のみ）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outliers: Option<OutlierReport>,
+    /// 月別のカレンダー上の稼働日数（perWorkingDay 指定時のみ）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub working_days: Option<Vec<u32>>,
+    /// 系列ごとのトレンドと予測（trend 指定時のみ）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trend: Option<TrendSection>,
+}
+
+/// 除外したボットの集計
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExcludedBotStats {
+    /// 除外したコミット数
+    pub commit_count: usize,
+    /// 月別の指標値（months 配列に対応、全ボットのコミットを合算して算出）
+    pub totals: Vec<i64>,
+    /// ボット別データ系列
+    pub series: Vec<UserMonthlySeries>,
 }
 
 /// ユーザー別月次データ系列
@@ -23,10 +53,27 @@ pub struct UserMonthlySeries {
     pub user_key: String,
     /// 表示名（authorName）
     pub display_name: String,
-    /// 月別合計行数（months 配列に対応）
+    /// 月別の指標値（months 配列に対応、既定は合計行数）
     pub totals: Vec<i64>,
     /// 月別欠損コミット件数（months 配列に対応）
     pub missing_counts: Vec<usize>,
+    /// カテゴリ別の月別指標値（splitByCategory 指定時のみ）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_totals: Option<BTreeMap<String, Vec<i64>>>,
+    /// プロジェクト（または名前空間）別の月別指標値（breakdown 指定時のみ）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_totals: Option<BTreeMap<String, Vec<i64>>>,
+    /// 月別の所属メンバー数（チーム集計時のみ）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member_counts: Option<Vec<usize>>,
+    /// コミットが集計されたメンバー/ユーザー数（チーム集計・プロジェクト集計時のみ）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contributor_count: Option<usize>,
+    /// 月別の稼働日あたりの指標値（perWorkingDay 指定時のみ、稼働日がない月は null）
+    ///
+    /// ユーザー別集計では本人の休暇を除いた稼働日数、チーム/プロジェクト集計ではカレンダー上の稼働日数で割る。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_working_day: Option<Vec<Option<f64>>>,
 }
 
 impl MonthlyStatsResponse {
@@ -34,20 +81,223 @@ impl MonthlyStatsResponse {
     pub fn empty() -> Self {
         Self {
             months: (1..=12).collect(),
+            metric: Metric::default(),
             series: Vec::new(),
+            excluded_bots: None,
+            outliers: None,
+            working_days: None,
+            trend: None,
+        }
+    }
+}
+
+/// 集計単位
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GroupBy {
+    /// ユーザー別
+    #[default]
+    User,
+    /// チーム別（コミット日時点の所属で振り分け）
+    Team,
+    /// プロジェクト別
+    Project,
+}
+
+/// ユーザーごとの内訳の単位
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectBreakdown {
+    /// プロジェクト（path_with_namespace）別
+    Project,
+    /// 名前空間別
+    Namespace,
+}
+
+/// 集計オプション（プロジェクトビュー/横断ビュー共通）
+#[derive(Debug, Clone, Default)]
+pub struct AggregateOptions {
+    /// 対象カテゴリ（空の場合は全カテゴリ）
+    pub categories: Vec<String>,
+    /// ユーザーごとの合計をカテゴリ別にも出力するか
+    pub split_by_category: bool,
+    /// ボットのコミットを除外し、別枠で集計するか
+    pub exclude_bots: bool,
+    /// 集計単位
+    pub group_by: GroupBy,
+    /// チーム定義（group_by が Team の場合に使用）
+    pub teams: Vec<Team>,
+    /// 系列ごとのプロジェクト/名前空間内訳
+    pub breakdown: Option<ProjectBreakdown>,
+    /// 出力する指標
+    pub metric: Metric,
+    /// プロジェクト定義（group_by が Project または breakdown 指定時に使用）
+    pub projects: HashMap<i64, Project>,
+    /// 集計対象の年（group_by が Team の場合の月別所属人数に使用）
+    pub year: i32,
+}
+
+impl AggregateOptions {
+    /// コミットが集計対象か（カテゴリフィルタ）
+    pub fn includes(&self, commit: &Commit) -> bool {
+        self.categories.is_empty() || self.categories.contains(&commit.category)
+    }
+
+    /// プロジェクト定義の読み込みが必要か
+    pub fn needs_projects(&self) -> bool {
+        self.group_by == GroupBy::Project || self.breakdown.is_some()
+    }
+
+    /// プロジェクトの表示名（path_with_namespace、未登録なら ID）
+    pub fn project_label(&self, project_id: i64) -> String {
+        self.projects
+            .get(&project_id)
+            .map(|p| p.path_with_namespace.clone())
+            .unwrap_or_else(|| format!("project:{}", project_id))
+    }
+
+    /// 内訳のキー（breakdown 未指定なら None）
+    pub fn breakdown_key(&self, project_id: i64) -> Option<String> {
+        match self.breakdown? {
+            ProjectBreakdown::Project => Some(self.project_label(project_id)),
+            ProjectBreakdown::Namespace => Some(
+                self.projects
+                    .get(&project_id)
+                    .map(|p| p.namespace().to_string())
+                    .unwrap_or_else(|| format!("project:{}", project_id)),
+            ),
+        }
+    }
+}
+
+/// 集計指標
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Metric {
+    /// 変更行数（追加 + 削除）
+    #[default]
+    TotalLines,
+    /// 追加行数
+    Additions,
+    /// 削除行数
+    Deletions,
+    /// 純増行数（追加 - 削除）
+    NetLines,
+    /// コミット数
+    CommitCount,
+    /// コミットした日数（UTC の日付単位）
+    ActiveDays,
+    /// 1 コミットあたりの平均変更行数（四捨五入）
+    AvgCommitSize,
+    /// 1 コミットあたりの変更行数の中央値（四捨五入）
+    MedianCommitSize,
+}
+
+impl Metric {
+    /// 表形式で出力する際の列名
+    pub fn column_name(&self) -> &'static str {
+        match self {
+            Self::TotalLines => "total_lines",
+            Self::Additions => "additions",
+            Self::Deletions => "deletions",
+            Self::NetLines => "net_lines",
+            Self::CommitCount => "commit_count",
+            Self::ActiveDays => "active_days",
+            Self::AvgCommitSize => "avg_commit_size",
+            Self::MedianCommitSize => "median_commit_size",
+        }
+    }
+}
+
+/// 月（バケット）単位の指標の中間データ
+#[derive(Debug, Clone, Default)]
+pub struct MonthMetrics {
+    /// 追加行数
+    pub additions: i64,
+    /// 削除行数
+    pub deletions: i64,
+    /// コミット数
+    pub commit_count: i64,
+    /// コミットした日（UTC の日）
+    pub active_days: BTreeSet<u32>,
+    /// コミットごとの変更行数（中央値用）
+    pub commit_sizes: Vec<i64>,
+}
+
+impl MonthMetrics {
+    /// コミットを加算
+    pub fn add_commit(&mut self, commit: &Commit) {
+        self.additions += commit.additions;
+        self.deletions += commit.deletions;
+        self.commit_count += 1;
+        self.active_days.insert(commit.committed_date_utc.day());
+        self.commit_sizes.push(commit.total_lines());
+    }
+
+    /// 別の集計を合算
+    pub fn merge(&mut self, other: &MonthMetrics) {
+        self.additions += other.additions;
+        self.deletions += other.deletions;
+        self.commit_count += other.commit_count;
+        self.active_days.extend(&other.active_days);
+        self.commit_sizes.extend(&other.commit_sizes);
+    }
+
+    /// 指標の値
+    pub fn value(&self, metric: Metric) -> i64 {
+        match metric {
+            Metric::TotalLines => self.additions + self.deletions,
+            Metric::Additions => self.additions,
+            Metric::Deletions => self.deletions,
+            Metric::NetLines => self.additions - self.deletions,
+            Metric::CommitCount => self.commit_count,
+            Metric::ActiveDays => self.active_days.len() as i64,
+            Metric::AvgCommitSize if self.commit_count == 0 => 0,
+            Metric::AvgCommitSize => {
+                ((self.additions + self.deletions) as f64 / self.commit_count as f64).round() as i64
+            }
+            Metric::MedianCommitSize => median(&self.commit_sizes),
         }
     }
 }
 
+/// 中央値（偶数件の場合は中央 2 件の平均を四捨五入、空なら 0）
+fn median(values: &[i64]) -> i64 {
+    if values.is_empty() {
+        return 0;
+    }
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable();
+    let mid = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        ((sorted[mid - 1] + sorted[mid]) as f64 / 2.0).round() as i64
+    } else {
+        sorted[mid]
+    }
+}
+
+/// 12 ヶ月分の指標を指標値の配列に変換
+pub(crate) fn metric_values(months: &[MonthMetrics; 12], metric: Metric) -> Vec<i64> {
+    months.iter().map(|m| m.value(metric)).collect()
+}
+
 /// ユーザー集計の中間データ
 #[derive(Debug, Clone, Default)]
 pub struct UserStats {
     /// 表示名
     pub display_name: String,
-    /// 月別合計行数（インデックス 0-11 = 1-12月）
-    pub monthly_totals: [i64; 12],
     /// 月別欠損件数
     pub monthly_missing: [usize; 12],
+    /// 月別の指標（インデックス 0-11 = 1-12月）
+    pub monthly_metrics: [MonthMetrics; 12],
+    /// 出力する指標
+    pub metric: Metric,
+    /// カテゴリ別の月別指標（カテゴリ分割時のみ）
+    pub category_totals: BTreeMap<String, [MonthMetrics; 12]>,
+    /// カテゴリ分割を行うか
+    pub split_by_category: bool,
+    /// プロジェクト/名前空間別の月別指標（内訳指定時のみ Some）
+    pub project_totals: Option<BTreeMap<String, [MonthMetrics; 12]>>,
 }
 
 impl UserStats {
@@ -59,13 +309,68 @@ impl UserStats {
         }
     }
 
+    /// カテゴリ分割ありで新規作成
+    pub fn with_category_split(display_name: &str, split_by_category: bool) -> Self {
+        Self {
+            split_by_category,
+            ..Self::new(display_name)
+        }
+    }
+
+    /// 集計オプションに応じて新規作成（指標/カテゴリ分割/プロジェクト内訳）
+    pub fn for_options(display_name: &str, options: &AggregateOptions) -> Self {
+        Self {
+            metric: options.metric,
+            project_totals: options.breakdown.map(|_| BTreeMap::new()),
+            ..Self::with_category_split(display_name, options.split_by_category)
+        }
+    }
+
+    /// 内訳にコミットを加算（内訳指定なしの場合は何もしない）
+    pub fn add_breakdown(&mut self, key: String, month_index: usize, commit: &Commit) {
+        if let Some(project_totals) = &mut self.project_totals {
+            project_totals.entry(key).or_default()[month_index].add_commit(commit);
+        }
+    }
+
+    /// コミットを月別集計に加算
+    pub fn add_commit(&mut self, month_index: usize, commit: &Commit) {
+        self.monthly_metrics[month_index].add_commit(commit);
+        if commit.stats_missing {
+            self.monthly_missing[month_index] += 1;
+        }
+        if self.split_by_category {
+            self.category_totals
+                .entry(commit.category.clone())
+                .or_default()[month_index]
+                .add_commit(commit);
+        }
+    }
+
     /// UserMonthlySeries に変換
     pub fn to_series(&self, user_key: &str) -> UserMonthlySeries {
         UserMonthlySeries {
             user_key: user_key.to_string(),
             display_name: self.display_name.clone(),
-            totals: self.monthly_totals.to_vec(),
+            totals: metric_values(&self.monthly_metrics, self.metric),
             missing_counts: self.monthly_missing.to_vec(),
+            category_totals: self.split_by_category.then(|| {
+                self.category_totals
+                    .iter()
+                    .map(|(category, months)| {
+                        (category.clone(), metric_values(months, self.metric))
+                    })
+                    .collect()
+            }),
+            project_totals: self.project_totals.as_ref().map(|project_totals| {
+                project_totals
+                    .iter()
+                    .map(|(key, months)| (key.clone(), metric_values(months, self.metric)))
+                    .collect()
+            }),
+            member_counts: None,
+            contributor_count: None,
+            per_working_day: None,
         }
     }
 }
@@ -77,8 +382,8 @@ mod tests {
     #[test]
     fn test_user_stats_to_series() {
         let mut stats = UserStats::new("John Doe");
-        stats.monthly_totals[0] = 100; // 1月
-        stats.monthly_totals[5] = 200; // 6月
+        stats.monthly_metrics[0].additions = 100; // 1月
+        stats.monthly_metrics[5].additions = 200; // 6月
         stats.monthly_missing[0] = 1;
 
         let series = stats.to_series("john@example.com");
@@ -98,9 +403,52 @@ mod tests {
             display_name: "John Doe".to_string(),
             totals: vec![0; 12],
             missing_counts: vec![0; 12],
+            category_totals: None,
+            project_totals: None,
+            member_counts: None,
+            contributor_count: None,
+            per_working_day: None,
         };
 
         // display_name には email が含まれない
         assert!(!series.display_name.contains("@"));
     }
+
+    #[test]
+    fn test_metrics_per_month() {
+        use chrono::{TimeZone, Utc};
+
+        let commit = |day: u32, additions: i64, deletions: i64| Commit {
+            project_id: 1,
+            branch_name: "main".to_string(),
+            sha: format!("sha-{}-{}", day, additions),
+            message: String::new(),
+            committed_date_utc: Utc.with_ymd_and_hms(2026, 1, day, 12, 0, 0).unwrap(),
+            authored_date_utc: None,
+            author_name: "Alice".to_string(),
+            author_email: None,
+            additions,
+            deletions,
+            stats_missing: false,
+            date_invalid: false,
+            category: "other".to_string(),
+            is_bot: false,
+        };
+
+        let mut months = MonthMetrics::default();
+        for c in [commit(5, 10, 2), commit(5, 1, 0), commit(7, 30, 10)] {
+            months.add_commit(&c);
+        }
+
+        assert_eq!(months.value(Metric::TotalLines), 53);
+        assert_eq!(months.value(Metric::Additions), 41);
+        assert_eq!(months.value(Metric::Deletions), 12);
+        assert_eq!(months.value(Metric::NetLines), 29);
+        assert_eq!(months.value(Metric::CommitCount), 3);
+        assert_eq!(months.value(Metric::ActiveDays), 2);
+        assert_eq!(months.value(Metric::AvgCommitSize), 18);
+        assert_eq!(months.value(Metric::MedianCommitSize), 12);
+        assert_eq!(MonthMetrics::default().value(Metric::AvgCommitSize), 0);
+        assert_eq!(median(&[1, 4]), 3);
+    }
 }
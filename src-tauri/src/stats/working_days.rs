@@ -0,0 +1,114 @@
+//! 稼働日あたりの正規化
+//!
+//! 月次集計の各月の値を、その月の稼働日数（週末・祝日・休暇を除く）で割る。
+//! 夏季休暇や大型連休のある月が見かけ上落ち込むのを補正するためのもの。
+
+use crate::domain::WorkCalendar;
+use crate::stats::{GroupBy, MonthlyStatsResponse};
+
+/// 月次集計に稼働日数と稼働日あたりの値を付与する
+///
+/// # Arguments
+/// * `response` - 月次集計（months は `year` の月）
+/// * `calendar` - 稼働日カレンダー
+/// * `year` - 対象年
+/// * `group_by` - 集計単位（ユーザー別のときだけ本人の休暇を反映する）
+pub fn apply_working_days(
+    response: &mut MonthlyStatsResponse,
+    calendar: &WorkCalendar,
+    year: i32,
+    group_by: GroupBy,
+) {
+    let working_days: Vec<u32> = response
+        .months
+        .iter()
+        .map(|&month| calendar.working_days_in_month(year, month))
+        .collect();
+
+    for series in &mut response.series {
+        let days: Vec<u32> = match group_by {
+            GroupBy::User => response
+                .months
+                .iter()
+                .map(|&month| calendar.user_working_days_in_month(&series.user_key, year, month))
+                .collect(),
+            GroupBy::Team | GroupBy::Project => working_days.clone(),
+        };
+        series.per_working_day = Some(
+            series
+                .totals
+                .iter()
+                .zip(days)
+                .map(|(&total, days)| (days > 0).then(|| total as f64 / days as f64))
+                .collect(),
+        );
+    }
+
+    response.working_days = Some(working_days);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Holiday, UserLeave};
+    use crate::stats::UserMonthlySeries;
+    use chrono::NaiveDate;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn series(user_key: &str, total: i64) -> UserMonthlySeries {
+        UserMonthlySeries {
+            user_key: user_key.to_string(),
+            display_name: user_key.to_string(),
+            totals: vec![total; 12],
+            missing_counts: vec![0; 12],
+            category_totals: None,
+            project_totals: None,
+            member_counts: None,
+            contributor_count: None,
+            per_working_day: None,
+        }
+    }
+
+    #[test]
+    fn test_per_working_day_uses_user_leave() {
+        let calendar = WorkCalendar {
+            holidays: vec![Holiday {
+                date: date(2026, 5, 4),
+                name: "みどりの日".to_string(),
+                source: None,
+            }],
+            leaves: vec![UserLeave {
+                user_key: "alice@example.com".to_string(),
+                from: date(2026, 5, 1),
+                until: date(2026, 5, 31),
+            }],
+            ..Default::default()
+        };
+        let mut response = MonthlyStatsResponse {
+            series: vec![
+                series("alice@example.com", 100),
+                series("bob@example.com", 200),
+            ],
+            ..MonthlyStatsResponse::empty()
+        };
+
+        apply_working_days(&mut response, &calendar, 2026, GroupBy::User);
+
+        let working_days = response.working_days.as_ref().unwrap();
+        assert_eq!(working_days[4], 20);
+        // Alice は 5月いっぱい休暇
+        let alice = response.series[0].per_working_day.as_ref().unwrap();
+        assert_eq!(alice[4], None);
+        assert_eq!(alice[5], Some(100.0 / 22.0));
+        let bob = response.series[1].per_working_day.as_ref().unwrap();
+        assert_eq!(bob[4], Some(10.0));
+
+        // チーム集計では休暇を反映しない
+        apply_working_days(&mut response, &calendar, 2026, GroupBy::Team);
+        let alice = response.series[0].per_working_day.as_ref().unwrap();
+        assert_eq!(alice[4], Some(5.0));
+    }
+}
@@ -0,0 +1,231 @@
+//! アラートリポジトリ（SQLite ベース）
+//!
+//! アラートルールを app_settings に保存し、発火したアラートを alerts テーブルに記録する。
+
+use crate::error::{AppError, AppResult};
+use crate::stats::{validate_alert_rules, AlertOccurrence, AlertRule};
+use crate::storage::model::Alert;
+use crate::storage::settings_repository::{
+    get_setting_with_connection, set_setting_with_connection,
+};
+use crate::storage::sqlite;
+use rusqlite::params;
+
+/// app_settings のキー
+const ALERT_RULES_KEY: &str = "alert_rules";
+
+/// アラートルールを取得（未設定なら空）
+pub fn get_alert_rules() -> AppResult<Vec<AlertRule>> {
+    let conn = sqlite::DatabaseConnection::create_connection()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    get_alert_rules_with_connection(&conn)
+}
+
+/// アラートルールを検証して保存
+pub fn save_alert_rules(rules: &[AlertRule]) -> AppResult<()> {
+    let conn = sqlite::DatabaseConnection::create_connection()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    save_alert_rules_with_connection(&conn, rules)
+}
+
+/// 発火したアラートを記録し、新たに記録したものを返す（記録済みの fingerprint は無視）
+pub fn record_alerts(
+    run_id: Option<&str>,
+    occurrences: &[AlertOccurrence],
+) -> AppResult<Vec<Alert>> {
+    let mut conn = sqlite::DatabaseConnection::create_connection()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    record_alerts_with_connection(&mut conn, run_id, occurrences)
+}
+
+/// アラートを新しい順に取得
+pub fn list_alerts(unacknowledged_only: bool, limit: Option<usize>) -> AppResult<Vec<Alert>> {
+    let conn = sqlite::DatabaseConnection::create_connection()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    list_alerts_with_connection(&conn, unacknowledged_only, limit)
+}
+
+/// 未確認のアラート件数
+pub fn count_unacknowledged_alerts() -> AppResult<i64> {
+    let conn = sqlite::DatabaseConnection::create_connection()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    count_unacknowledged_alerts_with_connection(&conn)
+}
+
+/// アラートを確認済みにする（ID 省略時は未確認のすべて）。更新件数を返す
+pub fn acknowledge_alerts(ids: Option<&[i64]>) -> AppResult<usize> {
+    let mut conn = sqlite::DatabaseConnection::create_connection()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    acknowledge_alerts_with_connection(&mut conn, ids)
+}
+
+pub(crate) fn get_alert_rules_with_connection(
+    conn: &rusqlite::Connection,
+) -> AppResult<Vec<AlertRule>> {
+    Ok(get_setting_with_connection(conn, ALERT_RULES_KEY)?.unwrap_or_default())
+}
+
+pub(crate) fn save_alert_rules_with_connection(
+    conn: &rusqlite::Connection,
+    rules: &[AlertRule],
+) -> AppResult<()> {
+    validate_alert_rules(rules)?;
+    let rules: Vec<AlertRule> = rules
+        .iter()
+        .cloned()
+        .map(|rule| AlertRule {
+            name: rule.name.trim().to_string(),
+            ..rule
+        })
+        .collect();
+    set_setting_with_connection(conn, ALERT_RULES_KEY, &rules)
+}
+
+pub(crate) fn record_alerts_with_connection(
+    conn: &mut rusqlite::Connection,
+    run_id: Option<&str>,
+    occurrences: &[AlertOccurrence],
+) -> AppResult<Vec<Alert>> {
+    let now = chrono::Utc::now().to_rfc3339();
+    let tx = conn
+        .transaction()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    let mut inserted_ids = Vec::new();
+    for occurrence in occurrences {
+        let changed = tx
+            .execute(
+                "INSERT OR IGNORE INTO alerts (
+                    rule_name, kind, fingerprint, subject_key, subject_name,
+                    period, value, message, run_id, triggered_at_utc
+                 ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+                params![
+                    occurrence.rule_name,
+                    occurrence.kind,
+                    occurrence.fingerprint,
+                    occurrence.subject_key,
+                    occurrence.subject_name,
+                    occurrence.period,
+                    occurrence.value,
+                    occurrence.message,
+                    run_id,
+                    now,
+                ],
+            )
+            .map_err(|e| AppError::Storage(e.to_string()))?;
+        if changed > 0 {
+            inserted_ids.push(tx.last_insert_rowid());
+        }
+    }
+
+    tx.commit().map_err(|e| AppError::Storage(e.to_string()))?;
+
+    let mut alerts = Vec::with_capacity(inserted_ids.len());
+    for id in inserted_ids {
+        alerts.push(find_alert_with_connection(conn, id)?);
+    }
+    Ok(alerts)
+}
+
+pub(crate) fn list_alerts_with_connection(
+    conn: &rusqlite::Connection,
+    unacknowledged_only: bool,
+    limit: Option<usize>,
+) -> AppResult<Vec<Alert>> {
+    let limit = limit.map(|l| l as i64).unwrap_or(-1);
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, rule_name, kind, subject_key, subject_name, period, value, message,
+                    run_id, triggered_at_utc, acknowledged_at_utc
+             FROM alerts
+             WHERE ?1 = 0 OR acknowledged_at_utc IS NULL
+             ORDER BY triggered_at_utc DESC, id DESC
+             LIMIT ?2",
+        )
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    let alerts = stmt
+        .query_map(params![unacknowledged_only, limit], map_alert)
+        .map_err(|e| AppError::Storage(e.to_string()))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    Ok(alerts)
+}
+
+pub(crate) fn count_unacknowledged_alerts_with_connection(
+    conn: &rusqlite::Connection,
+) -> AppResult<i64> {
+    conn.query_row(
+        "SELECT COUNT(*) FROM alerts WHERE acknowledged_at_utc IS NULL",
+        [],
+        |row| row.get(0),
+    )
+    .map_err(|e| AppError::Storage(e.to_string()))
+}
+
+pub(crate) fn acknowledge_alerts_with_connection(
+    conn: &mut rusqlite::Connection,
+    ids: Option<&[i64]>,
+) -> AppResult<usize> {
+    let now = chrono::Utc::now().to_rfc3339();
+
+    let Some(ids) = ids else {
+        return conn
+            .execute(
+                "UPDATE alerts SET acknowledged_at_utc = ?1 WHERE acknowledged_at_utc IS NULL",
+                [&now],
+            )
+            .map_err(|e| AppError::Storage(e.to_string()));
+    };
+
+    let tx = conn
+        .transaction()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+    let mut updated = 0;
+    for id in ids {
+        updated += tx
+            .execute(
+                "UPDATE alerts SET acknowledged_at_utc = ?1
+                 WHERE id = ?2 AND acknowledged_at_utc IS NULL",
+                params![now, id],
+            )
+            .map_err(|e| AppError::Storage(e.to_string()))?;
+    }
+    tx.commit().map_err(|e| AppError::Storage(e.to_string()))?;
+
+    Ok(updated)
+}
+
+fn find_alert_with_connection(conn: &rusqlite::Connection, id: i64) -> AppResult<Alert> {
+    conn.query_row(
+        "SELECT id, rule_name, kind, subject_key, subject_name, period, value, message,
+                run_id, triggered_at_utc, acknowledged_at_utc
+         FROM alerts WHERE id = ?1",
+        [id],
+        map_alert,
+    )
+    .map_err(|e| AppError::Storage(e.to_string()))
+}
+
+fn map_alert(row: &rusqlite::Row<'_>) -> rusqlite::Result<Alert> {
+    Ok(Alert {
+        id: row.get(0)?,
+        rule_name: row.get(1)?,
+        kind: row.get(2)?,
+        subject_key: row.get(3)?,
+        subject_name: row.get(4)?,
+        period: row.get(5)?,
+        value: row.get(6)?,
+        message: row.get(7)?,
+        run_id: row.get(8)?,
+        triggered_at: row.get(9)?,
+        acknowledged_at: row.get(10)?,
+    })
+}
@@ -0,0 +1,129 @@
+//! アラートリポジトリのテスト
+
+#[cfg(test)]
+mod tests {
+    use crate::stats::{AlertCondition, AlertOccurrence, AlertRule};
+    use crate::storage::alert_repository::{
+        acknowledge_alerts_with_connection, count_unacknowledged_alerts_with_connection,
+        get_alert_rules_with_connection, list_alerts_with_connection,
+        record_alerts_with_connection, save_alert_rules_with_connection,
+    };
+    use crate::storage::sqlite::run_migrations;
+    use rusqlite::Connection;
+
+    fn create_test_connection() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        run_migrations(&conn).unwrap();
+        conn
+    }
+
+    fn occurrence(fingerprint: &str) -> AlertOccurrence {
+        AlertOccurrence {
+            rule_name: "huge commit".to_string(),
+            kind: "largeCommit".to_string(),
+            fingerprint: fingerprint.to_string(),
+            subject_key: fingerprint.to_string(),
+            subject_name: "Alice".to_string(),
+            period: "2026-03".to_string(),
+            value: 25_000.0,
+            message: "huge commit".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_rules_roundtrip() {
+        let conn = create_test_connection();
+        assert!(get_alert_rules_with_connection(&conn).unwrap().is_empty());
+
+        let rule = AlertRule {
+            name: " huge commit ".to_string(),
+            enabled: true,
+            include_bots: false,
+            condition: AlertCondition::LargeCommit { min_lines: 20_000 },
+        };
+        save_alert_rules_with_connection(&conn, &[rule]).unwrap();
+
+        let rules = get_alert_rules_with_connection(&conn).unwrap();
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].name, "huge commit");
+
+        let invalid = AlertRule {
+            name: "zero".to_string(),
+            enabled: true,
+            include_bots: false,
+            condition: AlertCondition::LargeCommit { min_lines: 0 },
+        };
+        assert!(save_alert_rules_with_connection(&conn, &[invalid]).is_err());
+    }
+
+    #[test]
+    fn test_record_alerts_deduplicates_by_fingerprint() {
+        let mut conn = create_test_connection();
+
+        let first = record_alerts_with_connection(
+            &mut conn,
+            Some("run-1"),
+            &[occurrence("1:aaa"), occurrence("1:bbb")],
+        )
+        .unwrap();
+        assert_eq!(first.len(), 2);
+        assert_eq!(first[0].run_id.as_deref(), Some("run-1"));
+
+        let second = record_alerts_with_connection(
+            &mut conn,
+            Some("run-2"),
+            &[occurrence("1:bbb"), occurrence("1:ccc")],
+        )
+        .unwrap();
+        assert_eq!(second.len(), 1);
+        assert_eq!(second[0].subject_key, "1:ccc");
+
+        assert_eq!(
+            list_alerts_with_connection(&conn, false, None)
+                .unwrap()
+                .len(),
+            3
+        );
+        assert_eq!(
+            list_alerts_with_connection(&conn, false, Some(2))
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_acknowledge_alerts() {
+        let mut conn = create_test_connection();
+        let alerts = record_alerts_with_connection(
+            &mut conn,
+            None,
+            &[
+                occurrence("1:aaa"),
+                occurrence("1:bbb"),
+                occurrence("1:ccc"),
+            ],
+        )
+        .unwrap();
+
+        let updated = acknowledge_alerts_with_connection(&mut conn, Some(&[alerts[0].id])).unwrap();
+        assert_eq!(updated, 1);
+        assert_eq!(
+            count_unacknowledged_alerts_with_connection(&conn).unwrap(),
+            2
+        );
+
+        let unacknowledged = list_alerts_with_connection(&conn, true, None).unwrap();
+        assert_eq!(unacknowledged.len(), 2);
+        assert!(unacknowledged.iter().all(|a| a.acknowledged_at.is_none()));
+
+        assert_eq!(
+            acknowledge_alerts_with_connection(&mut conn, None).unwrap(),
+            2
+        );
+        assert_eq!(
+            count_unacknowledged_alerts_with_connection(&conn).unwrap(),
+            0
+        );
+    }
+}
@@ -0,0 +1,363 @@
+//! データアーカイブ（SQLite ベース）
+//!
+//! 収集済みのプロジェクト・コミット、チームやボット判定などの識別情報、フィルタと設定、
+//! 一括収集の履歴をテーブル単位で書き出し、別の端末のデータベースに取り込む。
+//! 接続設定（アクセストークン）と発火済みアラートは含めない。
+
+use crate::error::{AppError, AppResult};
+use crate::storage::bulk_collection_repository::has_running_run_with_connection;
+use crate::storage::model::{
+    ArchiveImportMode, ArchiveTable, ArchiveTableSummary, DataArchive, ARCHIVE_FORMAT,
+};
+use crate::storage::schema::{SchemaVersion, CURRENT_SCHEMA_VERSION};
+use crate::storage::sqlite;
+use rusqlite::types::{Type, Value, ValueRef};
+use rusqlite::{params_from_iter, Connection, OptionalExtension};
+use std::collections::{HashMap, HashSet};
+
+/// アーカイブに含めるテーブル（取り込み順。置き換え時の削除は逆順）
+const ARCHIVE_TABLES: &[&str] = &[
+    "projects",
+    "project_tags",
+    "commits",
+    "teams",
+    "team_members",
+    "gitlab_bot_users",
+    "user_filters",
+    "category_rules",
+    "app_settings",
+    "bulk_collection_runs",
+    "bulk_collection_results",
+];
+
+/// データベース全体をアーカイブに書き出す
+pub fn export_archive() -> AppResult<DataArchive> {
+    let conn = sqlite::DatabaseConnection::create_connection()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    export_archive_with_connection(&conn)
+}
+
+/// アーカイブを取り込み、テーブルごとの件数を返す
+pub fn import_archive(
+    archive: &DataArchive,
+    mode: ArchiveImportMode,
+) -> AppResult<Vec<ArchiveTableSummary>> {
+    let mut conn = sqlite::DatabaseConnection::create_connection()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    import_archive_with_connection(&mut conn, archive, mode)
+}
+
+/// アーカイブの形式とスキーマバージョンを検証
+pub fn validate_archive(archive: &DataArchive) -> AppResult<()> {
+    if archive.format != ARCHIVE_FORMAT {
+        return Err(AppError::Validation(format!(
+            "アーカイブの形式が不正です: {}",
+            archive.format
+        )));
+    }
+    if !archive.schema.is_compatible() {
+        return Err(AppError::Validation(format!(
+            "アーカイブのスキーマバージョン（{}）が現在のバージョン（{}）と一致しません。同じバージョンのアプリで書き出したアーカイブを使用してください",
+            archive.schema.version, CURRENT_SCHEMA_VERSION
+        )));
+    }
+    Ok(())
+}
+
+pub(crate) fn export_archive_with_connection(conn: &Connection) -> AppResult<DataArchive> {
+    let version =
+        sqlite::current_schema_version(conn).map_err(|e| AppError::Storage(e.to_string()))?;
+    let tables = ARCHIVE_TABLES
+        .iter()
+        .map(|name| export_table(conn, name))
+        .collect::<AppResult<Vec<_>>>()?;
+
+    Ok(DataArchive {
+        format: ARCHIVE_FORMAT.to_string(),
+        schema: SchemaVersion { version },
+        exported_at_utc: chrono::Utc::now().to_rfc3339(),
+        tables,
+    })
+}
+
+pub(crate) fn import_archive_with_connection(
+    conn: &mut Connection,
+    archive: &DataArchive,
+    mode: ArchiveImportMode,
+) -> AppResult<Vec<ArchiveTableSummary>> {
+    validate_archive(archive)?;
+    if has_running_run_with_connection(conn)? {
+        return Err(AppError::Validation(
+            "一括収集の実行中はアーカイブを取り込めません。完了または中断してから再実行してください"
+                .to_string(),
+        ));
+    }
+    if let Some(table) = archive
+        .tables
+        .iter()
+        .find(|table| !ARCHIVE_TABLES.contains(&table.name.as_str()))
+    {
+        return Err(AppError::Validation(format!(
+            "アーカイブに取り込めないテーブルが含まれています: {}",
+            table.name
+        )));
+    }
+
+    // 取り込み順に並べ、列がテーブルに存在するか検証する
+    let tables: Vec<&ArchiveTable> = ARCHIVE_TABLES
+        .iter()
+        .filter_map(|name| archive.tables.iter().find(|table| table.name == *name))
+        .collect();
+    for table in &tables {
+        validate_columns(conn, table)?;
+    }
+
+    let tx = conn
+        .transaction()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    if mode == ArchiveImportMode::Replace {
+        for table in tables.iter().rev() {
+            tx.execute(&format!("DELETE FROM {}", table.name), [])
+                .map_err(|e| AppError::Storage(e.to_string()))?;
+        }
+    }
+
+    // マージ時はチームを名前で突き合わせる（アーカイブのチーム ID → 取り込み先のチーム ID）
+    let mut team_ids: HashMap<i64, Option<i64>> = HashMap::new();
+    let mut summaries = Vec::new();
+    for table in tables {
+        let imported_count = match (table.name.as_str(), mode) {
+            ("teams", ArchiveImportMode::Merge) => merge_teams(&tx, table, &mut team_ids)?,
+            ("team_members", ArchiveImportMode::Merge) => {
+                merge_team_members(&tx, table, &team_ids)?
+            }
+            // 分類ルールは順序付きの一覧のため、既存のルールがあれば混ぜない
+            ("category_rules", ArchiveImportMode::Merge) if has_rows(&tx, "category_rules")? => 0,
+            _ => insert_rows(&tx, table, table.rows.iter())?,
+        };
+        summaries.push(ArchiveTableSummary {
+            name: table.name.clone(),
+            row_count: table.rows.len(),
+            imported_count,
+        });
+    }
+
+    // 書き出し元で実行中だった一括収集は中断扱いにする（取り込み前に実行中がないことは確認済み）
+    tx.execute(
+        "UPDATE bulk_collection_runs SET status = 'cancelled' WHERE status = 'running'",
+        [],
+    )
+    .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    tx.commit().map_err(|e| AppError::Storage(e.to_string()))?;
+
+    Ok(summaries)
+}
+
+fn export_table(conn: &Connection, name: &str) -> AppResult<ArchiveTable> {
+    let mut stmt = conn
+        .prepare(&format!("SELECT * FROM {} ORDER BY rowid", name))
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+    let columns: Vec<String> = stmt.column_names().into_iter().map(String::from).collect();
+
+    let rows = stmt
+        .query_map([], |row| {
+            (0..columns.len())
+                .map(|index| to_json(index, row.get_ref(index)?))
+                .collect::<rusqlite::Result<Vec<_>>>()
+        })
+        .map_err(|e| AppError::Storage(e.to_string()))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    Ok(ArchiveTable {
+        name: name.to_string(),
+        columns,
+        rows,
+    })
+}
+
+fn validate_columns(conn: &Connection, table: &ArchiveTable) -> AppResult<()> {
+    let mut stmt = conn
+        .prepare("SELECT name FROM pragma_table_info(?1)")
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+    let existing: HashSet<String> = stmt
+        .query_map([&table.name], |row| row.get(0))
+        .map_err(|e| AppError::Storage(e.to_string()))?
+        .collect::<Result<_, _>>()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    if let Some(column) = table.columns.iter().find(|c| !existing.contains(*c)) {
+        return Err(AppError::Validation(format!(
+            "アーカイブの列がデータベースに存在しません: {}.{}",
+            table.name, column
+        )));
+    }
+    if let Some(row) = table.rows.iter().find(|r| r.len() != table.columns.len()) {
+        return Err(AppError::Validation(format!(
+            "アーカイブの行の列数が一致しません: {}（{} 列、期待値 {} 列）",
+            table.name,
+            row.len(),
+            table.columns.len()
+        )));
+    }
+    Ok(())
+}
+
+/// 行を挿入し、挿入した件数を返す（主キー/一意制約が重複する行は既存を優先して無視）
+fn insert_rows<'a>(
+    conn: &Connection,
+    table: &ArchiveTable,
+    rows: impl Iterator<Item = &'a Vec<serde_json::Value>>,
+) -> AppResult<usize> {
+    let columns = table
+        .columns
+        .iter()
+        .map(|c| format!("\"{}\"", c))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let placeholders = vec!["?"; table.columns.len()].join(", ");
+    let mut stmt = conn
+        .prepare(&format!(
+            "INSERT INTO {} ({}) VALUES ({}) ON CONFLICT DO NOTHING",
+            table.name, columns, placeholders
+        ))
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    let mut inserted = 0;
+    for row in rows {
+        let values = row.iter().map(from_json).collect::<AppResult<Vec<_>>>()?;
+        inserted += stmt.execute(params_from_iter(values)).map_err(|e| {
+            AppError::Storage(format!("{} の取り込みに失敗しました: {}", table.name, e))
+        })?;
+    }
+    Ok(inserted)
+}
+
+/// チームを名前で突き合わせて追加する（同名のチームがあれば既存を優先し、メンバーも取り込まない）
+fn merge_teams(
+    conn: &Connection,
+    table: &ArchiveTable,
+    team_ids: &mut HashMap<i64, Option<i64>>,
+) -> AppResult<usize> {
+    let id_index = column_index(table, "team_id")?;
+    let name_index = column_index(table, "name")?;
+    let new_team = ArchiveTable {
+        name: table.name.clone(),
+        columns: without_index(&table.columns, id_index),
+        rows: Vec::new(),
+    };
+
+    let mut inserted = 0;
+    for row in &table.rows {
+        let archive_id = row[id_index]
+            .as_i64()
+            .ok_or_else(|| AppError::Validation("アーカイブのチーム ID が不正です".to_string()))?;
+        let existing: Option<i64> = conn
+            .query_row(
+                "SELECT team_id FROM teams WHERE name = ?1",
+                [row[name_index].as_str().unwrap_or_default()],
+                |r| r.get(0),
+            )
+            .optional()
+            .map_err(|e| AppError::Storage(e.to_string()))?;
+        if existing.is_some() {
+            team_ids.insert(archive_id, None);
+            continue;
+        }
+
+        let values = without_index(row, id_index);
+        inserted += insert_rows(conn, &new_team, std::iter::once(&values))?;
+        team_ids.insert(archive_id, Some(conn.last_insert_rowid()));
+    }
+    Ok(inserted)
+}
+
+/// 新たに追加したチームのメンバーだけを、取り込み先のチーム ID に付け替えて追加する
+fn merge_team_members(
+    conn: &Connection,
+    table: &ArchiveTable,
+    team_ids: &HashMap<i64, Option<i64>>,
+) -> AppResult<usize> {
+    let id_index = column_index(table, "team_id")?;
+    let rows: Vec<Vec<serde_json::Value>> = table
+        .rows
+        .iter()
+        .filter_map(|row| {
+            let team_id = (*team_ids.get(&row[id_index].as_i64()?)?)?;
+            let mut row = row.clone();
+            row[id_index] = team_id.into();
+            Some(row)
+        })
+        .collect();
+    insert_rows(conn, table, rows.iter())
+}
+
+fn has_rows(conn: &Connection, table: &str) -> AppResult<bool> {
+    conn.query_row(
+        &format!("SELECT EXISTS(SELECT 1 FROM {})", table),
+        [],
+        |row| row.get(0),
+    )
+    .map_err(|e| AppError::Storage(e.to_string()))
+}
+
+fn column_index(table: &ArchiveTable, column: &str) -> AppResult<usize> {
+    table
+        .columns
+        .iter()
+        .position(|c| c == column)
+        .ok_or_else(|| {
+            AppError::Validation(format!(
+                "アーカイブの {} に {} 列がありません",
+                table.name, column
+            ))
+        })
+}
+
+fn without_index<T: Clone>(values: &[T], index: usize) -> Vec<T> {
+    values
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| *i != index)
+        .map(|(_, value)| value.clone())
+        .collect()
+}
+
+fn to_json(index: usize, value: ValueRef<'_>) -> rusqlite::Result<serde_json::Value> {
+    Ok(match value {
+        ValueRef::Null => serde_json::Value::Null,
+        ValueRef::Integer(i) => i.into(),
+        ValueRef::Real(f) => serde_json::Number::from_f64(f)
+            .map(serde_json::Value::Number)
+            .unwrap_or(serde_json::Value::Null),
+        ValueRef::Text(text) => String::from_utf8_lossy(text).into_owned().into(),
+        ValueRef::Blob(_) => {
+            return Err(rusqlite::Error::InvalidColumnType(
+                index,
+                "blob".to_string(),
+                Type::Blob,
+            ))
+        }
+    })
+}
+
+fn from_json(value: &serde_json::Value) -> AppResult<Value> {
+    match value {
+        serde_json::Value::Null => Ok(Value::Null),
+        serde_json::Value::Bool(b) => Ok(Value::Integer(i64::from(*b))),
+        serde_json::Value::Number(n) => n
+            .as_i64()
+            .map(Value::Integer)
+            .or_else(|| n.as_f64().map(Value::Real))
+            .ok_or_else(|| AppError::Validation(format!("アーカイブの数値が不正です: {}", n))),
+        serde_json::Value::String(s) => Ok(Value::Text(s.clone())),
+        other => Err(AppError::Validation(format!(
+            "アーカイブの値が不正です: {}",
+            other
+        ))),
+    }
+}
@@ -0,0 +1,226 @@
+//! データアーカイブのテスト
+
+#[cfg(test)]
+mod tests {
+    use crate::error::AppError;
+    use crate::storage::archive_repository::{
+        export_archive_with_connection, import_archive_with_connection,
+    };
+    use crate::storage::model::{ArchiveImportMode, DataArchive};
+    use crate::storage::sqlite::run_migrations;
+    use crate::storage::CURRENT_SCHEMA_VERSION;
+    use rusqlite::Connection;
+
+    fn create_test_connection() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        run_migrations(&conn).unwrap();
+        conn
+    }
+
+    /// 移行元のデータ（接続設定・プロジェクト・コミット・チーム・設定・一括収集履歴）
+    fn seed_source(conn: &Connection) {
+        conn.execute_batch(
+            "INSERT INTO connections (id, base_url, author_email, access_token, updated_at_utc)
+             VALUES (1, 'https://gitlab.example.com', NULL, 'glpat-secret-token', '2026-01-01T00:00:00Z');
+             INSERT INTO projects (project_id, name, path_with_namespace, web_url)
+             VALUES (1, 'web', 'group/web', 'https://gitlab.example.com/group/web');
+             INSERT INTO commits (project_id, branch_name, sha, author_name, author_email, committed_date_utc, additions, deletions, message)
+             VALUES (1, 'main', 'aaa', 'Alice', 'alice@example.com', '2026-01-05T00:00:00+00:00', 10, 2, 'feat: search index');
+             INSERT INTO teams (team_id, name, created_at_utc, updated_at_utc)
+             VALUES (7, 'Platform', '2026-01-01T00:00:00Z', '2026-01-01T00:00:00Z'),
+                    (8, 'Apps', '2026-01-01T00:00:00Z', '2026-01-01T00:00:00Z');
+             INSERT INTO team_members (team_id, position, user_key, valid_from, valid_until)
+             VALUES (7, 0, 'alice@example.com', NULL, NULL),
+                    (8, 0, 'bob@example.com', NULL, NULL);
+             INSERT INTO app_settings (key, value_json, updated_at_utc)
+             VALUES ('reporting_settings', '{\"timeZone\":\"Asia/Tokyo\"}', '2026-01-01T00:00:00Z');
+             INSERT INTO bulk_collection_runs (run_id, started_at_utc, status, total_targets)
+             VALUES ('run-1', '2026-01-06T00:00:00Z', 'running', 1);",
+        )
+        .unwrap();
+    }
+
+    fn count(conn: &Connection, sql: &str) -> i64 {
+        conn.query_row(sql, [], |row| row.get(0)).unwrap()
+    }
+
+    #[test]
+    fn test_export_excludes_access_token() {
+        let conn = create_test_connection();
+        seed_source(&conn);
+
+        let archive = export_archive_with_connection(&conn).unwrap();
+        assert_eq!(archive.schema.version, CURRENT_SCHEMA_VERSION);
+        assert!(archive.tables.iter().all(|t| t.name != "connections"));
+
+        let json = serde_json::to_string(&archive).unwrap();
+        assert!(!json.contains("glpat-secret-token"));
+        assert!(json.contains("feat: search index"));
+    }
+
+    #[test]
+    fn test_import_replace_round_trip() {
+        let source = create_test_connection();
+        seed_source(&source);
+        let json =
+            serde_json::to_string(&export_archive_with_connection(&source).unwrap()).unwrap();
+        let archive: DataArchive = serde_json::from_str(&json).unwrap();
+
+        let mut target = create_test_connection();
+        target
+            .execute_batch(
+                "INSERT INTO projects (project_id, name, path_with_namespace, web_url)
+                 VALUES (2, 'old', 'group/old', 'https://gitlab.example.com/group/old');
+                 INSERT INTO commits (project_id, branch_name, sha, author_name, author_email, committed_date_utc, additions, deletions)
+                 VALUES (2, 'main', 'zzz', 'Zed', 'zed@example.com', '2025-01-01T00:00:00+00:00', 1, 0);",
+            )
+            .unwrap();
+
+        let summaries =
+            import_archive_with_connection(&mut target, &archive, ArchiveImportMode::Replace)
+                .unwrap();
+        let commits = summaries.iter().find(|s| s.name == "commits").unwrap();
+        assert_eq!((commits.row_count, commits.imported_count), (1, 1));
+
+        // 既存データは置き換えられ、アーカイブの内容だけが残る
+        assert_eq!(count(&target, "SELECT COUNT(*) FROM projects"), 1);
+        assert_eq!(
+            count(&target, "SELECT COUNT(*) FROM commits WHERE sha = 'aaa'"),
+            1
+        );
+        assert_eq!(
+            count(&target, "SELECT COUNT(*) FROM commits WHERE sha = 'zzz'"),
+            0
+        );
+        assert_eq!(
+            count(
+                &target,
+                "SELECT COUNT(*) FROM team_members WHERE team_id = 7"
+            ),
+            1
+        );
+        assert_eq!(count(&target, "SELECT COUNT(*) FROM connections"), 0);
+
+        // 全文検索のインデックスも取り込んだコミットで更新される
+        assert_eq!(
+            count(
+                &target,
+                "SELECT COUNT(*) FROM commits_fts WHERE commits_fts MATCH 'search'"
+            ),
+            1
+        );
+
+        // 書き出し元で実行中だった一括収集は中断扱い
+        let status: String = target
+            .query_row(
+                "SELECT status FROM bulk_collection_runs WHERE run_id = 'run-1'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(status, "cancelled");
+    }
+
+    #[test]
+    fn test_import_merge_keeps_existing_rows() {
+        let source = create_test_connection();
+        seed_source(&source);
+        let archive = export_archive_with_connection(&source).unwrap();
+
+        // 取り込み先には同名のチーム（別 ID）と、ID が衝突する別名のチームがある
+        let mut target = create_test_connection();
+        target
+            .execute_batch(
+                "INSERT INTO teams (team_id, name, created_at_utc, updated_at_utc)
+                 VALUES (1, 'Platform', '2026-02-01T00:00:00Z', '2026-02-01T00:00:00Z'),
+                        (8, 'Data', '2026-02-01T00:00:00Z', '2026-02-01T00:00:00Z');
+                 INSERT INTO team_members (team_id, position, user_key, valid_from, valid_until)
+                 VALUES (1, 0, 'carol@example.com', NULL, NULL);
+                 INSERT INTO app_settings (key, value_json, updated_at_utc)
+                 VALUES ('reporting_settings', '{\"timeZone\":\"UTC\"}', '2026-02-01T00:00:00Z');",
+            )
+            .unwrap();
+
+        import_archive_with_connection(&mut target, &archive, ArchiveImportMode::Merge).unwrap();
+
+        // 同名のチームは既存を優先し、メンバーも混ぜない
+        assert_eq!(
+            count(
+                &target,
+                "SELECT COUNT(*) FROM teams WHERE name = 'Platform'"
+            ),
+            1
+        );
+        assert_eq!(
+            count(
+                &target,
+                "SELECT COUNT(*) FROM team_members WHERE team_id = 1"
+            ),
+            1
+        );
+
+        // 新しいチームは別 ID で追加され、メンバーも付け替えられる
+        let apps_members = count(
+            &target,
+            "SELECT COUNT(*) FROM team_members m JOIN teams t ON t.team_id = m.team_id
+             WHERE t.name = 'Apps' AND m.user_key = 'bob@example.com'",
+        );
+        assert_eq!(apps_members, 1);
+        assert_eq!(
+            count(
+                &target,
+                "SELECT COUNT(*) FROM team_members WHERE team_id = 8"
+            ),
+            0
+        );
+
+        // 設定は既存を優先し、コミットは追加される
+        let settings: String = target
+            .query_row(
+                "SELECT value_json FROM app_settings WHERE key = 'reporting_settings'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(settings, "{\"timeZone\":\"UTC\"}");
+        assert_eq!(count(&target, "SELECT COUNT(*) FROM commits"), 1);
+
+        // 再度取り込んでも重複しない
+        let summaries =
+            import_archive_with_connection(&mut target, &archive, ArchiveImportMode::Merge)
+                .unwrap();
+        assert!(summaries.iter().all(|s| s.imported_count == 0));
+    }
+
+    #[test]
+    fn test_import_rejects_incompatible_archive() {
+        let source = create_test_connection();
+        let mut archive = export_archive_with_connection(&source).unwrap();
+        archive.schema.version = CURRENT_SCHEMA_VERSION - 1;
+
+        let mut target = create_test_connection();
+        let result =
+            import_archive_with_connection(&mut target, &archive, ArchiveImportMode::Merge);
+        assert!(matches!(result, Err(AppError::Validation(_))));
+
+        // 取り込めないテーブル（接続設定など）を含むアーカイブも拒否する
+        let mut archive = export_archive_with_connection(&source).unwrap();
+        archive.tables[0].name = "connections".to_string();
+        let result =
+            import_archive_with_connection(&mut target, &archive, ArchiveImportMode::Merge);
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[test]
+    fn test_import_rejected_while_bulk_collection_running() {
+        let source = create_test_connection();
+        let archive = export_archive_with_connection(&source).unwrap();
+
+        let mut target = create_test_connection();
+        seed_source(&target);
+        let result =
+            import_archive_with_connection(&mut target, &archive, ArchiveImportMode::Replace);
+        assert!(matches!(result, Err(AppError::Validation(_))));
+        assert_eq!(count(&target, "SELECT COUNT(*) FROM commits"), 1);
+    }
+}
@@ -0,0 +1,310 @@
+//! データベースのバックアップと復元（SQLite オンラインバックアップ API）
+//!
+//! アプリ使用中でもデータベースファイル（`gitlab-analyzer.db`）を一貫した状態で複製する。
+//! マイグレーションやブランチ削除などの破壊的な操作の前には自動でスナップショットを作成し、
+//! 契機ごとの保持数を超えた古いスナップショットから削除する（ブランチ削除を繰り返しても
+//! マイグレーション前のスナップショットが押し出されないようにする）。
+
+use crate::error::{AppError, AppResult};
+use crate::paths;
+use crate::storage::bulk_collection_repository::has_running_run_with_connection;
+use crate::storage::migration_repository::migrate_with_connection;
+use crate::storage::model::{DatabaseBackup, SnapshotReason};
+use crate::storage::schema::CURRENT_SCHEMA_VERSION;
+use crate::storage::sqlite;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use rusqlite::backup::{Backup, Progress};
+use rusqlite::{Connection, DatabaseName, OpenFlags};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tracing::info;
+
+/// 自動スナップショットの契機ごとの保持数（超えた分は古いものから削除）
+pub const MAX_SNAPSHOTS_PER_REASON: usize = 5;
+
+/// 自動スナップショットのファイル名の接頭辞
+const SNAPSHOT_PREFIX: &str = "snapshot-";
+/// 手動バックアップのファイル名の接頭辞
+const BACKUP_PREFIX: &str = "backup-";
+/// ファイル名に埋め込む作成日時の形式
+const TIMESTAMP_FORMAT: &str = "%Y%m%dT%H%M%S%3fZ";
+
+/// データベースをバックアップする（出力先を省略した場合はバックアップ保存先に作成）
+pub fn backup_database(output_path: Option<&Path>) -> AppResult<DatabaseBackup> {
+    let conn = sqlite::DatabaseConnection::create_connection()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    let path = match output_path {
+        Some(path) => path.to_path_buf(),
+        None => paths::ensure_backup_dir()?.join(backup_file_name(BACKUP_PREFIX, None)),
+    };
+    backup_with_connection(&conn, &path)?;
+
+    describe_backup(&path)
+}
+
+/// 自動スナップショットを作成し、同じ契機で保持数を超えた古いスナップショットを削除する
+pub fn create_snapshot(reason: SnapshotReason) -> AppResult<DatabaseBackup> {
+    let conn = sqlite::DatabaseConnection::create_connection()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    create_snapshot_with_connection(&conn, &paths::ensure_backup_dir()?, reason)
+}
+
+/// バックアップ保存先のバックアップ・スナップショットを新しい順に返す
+pub fn list_backups() -> AppResult<Vec<DatabaseBackup>> {
+    list_backups_in_dir(&paths::ensure_backup_dir()?)
+}
+
+/// バックアップからデータベースを復元し、復元前に作成したスナップショットを返す
+pub fn restore_database(source: &Path) -> AppResult<DatabaseBackup> {
+    let mut conn = sqlite::DatabaseConnection::create_connection()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    restore_with_connection(&mut conn, &paths::ensure_backup_dir()?, source)
+}
+
+/// バックアップファイルの情報を取得（スキーマバージョンが読み取れない場合は None）
+pub fn describe_backup(path: &Path) -> AppResult<DatabaseBackup> {
+    let metadata = std::fs::metadata(path).map_err(|e| {
+        AppError::Validation(format!(
+            "バックアップファイルを開けません（{}）: {}",
+            path.display(),
+            e
+        ))
+    })?;
+
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let (created_at, reason) = parse_backup_file_name(&file_name);
+    let created_at = created_at
+        .or_else(|| metadata.modified().ok().map(DateTime::<Utc>::from))
+        .unwrap_or(DateTime::<Utc>::UNIX_EPOCH);
+
+    Ok(DatabaseBackup {
+        path: path.to_string_lossy().into_owned(),
+        reason,
+        created_at_utc: created_at.to_rfc3339(),
+        size_bytes: metadata.len(),
+        schema_version: read_schema_version(path).ok(),
+    })
+}
+
+/// バックアップファイルのスキーマバージョンを読み取る
+pub fn read_schema_version(path: &Path) -> AppResult<u32> {
+    let invalid = |e: rusqlite::Error| {
+        AppError::Validation(format!(
+            "このアプリのデータベースのバックアップとして読み取れません（{}）: {}",
+            path.display(),
+            e
+        ))
+    };
+
+    let conn =
+        Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY).map_err(invalid)?;
+    let version: Option<u32> = conn
+        .query_row("SELECT MAX(version) FROM schema_migrations", [], |row| {
+            row.get(0)
+        })
+        .map_err(invalid)?;
+
+    Ok(version.unwrap_or(0))
+}
+
+/// データベースを指定ファイルに複製する
+///
+/// 途中で失敗したファイルをバックアップと取り違えないよう、一時ファイルに書き出してから
+/// 置き換える。複製はジャーナルモードを DELETE に戻し、単一ファイルで持ち運べるようにする。
+pub(crate) fn backup_with_connection(conn: &Connection, path: &Path) -> AppResult<()> {
+    let temp_path = path.with_extension("tmp");
+    let result = write_backup(conn, &temp_path).and_then(|_| {
+        std::fs::rename(&temp_path, path).map_err(|e| AppError::Storage(e.to_string()))
+    });
+    if result.is_err() {
+        let _ = std::fs::remove_file(&temp_path);
+    }
+    result
+}
+
+pub(crate) fn create_snapshot_with_connection(
+    conn: &Connection,
+    backup_dir: &Path,
+    reason: SnapshotReason,
+) -> AppResult<DatabaseBackup> {
+    let snapshot = write_snapshot(conn, backup_dir, reason)?;
+    rotate_snapshots(backup_dir, MAX_SNAPSHOTS_PER_REASON)?;
+    Ok(snapshot)
+}
+
+pub(crate) fn list_backups_in_dir(backup_dir: &Path) -> AppResult<Vec<DatabaseBackup>> {
+    let mut backups = backup_files(backup_dir, &[SNAPSHOT_PREFIX, BACKUP_PREFIX])?
+        .iter()
+        .map(|path| describe_backup(path))
+        .collect::<AppResult<Vec<_>>>()?;
+    backups.sort_by(|a, b| {
+        b.created_at_utc
+            .cmp(&a.created_at_utc)
+            .then_with(|| b.path.cmp(&a.path))
+    });
+    Ok(backups)
+}
+
+pub(crate) fn restore_with_connection(
+    conn: &mut Connection,
+    backup_dir: &Path,
+    source: &Path,
+) -> AppResult<DatabaseBackup> {
+    // 復元元がこのアプリのデータベースであることを確認する
+    // （古いスキーマは復元後にマイグレーションするが、新しいアプリで作成したものは扱えない）
+    let version = read_schema_version(source)?;
+    if version > CURRENT_SCHEMA_VERSION {
+        return Err(AppError::Validation(format!(
+            "バックアップのスキーマバージョン（{}）が現在のバージョン（{}）より新しいため復元できません。新しいバージョンのアプリを使用してください",
+            version, CURRENT_SCHEMA_VERSION
+        )));
+    }
+    if has_running_run_with_connection(conn)? {
+        return Err(AppError::Validation(
+            "一括収集の実行中は復元できません。完了または中断してから再実行してください"
+                .to_string(),
+        ));
+    }
+
+    // 復元前の状態を残してから置き換える（復元元を消さないよう、ローテーションは復元後に行う）
+    let snapshot = write_snapshot(conn, backup_dir, SnapshotReason::PreRestore)?;
+
+    info!(
+        "データベース復元開始: source={}, schema_version={}",
+        source.display(),
+        version
+    );
+
+    conn.restore(DatabaseName::Main, source, None::<fn(Progress)>)
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    // マイグレーション前のスナップショットなど、古いスキーマのバックアップを現在のスキーマに揃える
+    migrate_with_connection(conn)?;
+
+    // バックアップ時点で実行中だった一括収集は中断扱いにする
+    conn.execute(
+        "UPDATE bulk_collection_runs SET status = 'cancelled' WHERE status = 'running'",
+        [],
+    )
+    .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    rotate_snapshots(backup_dir, MAX_SNAPSHOTS_PER_REASON)?;
+
+    info!("データベース復元完了: source={}", source.display());
+
+    Ok(snapshot)
+}
+
+/// 契機ごとに保持数を超えた古い自動スナップショットを削除し、削除件数を返す
+pub(crate) fn rotate_snapshots(backup_dir: &Path, keep_per_reason: usize) -> AppResult<usize> {
+    let mut by_reason: BTreeMap<Option<String>, Vec<PathBuf>> = BTreeMap::new();
+    for path in backup_files(backup_dir, &[SNAPSHOT_PREFIX])? {
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let (_, reason) = parse_backup_file_name(&file_name);
+        by_reason.entry(reason).or_default().push(path);
+    }
+
+    let mut deleted = 0;
+    for snapshots in by_reason.values_mut() {
+        // ファイル名は作成日時から始まるため、名前順が作成順になる
+        snapshots.sort();
+        let excess = snapshots.len().saturating_sub(keep_per_reason);
+        for path in &snapshots[..excess] {
+            std::fs::remove_file(path).map_err(|e| AppError::Storage(e.to_string()))?;
+            info!("古いスナップショットを削除: {}", path.display());
+        }
+        deleted += excess;
+    }
+    Ok(deleted)
+}
+
+fn write_snapshot(
+    conn: &Connection,
+    backup_dir: &Path,
+    reason: SnapshotReason,
+) -> AppResult<DatabaseBackup> {
+    let path = backup_dir.join(backup_file_name(SNAPSHOT_PREFIX, Some(reason)));
+    backup_with_connection(conn, &path)?;
+
+    info!(
+        "スナップショット作成: reason={}, path={}",
+        reason.as_str(),
+        path.display()
+    );
+
+    describe_backup(&path)
+}
+
+fn write_backup(conn: &Connection, path: &Path) -> AppResult<()> {
+    let mut dest = Connection::open(path).map_err(|e| AppError::Storage(e.to_string()))?;
+    Backup::new(conn, &mut dest)
+        .and_then(|backup| backup.run_to_completion(100, Duration::from_millis(250), None))
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+    dest.query_row("PRAGMA journal_mode = DELETE", [], |row| {
+        row.get::<_, String>(0)
+    })
+    .map_err(|e| AppError::Storage(e.to_string()))?;
+    Ok(())
+}
+
+/// バックアップ保存先から、指定の接頭辞を持つデータベースファイルを列挙する
+fn backup_files(backup_dir: &Path, prefixes: &[&str]) -> AppResult<Vec<PathBuf>> {
+    if !backup_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut files = Vec::new();
+    for entry in std::fs::read_dir(backup_dir).map_err(|e| AppError::Storage(e.to_string()))? {
+        let path = entry.map_err(|e| AppError::Storage(e.to_string()))?.path();
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        if name.ends_with(".db") && prefixes.iter().any(|prefix| name.starts_with(prefix)) {
+            files.push(path);
+        }
+    }
+    Ok(files)
+}
+
+/// `snapshot-<作成日時>-<契機>.db` / `backup-<作成日時>.db`
+fn backup_file_name(prefix: &str, reason: Option<SnapshotReason>) -> String {
+    let timestamp = Utc::now().format(TIMESTAMP_FORMAT);
+    match reason {
+        Some(reason) => format!("{}{}-{}.db", prefix, timestamp, reason.as_str()),
+        None => format!("{}{}.db", prefix, timestamp),
+    }
+}
+
+/// ファイル名から作成日時と自動スナップショットの契機を取り出す
+fn parse_backup_file_name(file_name: &str) -> (Option<DateTime<Utc>>, Option<String>) {
+    let Some(stem) = file_name.strip_suffix(".db") else {
+        return (None, None);
+    };
+    let (rest, is_snapshot) = match stem.strip_prefix(SNAPSHOT_PREFIX) {
+        Some(rest) => (rest, true),
+        None => match stem.strip_prefix(BACKUP_PREFIX) {
+            Some(rest) => (rest, false),
+            None => return (None, None),
+        },
+    };
+
+    let (timestamp, reason) = match rest.split_once('-') {
+        Some((timestamp, reason)) => (timestamp, Some(reason.to_string())),
+        None => (rest, None),
+    };
+    let created_at = NaiveDateTime::parse_from_str(timestamp, TIMESTAMP_FORMAT)
+        .ok()
+        .map(|naive| naive.and_utc());
+
+    (created_at, reason.filter(|_| is_snapshot))
+}
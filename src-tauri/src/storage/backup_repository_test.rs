@@ -0,0 +1,206 @@
+//! データベースのバックアップと復元のテスト
+
+#[cfg(test)]
+mod tests {
+    use crate::error::AppError;
+    use crate::storage::backup_repository::{
+        backup_with_connection, create_snapshot_with_connection, describe_backup,
+        list_backups_in_dir, restore_with_connection, rotate_snapshots,
+    };
+    use crate::storage::model::SnapshotReason;
+    use crate::storage::sqlite::{current_schema_version, run_migrations};
+    use crate::storage::CURRENT_SCHEMA_VERSION;
+    use rusqlite::Connection;
+    use std::path::Path;
+    use tempfile::tempdir;
+
+    fn create_test_connection() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        run_migrations(&conn).unwrap();
+        conn.execute_batch(
+            "INSERT INTO projects (project_id, name, path_with_namespace, web_url)
+             VALUES (1, 'web', 'group/web', 'https://gitlab.example.com/group/web');
+             INSERT INTO commits (project_id, branch_name, sha, author_name, author_email, committed_date_utc, additions, deletions)
+             VALUES (1, 'main', 'aaa', 'Alice', 'alice@example.com', '2026-01-05T00:00:00+00:00', 10, 2);",
+        )
+        .unwrap();
+        conn
+    }
+
+    fn count_commits(path: &Path) -> i64 {
+        Connection::open(path)
+            .unwrap()
+            .query_row("SELECT COUNT(*) FROM commits", [], |row| row.get(0))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_backup_and_restore_round_trip() {
+        let dir = tempdir().unwrap();
+        let mut conn = create_test_connection();
+
+        let backup_path = dir.path().join("backup-manual.db");
+        backup_with_connection(&conn, &backup_path).unwrap();
+
+        // バックアップは単一ファイルで、スキーマバージョンを読み取れる
+        let backup = describe_backup(&backup_path).unwrap();
+        assert_eq!(backup.schema_version, Some(CURRENT_SCHEMA_VERSION));
+        assert!(backup.size_bytes > 0);
+        let journal_mode: String = Connection::open(&backup_path)
+            .unwrap()
+            .query_row("PRAGMA journal_mode", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(journal_mode, "delete");
+
+        conn.execute("DELETE FROM commits", []).unwrap();
+
+        let snapshot = restore_with_connection(&mut conn, dir.path(), &backup_path).unwrap();
+        let restored: i64 = conn
+            .query_row("SELECT COUNT(*) FROM commits", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(restored, 1);
+
+        // 復元前の状態はスナップショットに残る
+        assert_eq!(snapshot.reason.as_deref(), Some("pre-restore"));
+        assert_eq!(count_commits(Path::new(&snapshot.path)), 0);
+    }
+
+    #[test]
+    fn test_restore_pre_migration_snapshot() {
+        let dir = tempdir().unwrap();
+        let mut conn = create_test_connection();
+
+        // 作成日時の列（version 16）を追加する前のデータベースを再現する
+        let snapshot_path = dir
+            .path()
+            .join("snapshot-20260101T000000000Z-pre-migration.db");
+        backup_with_connection(&conn, &snapshot_path).unwrap();
+        Connection::open(&snapshot_path)
+            .unwrap()
+            .execute_batch(
+                "DROP INDEX idx_commits_authored_date;
+                 ALTER TABLE commits DROP COLUMN authored_date_utc;
+                 DELETE FROM schema_migrations WHERE version >= 16;",
+            )
+            .unwrap();
+        assert_eq!(
+            describe_backup(&snapshot_path).unwrap().schema_version,
+            Some(15)
+        );
+
+        conn.execute("DELETE FROM commits", []).unwrap();
+        restore_with_connection(&mut conn, dir.path(), &snapshot_path).unwrap();
+
+        // 復元後にマイグレーションを適用し、現在のスキーマで読み書きできる
+        assert_eq!(
+            current_schema_version(&conn).unwrap(),
+            CURRENT_SCHEMA_VERSION
+        );
+        let authored: Option<String> = conn
+            .query_row(
+                "SELECT authored_date_utc FROM commits WHERE sha = 'aaa'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(authored, None);
+    }
+
+    #[test]
+    fn test_restore_rejects_incompatible_backup() {
+        let dir = tempdir().unwrap();
+        let mut conn = create_test_connection();
+
+        let backup_path = dir.path().join("backup-newer.db");
+        backup_with_connection(&conn, &backup_path).unwrap();
+        Connection::open(&backup_path)
+            .unwrap()
+            .execute(
+                "INSERT INTO schema_migrations (version, applied_at_utc) VALUES (?1, '2026-01-01T00:00:00Z')",
+                [CURRENT_SCHEMA_VERSION + 1],
+            )
+            .unwrap();
+
+        let result = restore_with_connection(&mut conn, dir.path(), &backup_path);
+        assert!(matches!(result, Err(AppError::Validation(_))));
+
+        // SQLite 以外のファイルも拒否し、スナップショットは作らない
+        let not_database = dir.path().join("notes.db");
+        std::fs::write(&not_database, "not a database").unwrap();
+        let result = restore_with_connection(&mut conn, dir.path(), &not_database);
+        assert!(matches!(result, Err(AppError::Validation(_))));
+
+        assert!(list_backups_in_dir(dir.path())
+            .unwrap()
+            .iter()
+            .all(|backup| backup.reason.is_none()));
+    }
+
+    #[test]
+    fn test_restore_rejected_while_bulk_collection_running() {
+        let dir = tempdir().unwrap();
+        let mut conn = create_test_connection();
+
+        let backup_path = dir.path().join("backup-manual.db");
+        backup_with_connection(&conn, &backup_path).unwrap();
+        conn.execute(
+            "INSERT INTO bulk_collection_runs (run_id, started_at_utc, status, total_targets)
+             VALUES ('run-1', '2026-01-06T00:00:00Z', 'running', 1)",
+            [],
+        )
+        .unwrap();
+
+        let result = restore_with_connection(&mut conn, dir.path(), &backup_path);
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[test]
+    fn test_rotate_snapshots_keeps_latest_per_reason() {
+        let dir = tempdir().unwrap();
+        for name in [
+            "snapshot-20260101T000000000Z-pre-migration.db",
+            "snapshot-20260102T000000000Z-pre-delete-branch.db",
+            "snapshot-20260103T000000000Z-pre-delete-branch.db",
+            "snapshot-20260104T000000000Z-pre-delete-branch.db",
+            "snapshot-20260105T000000000Z-pre-import.db",
+            "backup-20251231T000000000Z.db",
+            "unrelated.db",
+        ] {
+            std::fs::write(dir.path().join(name), "").unwrap();
+        }
+
+        assert_eq!(rotate_snapshots(dir.path(), 2).unwrap(), 1);
+
+        // ブランチ削除を繰り返してもマイグレーション前のスナップショットは残り、
+        // 手動バックアップは削除されず、新しい順に並ぶ
+        let backups = list_backups_in_dir(dir.path()).unwrap();
+        let reasons: Vec<Option<&str>> = backups.iter().map(|b| b.reason.as_deref()).collect();
+        assert_eq!(
+            reasons,
+            vec![
+                Some("pre-import"),
+                Some("pre-delete-branch"),
+                Some("pre-delete-branch"),
+                Some("pre-migration"),
+                None
+            ]
+        );
+        assert_eq!(backups[0].created_at_utc, "2026-01-05T00:00:00+00:00");
+        assert_eq!(backups[2].created_at_utc, "2026-01-03T00:00:00+00:00");
+        assert_eq!(backups[4].schema_version, None);
+    }
+
+    #[test]
+    fn test_create_snapshot() {
+        let dir = tempdir().unwrap();
+        let conn = create_test_connection();
+
+        let snapshot =
+            create_snapshot_with_connection(&conn, dir.path(), SnapshotReason::PreDeleteBranch)
+                .unwrap();
+        assert_eq!(snapshot.reason.as_deref(), Some("pre-delete-branch"));
+        assert_eq!(snapshot.schema_version, Some(CURRENT_SCHEMA_VERSION));
+        assert_eq!(count_commits(Path::new(&snapshot.path)), 1);
+        assert_eq!(list_backups_in_dir(dir.path()).unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,217 @@
+//! ボット判定リポジトリ（SQLite ベース）
+//!
+//! ボット判定設定と GitLab で bot と判定されたユーザーを保存し、
+//! 保存済みコミットの is_bot を再判定する。
+
+use crate::domain::{BotConfig, BotDetector};
+use crate::error::{AppError, AppResult};
+use crate::storage::settings_repository::{
+    get_setting_with_connection, set_setting_with_connection,
+};
+use crate::storage::sqlite;
+use rusqlite::params;
+use serde::Serialize;
+use std::collections::HashSet;
+
+/// app_settings のキー
+const BOT_CONFIG_KEY: &str = "bot_config";
+
+/// GitLab で bot と判定されたユーザー
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitLabBotUser {
+    /// ユーザーキー（コミットの author_email、なければ author_name）
+    pub user_key: String,
+    /// GitLab ユーザー ID
+    pub gitlab_user_id: i64,
+    /// GitLab ユーザー名
+    pub username: String,
+}
+
+/// ボット判定設定を取得（未設定なら既定値）
+pub fn get_bot_config() -> AppResult<BotConfig> {
+    let conn = sqlite::DatabaseConnection::create_connection()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    get_bot_config_with_connection(&conn)
+}
+
+/// ボット判定設定を保存し、保存済みコミットを再判定する
+///
+/// 戻り値は is_bot が変わったコミット件数。
+pub fn save_bot_config(config: &BotConfig) -> AppResult<usize> {
+    let mut conn = sqlite::DatabaseConnection::create_connection()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    save_bot_config_with_connection(&mut conn, config)
+}
+
+/// GitLab で bot と判定されたユーザー一覧を取得
+pub fn list_gitlab_bot_users() -> AppResult<Vec<GitLabBotUser>> {
+    let conn = sqlite::DatabaseConnection::create_connection()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    list_gitlab_bot_users_with_connection(&conn)
+}
+
+/// 保存済みコミットの作者一覧（作者名, メールアドレス）を取得
+pub fn list_commit_authors() -> AppResult<Vec<(String, String)>> {
+    let conn = sqlite::DatabaseConnection::create_connection()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    list_commit_authors_with_connection(&conn)
+}
+
+/// GitLab で bot と判定されたユーザーを置き換え、保存済みコミットを再判定する
+///
+/// `keep_user_keys` のユーザー（照会に失敗した作者など）は保存済みの判定を残す。
+pub fn replace_gitlab_bot_users(
+    users: &[GitLabBotUser],
+    keep_user_keys: &[String],
+) -> AppResult<usize> {
+    let mut conn = sqlite::DatabaseConnection::create_connection()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    replace_gitlab_bot_users_with_connection(&mut conn, users, keep_user_keys)
+}
+
+pub(crate) fn get_bot_config_with_connection(conn: &rusqlite::Connection) -> AppResult<BotConfig> {
+    Ok(get_setting_with_connection(conn, BOT_CONFIG_KEY)?.unwrap_or_default())
+}
+
+pub(crate) fn save_bot_config_with_connection(
+    conn: &mut rusqlite::Connection,
+    config: &BotConfig,
+) -> AppResult<usize> {
+    let tx = conn
+        .transaction()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    // 保存前に正規表現を検証
+    let detector = BotDetector::new(config, gitlab_bot_keys(&tx)?)?;
+    set_setting_with_connection(&tx, BOT_CONFIG_KEY, config)?;
+    let updated = reclassify_bots_with_connection(&tx, &detector)?;
+
+    tx.commit().map_err(|e| AppError::Storage(e.to_string()))?;
+
+    Ok(updated)
+}
+
+pub(crate) fn list_gitlab_bot_users_with_connection(
+    conn: &rusqlite::Connection,
+) -> AppResult<Vec<GitLabBotUser>> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT user_key, gitlab_user_id, username FROM gitlab_bot_users ORDER BY user_key",
+        )
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    let users = stmt
+        .query_map([], |row| {
+            Ok(GitLabBotUser {
+                user_key: row.get(0)?,
+                gitlab_user_id: row.get(1)?,
+                username: row.get(2)?,
+            })
+        })
+        .map_err(|e| AppError::Storage(e.to_string()))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    Ok(users)
+}
+
+pub(crate) fn replace_gitlab_bot_users_with_connection(
+    conn: &mut rusqlite::Connection,
+    users: &[GitLabBotUser],
+    keep_user_keys: &[String],
+) -> AppResult<usize> {
+    let detected_at = chrono::Utc::now().to_rfc3339();
+
+    let tx = conn
+        .transaction()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    for user in list_gitlab_bot_users_with_connection(&tx)? {
+        if !keep_user_keys.contains(&user.user_key) {
+            tx.execute(
+                "DELETE FROM gitlab_bot_users WHERE user_key = ?1",
+                params![user.user_key],
+            )
+            .map_err(|e| AppError::Storage(e.to_string()))?;
+        }
+    }
+
+    for user in users {
+        tx.execute(
+            "INSERT OR IGNORE INTO gitlab_bot_users (user_key, gitlab_user_id, username, detected_at_utc)
+             VALUES (?1, ?2, ?3, ?4)",
+            params![user.user_key, user.gitlab_user_id, user.username, detected_at],
+        )
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+    }
+
+    let detector = load_bot_detector_with_connection(&tx)?;
+    let updated = reclassify_bots_with_connection(&tx, &detector)?;
+
+    tx.commit().map_err(|e| AppError::Storage(e.to_string()))?;
+
+    Ok(updated)
+}
+
+/// 保存済みの設定から判定器を作成
+pub(crate) fn load_bot_detector_with_connection(
+    conn: &rusqlite::Connection,
+) -> AppResult<BotDetector> {
+    let config = get_bot_config_with_connection(conn)?;
+    BotDetector::new(&config, gitlab_bot_keys(conn)?)
+}
+
+pub(crate) fn list_commit_authors_with_connection(
+    conn: &rusqlite::Connection,
+) -> AppResult<Vec<(String, String)>> {
+    let mut stmt = conn
+        .prepare("SELECT DISTINCT author_name, author_email FROM commits ORDER BY author_name")
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    let authors = stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+        .map_err(|e| AppError::Storage(e.to_string()))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    Ok(authors)
+}
+
+/// 保存済みコミットを判定器で再判定し、is_bot が変わった件数を返す
+pub(crate) fn reclassify_bots_with_connection(
+    conn: &rusqlite::Connection,
+    detector: &BotDetector,
+) -> AppResult<usize> {
+    // 判定は作者単位なので、作者ごとにまとめて更新する
+    let authors = list_commit_authors_with_connection(conn)?;
+
+    let mut update = conn
+        .prepare(
+            "UPDATE commits SET is_bot = ?1
+             WHERE author_name = ?2 AND author_email = ?3 AND is_bot <> ?1",
+        )
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    let mut updated = 0;
+    for (author_name, author_email) in authors {
+        let is_bot = detector.is_bot(&author_name, Some(&author_email));
+        updated += update
+            .execute(params![is_bot, author_name, author_email])
+            .map_err(|e| AppError::Storage(e.to_string()))?;
+    }
+
+    Ok(updated)
+}
+
+fn gitlab_bot_keys(conn: &rusqlite::Connection) -> AppResult<HashSet<String>> {
+    Ok(list_gitlab_bot_users_with_connection(conn)?
+        .into_iter()
+        .map(|user| user.user_key)
+        .collect())
+}
@@ -0,0 +1,140 @@
+//! ボット判定リポジトリのテスト
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::BotConfig;
+    use crate::error::AppError;
+    use crate::storage::bot_repository::{
+        get_bot_config_with_connection, list_gitlab_bot_users_with_connection,
+        replace_gitlab_bot_users_with_connection, save_bot_config_with_connection, GitLabBotUser,
+    };
+    use crate::storage::migration_repository::backfill_with_connection;
+    use crate::storage::sqlite::run_migrations;
+    use rusqlite::Connection;
+
+    fn create_test_connection() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(include_str!("sqlite/migrations/001_init.sql"))
+            .unwrap();
+        conn.execute_batch(
+            "INSERT INTO projects (project_id, name, path_with_namespace, web_url, last_sync_time_utc)
+             VALUES (1, 'project-a', 'group/project-a', 'https://gitlab.example.com/group/project-a', '2026-02-01T00:00:00Z');
+             INSERT INTO commits (project_id, branch_name, sha, author_name, author_email, committed_date_utc, additions, deletions) VALUES
+             (1, 'main', 'a1', 'alice', 'alice@example.com', '2026-01-10T09:00:00+00:00', 10, 2),
+             (1, 'main', 'b1', 'renovate[bot]', 'bot@renovateapp.com', '2026-01-11T09:00:00+00:00', 5, 5),
+             (1, 'main', 'c1', 'Release', 'svc-release@example.com', '2026-01-12T09:00:00+00:00', 1, 0);",
+        )
+        .unwrap();
+        // is_bot 列追加前のデータがマイグレーション後の補完で既定設定により判定されること
+        run_migrations(&conn).unwrap();
+        backfill_with_connection(&conn, 1).unwrap();
+        conn
+    }
+
+    fn is_bot(conn: &Connection, sha: &str) -> bool {
+        conn.query_row("SELECT is_bot FROM commits WHERE sha = ?1", [sha], |row| {
+            row.get(0)
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_migration_backfills_with_default_config() {
+        let conn = create_test_connection();
+
+        assert!(!is_bot(&conn, "a1"));
+        assert!(is_bot(&conn, "b1"));
+        assert!(!is_bot(&conn, "c1"));
+        assert_eq!(
+            get_bot_config_with_connection(&conn).unwrap(),
+            BotConfig::default()
+        );
+    }
+
+    #[test]
+    fn test_save_config_and_gitlab_users_reclassify_commits() {
+        let mut conn = create_test_connection();
+
+        let config = BotConfig {
+            exact_emails: vec!["alice@example.com".to_string()],
+            name_patterns: Vec::new(),
+            email_patterns: Vec::new(),
+            use_gitlab_bot_flag: true,
+        };
+        let updated = save_bot_config_with_connection(&mut conn, &config).unwrap();
+
+        assert_eq!(updated, 2);
+        assert!(is_bot(&conn, "a1"));
+        assert!(!is_bot(&conn, "b1"));
+        assert_eq!(get_bot_config_with_connection(&conn).unwrap(), config);
+
+        let updated = replace_gitlab_bot_users_with_connection(
+            &mut conn,
+            &[GitLabBotUser {
+                user_key: "svc-release@example.com".to_string(),
+                gitlab_user_id: 42,
+                username: "project_1_bot".to_string(),
+            }],
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(updated, 1);
+        assert!(is_bot(&conn, "c1"));
+    }
+
+    #[test]
+    fn test_replace_keeps_bot_users_whose_lookup_failed() {
+        let mut conn = create_test_connection();
+        let release_bot = GitLabBotUser {
+            user_key: "svc-release@example.com".to_string(),
+            gitlab_user_id: 42,
+            username: "project_1_bot".to_string(),
+        };
+        replace_gitlab_bot_users_with_connection(
+            &mut conn,
+            std::slice::from_ref(&release_bot),
+            &[],
+        )
+        .unwrap();
+        assert!(is_bot(&conn, "c1"));
+
+        // 照会に失敗した作者は判定を残す
+        let updated = replace_gitlab_bot_users_with_connection(
+            &mut conn,
+            &[],
+            &["svc-release@example.com".to_string()],
+        )
+        .unwrap();
+        assert_eq!(updated, 0);
+        assert!(is_bot(&conn, "c1"));
+        assert_eq!(
+            list_gitlab_bot_users_with_connection(&conn).unwrap(),
+            vec![release_bot]
+        );
+
+        // 照会できて bot でなかった作者は判定を外す
+        let updated = replace_gitlab_bot_users_with_connection(&mut conn, &[], &[]).unwrap();
+        assert_eq!(updated, 1);
+        assert!(!is_bot(&conn, "c1"));
+    }
+
+    #[test]
+    fn test_invalid_config_is_not_saved() {
+        let mut conn = create_test_connection();
+
+        let result = save_bot_config_with_connection(
+            &mut conn,
+            &BotConfig {
+                email_patterns: vec!["(".to_string()],
+                ..BotConfig::default()
+            },
+        );
+
+        assert!(matches!(result, Err(AppError::Validation(_))));
+        assert_eq!(
+            get_bot_config_with_connection(&conn).unwrap(),
+            BotConfig::default()
+        );
+    }
+}
@@ -82,7 +82,7 @@ pub fn get_latest_resumable_run() -> AppResult<Option<String>> {
     get_latest_resumable_run_with_connection(&conn)
 }
 
-/// 一括収集の対象（収集履歴あり）を取得
+/// 一括収集の対象（収集履歴あり、ローカル取り込みのプロジェクトを除く）を取得
 pub fn get_collection_targets() -> AppResult<Vec<(i64, String)>> {
     let conn = sqlite::DatabaseConnection::create_connection()
         .map_err(|e| AppError::Storage(e.to_string()))?;
@@ -345,6 +345,7 @@ pub(crate) fn get_collection_targets_with_connection(
         .prepare(
             "SELECT DISTINCT project_id, branch_name
              FROM commits
+             WHERE project_id > 0
              ORDER BY project_id, branch_name",
         )
         .map_err(|e| AppError::Storage(e.to_string()))?;
@@ -105,4 +105,29 @@ mod tests {
         let targets = get_collection_targets_with_connection(&conn).unwrap();
         assert_eq!(targets.len(), 2);
     }
+
+    #[test]
+    fn test_collection_targets_exclude_local_projects() {
+        let conn = create_test_connection();
+        seed_commits(&conn);
+        conn.execute(
+            "INSERT INTO projects (project_id, name, path_with_namespace, web_url)
+             VALUES (-10, 'mirror', 'local/mirror', 'file:///srv/mirror')",
+            [],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO commits (project_id, branch_name, sha, author_name, author_email, committed_date_utc, additions, deletions)
+             VALUES (-10, 'main', 'c1', 'user', 'user@example.com', '2026-02-01T00:00:00Z', 1, 0)",
+            [],
+        )
+        .unwrap();
+
+        // ローカル取り込みのプロジェクトは GitLab から収集しない
+        let targets = get_collection_targets_with_connection(&conn).unwrap();
+        assert_eq!(
+            targets,
+            vec![(1, "main".to_string()), (2, "dev".to_string())]
+        );
+    }
 }
@@ -0,0 +1,153 @@
+//! コミット分類ルールリポジトリ（SQLite ベース）
+//!
+//! ユーザー定義の分類ルールを保存し、保存済みコミットの再分類を行う。
+
+use crate::domain::{CategoryClassifier, CategoryRule, CategoryRuleTarget};
+use crate::error::{AppError, AppResult};
+use crate::storage::sqlite;
+use rusqlite::params;
+
+/// 分類ルールを評価順に取得
+pub fn list_category_rules() -> AppResult<Vec<CategoryRule>> {
+    let conn = sqlite::DatabaseConnection::create_connection()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    list_category_rules_with_connection(&conn)
+}
+
+/// 分類ルールを置き換え、保存済みコミットを再分類する
+///
+/// 戻り値はカテゴリが変わったコミット件数。
+pub fn replace_category_rules(rules: &[CategoryRule]) -> AppResult<usize> {
+    let mut conn = sqlite::DatabaseConnection::create_connection()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    replace_category_rules_with_connection(&mut conn, rules)
+}
+
+pub(crate) fn list_category_rules_with_connection(
+    conn: &rusqlite::Connection,
+) -> AppResult<Vec<CategoryRule>> {
+    let mut stmt = conn
+        .prepare("SELECT category, target, pattern FROM category_rules ORDER BY position")
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+            ))
+        })
+        .map_err(|e| AppError::Storage(e.to_string()))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    rows.into_iter()
+        .map(|(category, target, pattern)| {
+            let target = CategoryRuleTarget::parse(&target).ok_or_else(|| {
+                AppError::Storage(format!("Invalid category rule target: {}", target))
+            })?;
+            Ok(CategoryRule {
+                category,
+                target,
+                pattern,
+            })
+        })
+        .collect()
+}
+
+pub(crate) fn replace_category_rules_with_connection(
+    conn: &mut rusqlite::Connection,
+    rules: &[CategoryRule],
+) -> AppResult<usize> {
+    // 保存する値と再分類に使う値を揃えるため、前後の空白を除いてから検証する
+    let rules: Vec<CategoryRule> = rules
+        .iter()
+        .map(|rule| CategoryRule {
+            category: rule.category.trim().to_string(),
+            target: rule.target,
+            pattern: rule.pattern.trim().to_string(),
+        })
+        .collect();
+    let classifier = CategoryClassifier::new(&rules)?;
+    let updated_at = chrono::Utc::now().to_rfc3339();
+
+    let tx = conn
+        .transaction()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    tx.execute("DELETE FROM category_rules", [])
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    for (position, rule) in rules.iter().enumerate() {
+        tx.execute(
+            "INSERT INTO category_rules (position, category, target, pattern, updated_at_utc)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                position as i64,
+                rule.category,
+                rule.target.as_str(),
+                rule.pattern,
+                updated_at
+            ],
+        )
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+    }
+
+    let updated = reclassify_commits_with_connection(&tx, &classifier)?;
+
+    tx.commit().map_err(|e| AppError::Storage(e.to_string()))?;
+
+    Ok(updated)
+}
+
+/// 保存済みのルールで全コミットを再分類し、カテゴリが変わった件数を返す
+pub(crate) fn reclassify_with_saved_rules_with_connection(
+    conn: &rusqlite::Connection,
+) -> AppResult<usize> {
+    let classifier = CategoryClassifier::new(&list_category_rules_with_connection(conn)?)?;
+    reclassify_commits_with_connection(conn, &classifier)
+}
+
+/// 保存済みコミットを分類器で再分類し、カテゴリが変わった件数を返す
+pub(crate) fn reclassify_commits_with_connection(
+    conn: &rusqlite::Connection,
+    classifier: &CategoryClassifier,
+) -> AppResult<usize> {
+    let mut stmt = conn
+        .prepare("SELECT rowid, message, branch_name, category FROM commits")
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    let changes = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+            ))
+        })
+        .map_err(|e| AppError::Storage(e.to_string()))?
+        .filter_map(|row| match row {
+            Ok((rowid, message, branch_name, current)) => {
+                let category = classifier.classify(&message, &branch_name);
+                (category != current).then_some(Ok((rowid, category)))
+            }
+            Err(e) => Some(Err(e)),
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    let mut update = conn
+        .prepare("UPDATE commits SET category = ?1 WHERE rowid = ?2")
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+    for (rowid, category) in &changes {
+        update
+            .execute(params![category, rowid])
+            .map_err(|e| AppError::Storage(e.to_string()))?;
+    }
+
+    Ok(changes.len())
+}
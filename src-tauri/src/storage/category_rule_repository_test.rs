@@ -0,0 +1,100 @@
+//! コミット分類ルールリポジトリのテスト
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::{CategoryRule, CategoryRuleTarget};
+    use crate::error::AppError;
+    use crate::storage::category_rule_repository::{
+        list_category_rules_with_connection, replace_category_rules_with_connection,
+    };
+    use crate::storage::sqlite::run_migrations;
+    use rusqlite::Connection;
+
+    fn create_test_connection() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(include_str!("sqlite/migrations/001_init.sql"))
+            .unwrap();
+        conn.execute_batch(
+            "INSERT INTO projects (project_id, name, path_with_namespace, web_url, last_sync_time_utc)
+             VALUES (1, 'project-a', 'group/project-a', 'https://gitlab.example.com/group/project-a', '2026-02-01T00:00:00Z');
+             INSERT INTO commits (project_id, branch_name, sha, author_name, author_email, committed_date_utc, additions, deletions) VALUES
+             (1, 'main', 'a1', 'alice', 'alice@example.com', '2026-01-10T09:00:00+00:00', 10, 2),
+             (1, 'release/1.0', 'a2', 'alice', 'alice@example.com', '2026-01-11T09:00:00+00:00', 1, 1);",
+        )
+        .unwrap();
+        // 001 のスキーマから最新まで移行する（既存データの分類は migration_repository で補完）
+        run_migrations(&conn).unwrap();
+        conn.execute_batch("UPDATE commits SET message = 'fix(api): null check' WHERE sha = 'a1';")
+            .unwrap();
+        conn
+    }
+
+    fn category_of(conn: &Connection, sha: &str) -> String {
+        conn.query_row(
+            "SELECT category FROM commits WHERE sha = ?1",
+            [sha],
+            |row| row.get(0),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_replace_rules_reclassifies_commits() {
+        let mut conn = create_test_connection();
+        assert_eq!(category_of(&conn, "a1"), "other");
+
+        let rules = vec![CategoryRule {
+            category: "release".to_string(),
+            target: CategoryRuleTarget::Branch,
+            pattern: "^release/".to_string(),
+        }];
+        let updated = replace_category_rules_with_connection(&mut conn, &rules).unwrap();
+
+        assert_eq!(updated, 2);
+        assert_eq!(category_of(&conn, "a1"), "fix");
+        assert_eq!(category_of(&conn, "a2"), "release");
+        assert_eq!(list_category_rules_with_connection(&conn).unwrap(), rules);
+
+        // ルール削除で Conventional Commits のみに戻る
+        let updated = replace_category_rules_with_connection(&mut conn, &[]).unwrap();
+        assert_eq!(updated, 1);
+        assert_eq!(category_of(&conn, "a2"), "other");
+    }
+
+    #[test]
+    fn test_rules_are_trimmed_before_reclassifying() {
+        let mut conn = create_test_connection();
+
+        let rules = vec![CategoryRule {
+            category: " release ".to_string(),
+            target: CategoryRuleTarget::Branch,
+            pattern: " ^release/ ".to_string(),
+        }];
+        replace_category_rules_with_connection(&mut conn, &rules).unwrap();
+
+        // 保存したルールと再分類の結果が同じカテゴリ名になる
+        let saved = list_category_rules_with_connection(&conn).unwrap();
+        assert_eq!(saved[0].category, "release");
+        assert_eq!(saved[0].pattern, "^release/");
+        assert_eq!(category_of(&conn, "a2"), "release");
+    }
+
+    #[test]
+    fn test_invalid_rule_is_not_saved() {
+        let mut conn = create_test_connection();
+
+        let result = replace_category_rules_with_connection(
+            &mut conn,
+            &[CategoryRule {
+                category: "broken".to_string(),
+                target: CategoryRuleTarget::Message,
+                pattern: "[".to_string(),
+            }],
+        );
+
+        assert!(matches!(result, Err(AppError::Validation(_))));
+        assert!(list_category_rules_with_connection(&conn)
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -2,9 +2,9 @@
 //!
 //! Commit の永続化を SQLite で行います。
 
-use crate::domain::Commit;
+use crate::domain::{parse_gitlab_date, CategoryClassifier, Commit, CommitDateBasis};
 use crate::error::{AppError, AppResult};
-use crate::storage::{sqlite, BulkUpsertResult};
+use crate::storage::{bot_repository, category_rule_repository, sqlite, BulkUpsertResult};
 
 /// コミットリポジトリ
 pub struct CommitRepository;
@@ -77,51 +77,162 @@ impl CommitRepository {
     }
 
     /// 年でフィルタしたコミットを取得（全プロジェクト横断）
-    pub fn find_by_year(year: i32) -> AppResult<Vec<Commit>> {
+    ///
+    /// `basis` の日時で年に振り分け、返すコミットの `committed_date_utc` もその日時に置き換える。
+    pub fn find_by_year(year: i32, basis: CommitDateBasis) -> AppResult<Vec<Commit>> {
+        let start = chrono::NaiveDate::from_ymd_opt(year, 1, 1)
+            .ok_or_else(|| AppError::Validation(format!("年の指定が不正です: {}", year)))?;
+        let end = chrono::NaiveDate::from_ymd_opt(year + 1, 1, 1)
+            .ok_or_else(|| AppError::Validation(format!("年の指定が不正です: {}", year)))?;
+
+        Self::find_by_date_range(start, end, basis)
+    }
+
+    /// 期間 [start, end) のコミットを取得（全プロジェクト横断、日付は UTC）
+    ///
+    /// 日時不正のコミット（UNIX エポックで保存）は期間に関係なく含めない。
+    /// `basis` の日時で期間に振り分け、返すコミットの `committed_date_utc` もその日時に置き換える。
+    pub fn find_by_date_range(
+        start: chrono::NaiveDate,
+        end: chrono::NaiveDate,
+        basis: CommitDateBasis,
+    ) -> AppResult<Vec<Commit>> {
         let conn = sqlite::DatabaseConnection::create_connection()
             .map_err(|e| AppError::Storage(e.to_string()))?;
+        Self::find_by_date_range_with_connection(&conn, start, end, basis)
+    }
 
-        // NOTE: SQLite の実装で年フィルタのメソッドが必要
+    pub(crate) fn find_by_date_range_with_connection(
+        conn: &rusqlite::Connection,
+        start: chrono::NaiveDate,
+        end: chrono::NaiveDate,
+        basis: CommitDateBasis,
+    ) -> AppResult<Vec<Commit>> {
+        // NOTE: SQLite の実装で期間フィルタのメソッドが必要
         // 現時点では簡易的に範囲クエリで検索
-        let start_date = format!("{}-01-01T00:00:00Z", year);
-        let end_date = format!("{}-01-01T00:00:00Z", year + 1);
+        let start_date = format!("{}T00:00:00Z", start);
+        let end_date = format!("{}T00:00:00Z", end);
+
+        // 作成日時が無いコミットはコミット日時で振り分ける
+        let date_column = match basis {
+            CommitDateBasis::Committed => "committed_date_utc",
+            CommitDateBasis::Authored => "COALESCE(authored_date_utc, committed_date_utc)",
+        };
 
         // 全プロジェクトから取得
         let mut stmt = conn
-            .prepare(
-                "SELECT project_id, branch_name, sha, author_name, author_email, 
-                 committed_date_utc, additions, deletions
+            .prepare(&format!(
+                "SELECT {columns}
                  FROM commits
-                 WHERE committed_date_utc >= ? AND committed_date_utc < ?
-                 ORDER BY committed_date_utc DESC",
-            )
+                 WHERE date_invalid = 0 AND {date_column} >= ? AND {date_column} < ?
+                 ORDER BY {date_column} DESC",
+                columns = sqlite::commit_repository::COMMIT_COLUMNS,
+            ))
             .map_err(|e| AppError::Storage(e.to_string()))?;
 
         let commits = stmt
-            .query_map(rusqlite::params![start_date, end_date], |row| {
-                Ok(sqlite::commit_repository::Commit {
-                    project_id: row.get(0)?,
-                    branch_name: row.get(1)?,
-                    sha: row.get(2)?,
-                    author_name: row.get(3)?,
-                    author_email: row.get(4)?,
-                    committed_date_utc: row.get(5)?,
-                    additions: row.get(6)?,
-                    deletions: row.get(7)?,
-                })
-            })
+            .query_map(
+                rusqlite::params![start_date, end_date],
+                sqlite::commit_repository::map_row,
+            )
             .map_err(|e| AppError::Storage(e.to_string()))?
             .collect::<Result<Vec<_>, _>>()
             .map_err(|e| AppError::Storage(e.to_string()))?;
 
         let commits = commits
             .into_iter()
-            .map(Self::convert_from_sqlite)
+            .map(|c| Self::convert_from_sqlite(c).map(|commit| commit.with_date_basis(basis)))
             .collect::<AppResult<Vec<_>>>()?;
 
         Ok(commits)
     }
 
+    /// 条件に一致するコミットを 1 ページ分取得し、総件数と合わせて返す
+    pub fn find_page(
+        filter: &sqlite::commit_repository::CommitFilter,
+        sort: sqlite::commit_repository::CommitSortColumn,
+        descending: bool,
+        limit: i64,
+        offset: i64,
+    ) -> AppResult<(Vec<Commit>, i64)> {
+        let conn = sqlite::DatabaseConnection::create_connection()
+            .map_err(|e| AppError::Storage(e.to_string()))?;
+
+        Self::find_page_with_connection(&conn, filter, sort, descending, limit, offset)
+    }
+
+    /// `find_page` の接続指定版（テスト用に接続を差し替え可能）
+    pub(crate) fn find_page_with_connection(
+        conn: &rusqlite::Connection,
+        filter: &sqlite::commit_repository::CommitFilter,
+        sort: sqlite::commit_repository::CommitSortColumn,
+        descending: bool,
+        limit: i64,
+        offset: i64,
+    ) -> AppResult<(Vec<Commit>, i64)> {
+        let total_count = sqlite::CommitRepository::count_commits(conn, filter)
+            .map_err(|e| AppError::Storage(e.to_string()))?;
+
+        let commits =
+            sqlite::CommitRepository::find_commits(conn, filter, sort, descending, limit, offset)
+                .map_err(|e| AppError::Storage(e.to_string()))?
+                .into_iter()
+                .map(Self::convert_from_sqlite)
+                .collect::<AppResult<Vec<_>>>()?;
+
+        Ok((commits, total_count))
+    }
+
+    /// コミットメッセージを全文検索し、(コミット, スニペット) と総件数を返す
+    pub fn search(
+        query: &str,
+        filter: &sqlite::commit_repository::CommitFilter,
+        limit: i64,
+        offset: i64,
+    ) -> AppResult<(Vec<(Commit, String)>, i64)> {
+        let conn = sqlite::DatabaseConnection::create_connection()
+            .map_err(|e| AppError::Storage(e.to_string()))?;
+
+        Self::search_with_connection(&conn, query, filter, limit, offset)
+    }
+
+    /// `search` の接続指定版（テスト用に接続を差し替え可能）
+    pub(crate) fn search_with_connection(
+        conn: &rusqlite::Connection,
+        query: &str,
+        filter: &sqlite::commit_repository::CommitFilter,
+        limit: i64,
+        offset: i64,
+    ) -> AppResult<(Vec<(Commit, String)>, i64)> {
+        let total_count = sqlite::CommitRepository::count_search_commits(conn, query, filter)
+            .map_err(Self::map_search_error)?;
+
+        let hits = sqlite::CommitRepository::search_commits(conn, query, filter, limit, offset)
+            .map_err(Self::map_search_error)?
+            .into_iter()
+            .map(|hit| Ok((Self::convert_from_sqlite(hit.commit)?, hit.snippet)))
+            .collect::<AppResult<Vec<_>>>()?;
+
+        Ok((hits, total_count))
+    }
+
+    /// 検索クエリの構文エラーは入力エラーとして扱う
+    ///
+    /// 検索 SQL 自体は固定のため、SQLITE_ERROR は MATCH 式（ユーザー入力）に起因する。
+    fn map_search_error(e: anyhow::Error) -> AppError {
+        match e.downcast_ref::<rusqlite::Error>() {
+            Some(rusqlite::Error::SqliteFailure(err, message))
+                if err.code == rusqlite::ErrorCode::Unknown =>
+            {
+                AppError::Validation(format!(
+                    "検索クエリの構文が正しくありません: {}",
+                    message.as_deref().unwrap_or("syntax error")
+                ))
+            }
+            _ => AppError::Storage(e.to_string()),
+        }
+    }
+
     /// 指定ブランチのコミット件数を取得
     pub fn count_by_branch(project_id: i64, branch_name: &str) -> AppResult<i64> {
         let conn = sqlite::DatabaseConnection::create_connection()
@@ -153,30 +264,106 @@ impl CommitRepository {
         Ok(deleted as i64)
     }
 
+    /// stats が欠損しているコミットを (project_id, sha) 単位で取得（新しい順）
+    pub fn find_stats_missing(
+        project_id: Option<i64>,
+        limit: Option<usize>,
+    ) -> AppResult<Vec<(i64, String)>> {
+        let conn = sqlite::DatabaseConnection::create_connection()
+            .map_err(|e| AppError::Storage(e.to_string()))?;
+
+        Self::find_stats_missing_with_connection(&conn, project_id, limit)
+    }
+
+    /// `find_stats_missing` の接続指定版（テスト用に接続を差し替え可能）
+    pub(crate) fn find_stats_missing_with_connection(
+        conn: &rusqlite::Connection,
+        project_id: Option<i64>,
+        limit: Option<usize>,
+    ) -> AppResult<Vec<(i64, String)>> {
+        let rows = sqlite::CommitRepository::find_stats_missing(
+            conn,
+            project_id.map(|id| id as i32),
+            limit.map(|l| l as i64),
+        )
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(project_id, sha)| (project_id as i64, sha))
+            .collect())
+    }
+
+    /// 再取得した行数で更新し、欠損フラグを外す（全ブランチの同じコミットが対象）
+    pub fn update_stats(
+        project_id: i64,
+        sha: &str,
+        additions: i64,
+        deletions: i64,
+    ) -> AppResult<usize> {
+        let conn = sqlite::DatabaseConnection::create_connection()
+            .map_err(|e| AppError::Storage(e.to_string()))?;
+
+        Self::update_stats_with_connection(&conn, project_id, sha, additions, deletions)
+    }
+
+    /// `update_stats` の接続指定版（テスト用に接続を差し替え可能）
+    pub(crate) fn update_stats_with_connection(
+        conn: &rusqlite::Connection,
+        project_id: i64,
+        sha: &str,
+        additions: i64,
+        deletions: i64,
+    ) -> AppResult<usize> {
+        sqlite::CommitRepository::update_commit_stats(
+            conn,
+            project_id as i32,
+            sha,
+            additions as i32,
+            deletions as i32,
+        )
+        .map_err(|e| AppError::Storage(e.to_string()))
+    }
+
     /// 一括挿入（重複スキップ）
     pub fn bulk_upsert(new_commits: Vec<Commit>) -> AppResult<BulkUpsertResult> {
         let mut conn = sqlite::DatabaseConnection::create_connection()
             .map_err(|e| AppError::Storage(e.to_string()))?;
+        Self::bulk_upsert_with_connection(&mut conn, new_commits)
+    }
+
+    pub(crate) fn bulk_upsert_with_connection(
+        conn: &mut rusqlite::Connection,
+        new_commits: Vec<Commit>,
+    ) -> AppResult<BulkUpsertResult> {
+        // 保存時に分類ルールとボット判定を適用する
+        let rules = category_rule_repository::list_category_rules_with_connection(conn)?;
+        let classifier = CategoryClassifier::new(&rules)?;
+        let bot_detector = bot_repository::load_bot_detector_with_connection(conn)?;
 
         // ドメインの Commit 型を SQLite の Commit 型に変換
         let sqlite_commits = new_commits
             .into_iter()
-            .map(Self::convert_to_sqlite)
+            .map(|mut c| {
+                c.category = classifier.classify(&c.message, &c.branch_name);
+                c.is_bot = bot_detector.is_bot(&c.author_name, c.author_email.as_deref());
+                Self::convert_to_sqlite(c)
+            })
             .collect::<AppResult<Vec<_>>>()?;
 
         // プロジェクト ID を取得（保存後に使用）
         let project_id = sqlite_commits.first().map(|c| c.project_id).unwrap_or(0);
         let total_input = sqlite_commits.len();
 
-        // SQLite の save_commits は INSERT OR REPLACE を使うため、
+        // SQLite の save_commits は UPSERT を使うため、
         // 既存のレコードは更新される（実質的には重複スキップと同等）
-        let rows_before = sqlite::CommitRepository::count_commits_by_project(&conn, project_id)
+        let rows_before = sqlite::CommitRepository::count_commits_by_project(conn, project_id)
             .map_err(|e| AppError::Storage(e.to_string()))?;
 
-        sqlite::CommitRepository::save_commits(&mut conn, sqlite_commits)
+        sqlite::CommitRepository::save_commits(conn, sqlite_commits)
             .map_err(|e| AppError::Storage(e.to_string()))?;
 
-        let rows_after = sqlite::CommitRepository::count_commits_by_project(&conn, project_id)
+        let rows_after = sqlite::CommitRepository::count_commits_by_project(conn, project_id)
             .map_err(|e| AppError::Storage(e.to_string()))?;
 
         let inserted = (rows_after - rows_before) as usize;
@@ -195,13 +382,18 @@ impl CommitRepository {
             project_id: c.project_id as i64,
             branch_name: c.branch_name,
             sha: c.sha,
-            message: String::new(), // NOTE: SQLite スキーマに message フィールドがない
+            message: c.message,
             committed_date_utc,
             author_name: c.author_name,
+            // 空文字のまま保持する（Commit::user_key が未設定として作者名にフォールバックする）
             author_email: Some(c.author_email),
             additions: c.additions as i64,
             deletions: c.deletions as i64,
-            stats_missing: false, // NOTE: SQLite スキーマに stats_missing フィールドがない
+            stats_missing: c.stats_missing,
+            category: c.category,
+            is_bot: c.is_bot,
+            date_invalid: c.date_invalid,
+            authored_date_utc: c.authored_date_utc.as_deref().and_then(parse_gitlab_date),
         })
     }
 
@@ -211,11 +403,17 @@ impl CommitRepository {
             project_id: c.project_id as i32,
             branch_name: c.branch_name,
             sha: c.sha,
+            message: c.message,
             author_name: c.author_name,
             author_email: c.author_email.unwrap_or_default(),
             committed_date_utc: c.committed_date_utc.to_rfc3339(),
             additions: c.additions as i32,
             deletions: c.deletions as i32,
+            category: c.category,
+            is_bot: c.is_bot,
+            stats_missing: c.stats_missing,
+            date_invalid: c.date_invalid,
+            authored_date_utc: c.authored_date_utc.map(|date| date.to_rfc3339()),
         })
     }
 }
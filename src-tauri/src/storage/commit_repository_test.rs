@@ -0,0 +1,227 @@
+//! コミットリポジトリ（stats 欠損コミットの再取得、集計日時の切り替え）のテスト
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::{parse_git_log_numstat, CommitDateBasis};
+    use crate::storage::commit_repository::CommitRepository;
+    use crate::storage::sqlite::{self, run_migrations};
+    use chrono::NaiveDate;
+    use rusqlite::Connection;
+
+    fn create_test_connection() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        run_migrations(&conn).unwrap();
+        conn.execute(
+            "INSERT INTO projects (project_id, name, path_with_namespace, web_url) VALUES (1, 'p', 'g/p', 'https://example.com/g/p')",
+            [],
+        )
+        .unwrap();
+        conn
+    }
+
+    fn commit(
+        branch_name: &str,
+        sha: &str,
+        date: &str,
+        additions: i32,
+        stats_missing: bool,
+    ) -> sqlite::commit_repository::Commit {
+        sqlite::commit_repository::Commit {
+            project_id: 1,
+            branch_name: branch_name.to_string(),
+            sha: sha.to_string(),
+            message: "test".to_string(),
+            author_name: "Alice".to_string(),
+            author_email: "alice@example.com".to_string(),
+            committed_date_utc: date.to_string(),
+            additions,
+            deletions: 0,
+            category: "other".to_string(),
+            is_bot: false,
+            stats_missing,
+            date_invalid: false,
+            authored_date_utc: None,
+        }
+    }
+
+    fn stored_stats(conn: &Connection, branch_name: &str, sha: &str) -> (i64, bool) {
+        conn.query_row(
+            "SELECT additions, stats_missing FROM commits WHERE branch_name = ?1 AND sha = ?2",
+            [branch_name, sha],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_find_and_update_stats_missing() {
+        let mut conn = create_test_connection();
+        sqlite::CommitRepository::save_commits(
+            &mut conn,
+            vec![
+                commit("main", "aaa", "2026-01-01T00:00:00Z", 0, true),
+                commit("develop", "aaa", "2026-01-01T00:00:00Z", 0, true),
+                commit("main", "bbb", "2026-02-01T00:00:00Z", 0, true),
+                commit("main", "ccc", "2026-03-01T00:00:00Z", 10, false),
+            ],
+        )
+        .unwrap();
+
+        // 複数ブランチの同じコミットは 1 件、新しい順
+        let missing =
+            CommitRepository::find_stats_missing_with_connection(&conn, None, None).unwrap();
+        assert_eq!(
+            missing,
+            vec![(1, "bbb".to_string()), (1, "aaa".to_string())]
+        );
+        assert_eq!(
+            CommitRepository::find_stats_missing_with_connection(&conn, Some(2), None)
+                .unwrap()
+                .len(),
+            0
+        );
+        assert_eq!(
+            CommitRepository::find_stats_missing_with_connection(&conn, None, Some(1))
+                .unwrap()
+                .len(),
+            1
+        );
+
+        let updated =
+            CommitRepository::update_stats_with_connection(&conn, 1, "aaa", 30, 5).unwrap();
+        assert_eq!(updated, 2);
+        assert_eq!(stored_stats(&conn, "develop", "aaa"), (30, false));
+        assert_eq!(
+            CommitRepository::find_stats_missing_with_connection(&conn, None, None).unwrap(),
+            vec![(1, "bbb".to_string())]
+        );
+
+        // 再取得で 0 行と確認できたコミットも欠損フラグを外し、再取得の対象から外す
+        CommitRepository::update_stats_with_connection(&conn, 1, "bbb", 0, 0).unwrap();
+        assert_eq!(stored_stats(&conn, "main", "bbb"), (0, false));
+        assert!(
+            CommitRepository::find_stats_missing_with_connection(&conn, None, None)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_recollect_keeps_repaired_stats() {
+        let mut conn = create_test_connection();
+        sqlite::CommitRepository::save_commits(
+            &mut conn,
+            vec![commit("main", "aaa", "2026-01-01T00:00:00Z", 0, true)],
+        )
+        .unwrap();
+        CommitRepository::update_stats_with_connection(&conn, 1, "aaa", 30, 5).unwrap();
+
+        // 再収集で再び stats が欠損しても補完済みの行数を残す
+        sqlite::CommitRepository::save_commits(
+            &mut conn,
+            vec![commit("main", "aaa", "2026-01-01T00:00:00Z", 0, true)],
+        )
+        .unwrap();
+        assert_eq!(stored_stats(&conn, "main", "aaa"), (30, false));
+
+        // stats が返れば上書きする
+        sqlite::CommitRepository::save_commits(
+            &mut conn,
+            vec![commit("main", "aaa", "2026-01-01T00:00:00Z", 40, false)],
+        )
+        .unwrap();
+        assert_eq!(stored_stats(&conn, "main", "aaa"), (40, false));
+    }
+
+    #[test]
+    fn test_find_by_date_range_with_authored_basis() {
+        let mut conn = create_test_connection();
+        // 12 月に作成され 1 月にコミットされた（リベース等）コミットと、作成日時のない既存コミット
+        let rebased = sqlite::commit_repository::Commit {
+            authored_date_utc: Some("2025-12-20T10:00:00+00:00".to_string()),
+            ..commit("main", "aaa", "2026-01-05T10:00:00+00:00", 10, false)
+        };
+        let legacy = commit("main", "bbb", "2026-01-06T10:00:00+00:00", 20, false);
+        sqlite::CommitRepository::save_commits(&mut conn, vec![rebased, legacy]).unwrap();
+
+        let january = |basis| {
+            CommitRepository::find_by_date_range_with_connection(
+                &conn,
+                NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2026, 2, 1).unwrap(),
+                basis,
+            )
+            .unwrap()
+            .into_iter()
+            .map(|c| c.sha)
+            .collect::<Vec<_>>()
+        };
+        assert_eq!(january(CommitDateBasis::Committed), vec!["bbb", "aaa"]);
+        assert_eq!(january(CommitDateBasis::Authored), vec!["bbb"]);
+
+        // 作成日時で振り分けたコミットは committed_date_utc も作成日時になる
+        let december = CommitRepository::find_by_date_range_with_connection(
+            &conn,
+            NaiveDate::from_ymd_opt(2025, 12, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+            CommitDateBasis::Authored,
+        )
+        .unwrap();
+        assert_eq!(december.len(), 1);
+        assert_eq!(
+            december[0].committed_date_utc.to_rfc3339(),
+            "2025-12-20T10:00:00+00:00"
+        );
+        assert!(december[0].authored_date_utc.is_some());
+    }
+
+    #[test]
+    fn test_find_by_date_range_excludes_date_invalid() {
+        let mut conn = create_test_connection();
+        // 日時不正のコミットは UNIX エポックで保存される
+        let broken = sqlite::commit_repository::Commit {
+            date_invalid: true,
+            authored_date_utc: Some("2026-01-10T10:00:00+00:00".to_string()),
+            ..commit("main", "aaa", "1970-01-01T00:00:00+00:00", 10, false)
+        };
+        let valid = commit("main", "bbb", "2026-01-06T10:00:00+00:00", 20, false);
+        sqlite::CommitRepository::save_commits(&mut conn, vec![broken, valid]).unwrap();
+
+        for basis in [CommitDateBasis::Committed, CommitDateBasis::Authored] {
+            let shas = CommitRepository::find_by_date_range_with_connection(
+                &conn,
+                NaiveDate::from_ymd_opt(1970, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2027, 1, 1).unwrap(),
+                basis,
+            )
+            .unwrap()
+            .into_iter()
+            .map(|c| c.sha)
+            .collect::<Vec<_>>();
+            assert_eq!(shas, vec!["bbb"]);
+        }
+    }
+
+    #[test]
+    fn test_local_commit_without_email_keeps_user_key_after_round_trip() {
+        let mut conn = create_test_connection();
+        // git log --numstat の出力（レコード区切り U+1E、フィールド区切り U+1F）でメールが空のコミット
+        let output = "\u{1e}ccc333\u{1f}Bob\u{1f}\u{1f}2026-01-03T00:00:00Z\u{1f}2026-01-03T00:00:00Z\u{1f}fix: typo\n\u{1f}\n1\t1\tREADME.md\n";
+        let parsed = parse_git_log_numstat(1, "main", output).unwrap();
+        assert_eq!(parsed[0].user_key(), "Bob");
+
+        CommitRepository::bulk_upsert_with_connection(&mut conn, parsed).unwrap();
+
+        // 保存時は空文字になるが、読み戻したコミットのユーザーキーは変わらない
+        let stored = CommitRepository::find_by_date_range_with_connection(
+            &conn,
+            NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2027, 1, 1).unwrap(),
+            CommitDateBasis::Committed,
+        )
+        .unwrap();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].author_email.as_deref(), Some(""));
+        assert_eq!(stored[0].user_key(), "Bob");
+    }
+}
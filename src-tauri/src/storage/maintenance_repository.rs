@@ -0,0 +1,171 @@
+//! データベース保守（SQLite ベース）
+//!
+//! 健全性レポート、VACUUM / ANALYZE、保持期間を過ぎたコミットの削除を行う。
+//! 書き込みを伴う保守は一括収集の実行中には行わない。
+
+use crate::error::{AppError, AppResult};
+use crate::paths;
+use crate::storage::backup_repository::create_snapshot_with_connection;
+use crate::storage::bulk_collection_repository::has_running_run_with_connection;
+use crate::storage::model::{
+    CommitPurgeResult, DatabaseMaintenanceLog, DatabaseOptimizeResult, SnapshotReason,
+};
+use crate::storage::settings_repository::{
+    get_setting_with_connection, set_setting_with_connection,
+};
+use crate::storage::sqlite;
+use crate::storage::sqlite::health::{DatabaseHealth, DatabaseHealthChecker};
+use rusqlite::Connection;
+use std::path::Path;
+use tracing::info;
+
+/// app_settings のキー
+const MAINTENANCE_LOG_KEY: &str = "database_maintenance";
+
+/// データベースの健全性レポートを取得
+pub fn get_database_health() -> AppResult<DatabaseHealth> {
+    let conn = sqlite::DatabaseConnection::create_connection()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+    let db_path =
+        sqlite::DatabaseConnection::get_db_path().map_err(|e| AppError::Storage(e.to_string()))?;
+
+    get_database_health_with_connection(&conn, &db_path)
+}
+
+/// VACUUM / ANALYZE を実行
+pub fn optimize_database(vacuum: bool, analyze: bool) -> AppResult<DatabaseOptimizeResult> {
+    let conn = sqlite::DatabaseConnection::create_connection()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+    let db_path =
+        sqlite::DatabaseConnection::get_db_path().map_err(|e| AppError::Storage(e.to_string()))?;
+
+    optimize_database_with_connection(&conn, &db_path, vacuum, analyze)
+}
+
+/// 保持期間を過ぎたコミットを削除（dry run では件数のみ返す）
+pub fn purge_old_commits(days_to_keep: i32, dry_run: bool) -> AppResult<CommitPurgeResult> {
+    let conn = sqlite::DatabaseConnection::create_connection()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    purge_old_commits_with_connection(&conn, &paths::ensure_backup_dir()?, days_to_keep, dry_run)
+}
+
+pub(crate) fn get_database_health_with_connection(
+    conn: &Connection,
+    db_path: &Path,
+) -> AppResult<DatabaseHealth> {
+    let mut health = DatabaseHealthChecker::check_database_health(conn, db_path)
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    let log = get_maintenance_log(conn)?;
+    health.last_vacuum_at_utc = log.last_vacuum_at_utc;
+    health.last_analyze_at_utc = log.last_analyze_at_utc;
+
+    Ok(health)
+}
+
+pub(crate) fn optimize_database_with_connection(
+    conn: &Connection,
+    db_path: &Path,
+    vacuum: bool,
+    analyze: bool,
+) -> AppResult<DatabaseOptimizeResult> {
+    if !vacuum && !analyze {
+        return Err(AppError::Validation(
+            "VACUUM と ANALYZE の少なくとも一方を指定してください".to_string(),
+        ));
+    }
+    ensure_no_running_run(conn, "最適化")?;
+
+    let size_before_mb = DatabaseHealthChecker::total_size_mb(db_path);
+    let mut log = get_maintenance_log(conn)?;
+    let now = chrono::Utc::now().to_rfc3339();
+
+    if vacuum {
+        DatabaseHealthChecker::vacuum_database(conn)
+            .map_err(|e| AppError::Storage(e.to_string()))?;
+        log.last_vacuum_at_utc = Some(now.clone());
+    }
+    if analyze {
+        DatabaseHealthChecker::analyze_database(conn)
+            .map_err(|e| AppError::Storage(e.to_string()))?;
+        log.last_analyze_at_utc = Some(now);
+    }
+    set_setting_with_connection(conn, MAINTENANCE_LOG_KEY, &log)?;
+
+    let size_after_mb = DatabaseHealthChecker::total_size_mb(db_path);
+
+    info!(
+        "データベース最適化完了: vacuum={}, analyze={}, size={:.1}MB -> {:.1}MB",
+        vacuum, analyze, size_before_mb, size_after_mb
+    );
+
+    Ok(DatabaseOptimizeResult {
+        vacuumed: vacuum,
+        analyzed: analyze,
+        size_before_mb,
+        size_after_mb,
+    })
+}
+
+pub(crate) fn purge_old_commits_with_connection(
+    conn: &Connection,
+    backup_dir: &Path,
+    days_to_keep: i32,
+    dry_run: bool,
+) -> AppResult<CommitPurgeResult> {
+    if days_to_keep <= 0 {
+        return Err(AppError::Validation(
+            "保持日数は 1 以上を指定してください".to_string(),
+        ));
+    }
+    if !dry_run {
+        ensure_no_running_run(conn, "コミットを削除")?;
+    }
+
+    let cutoff_utc = DatabaseHealthChecker::retention_cutoff(days_to_keep);
+    let matched_count = DatabaseHealthChecker::count_commits_before(conn, &cutoff_utc)
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    if dry_run || matched_count == 0 {
+        return Ok(CommitPurgeResult {
+            cutoff_utc,
+            dry_run,
+            matched_count,
+            deleted_count: 0,
+            snapshot_path: None,
+        });
+    }
+
+    // 削除は復元できないため、事前にスナップショットを作成する
+    let snapshot = create_snapshot_with_connection(conn, backup_dir, SnapshotReason::PrePurge)?;
+    let deleted_count = DatabaseHealthChecker::delete_commits_before(conn, &cutoff_utc)
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    info!(
+        "保持期間を過ぎたコミットを削除: cutoff={}, deleted={}",
+        cutoff_utc, deleted_count
+    );
+
+    Ok(CommitPurgeResult {
+        cutoff_utc,
+        dry_run,
+        matched_count,
+        deleted_count,
+        snapshot_path: Some(snapshot.path),
+    })
+}
+
+fn get_maintenance_log(conn: &Connection) -> AppResult<DatabaseMaintenanceLog> {
+    Ok(get_setting_with_connection(conn, MAINTENANCE_LOG_KEY)?.unwrap_or_default())
+}
+
+fn ensure_no_running_run(conn: &Connection, action: &str) -> AppResult<()> {
+    if has_running_run_with_connection(conn)? {
+        return Err(AppError::Validation(format!(
+            "一括収集の実行中は{}できません。完了または中断してから再実行してください",
+            action
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,142 @@
+//! データベース保守のテスト
+
+#[cfg(test)]
+mod tests {
+    use crate::error::AppError;
+    use crate::storage::maintenance_repository::{
+        get_database_health_with_connection, optimize_database_with_connection,
+        purge_old_commits_with_connection,
+    };
+    use crate::storage::sqlite::run_migrations;
+    use rusqlite::Connection;
+    use std::path::PathBuf;
+    use tempfile::{tempdir, TempDir};
+
+    /// ファイルに作成したデータベース（VACUUM とファイルサイズの確認用）
+    fn create_test_database() -> (Connection, PathBuf, TempDir) {
+        let dir = tempdir().unwrap();
+        let db_path = dir.path().join("test.db");
+        let conn = Connection::open(&db_path).unwrap();
+        run_migrations(&conn).unwrap();
+        conn.execute_batch(
+            "INSERT INTO projects (project_id, name, path_with_namespace, web_url)
+             VALUES (1, 'web', 'group/web', 'https://gitlab.example.com/group/web');
+             INSERT INTO commits (project_id, branch_name, sha, author_name, author_email, committed_date_utc, additions, deletions, message)
+             VALUES (1, 'main', 'old', 'Alice', 'alice@example.com', '2020-01-05T00:00:00+00:00', 10, 2, 'feat: legacy importer'),
+                    (1, 'main', 'new', 'Alice', 'alice@example.com', '2999-01-05T00:00:00+00:00', 3, 1, 'fix: search ranking');
+             INSERT INTO commits (project_id, branch_name, sha, author_name, author_email, committed_date_utc, additions, deletions, message, date_invalid)
+             VALUES (1, 'main', 'bad', 'Bob', 'bob@example.com', '1970-01-01T00:00:00+00:00', 1, 0, 'chore: unknown date', 1);",
+        )
+        .unwrap();
+        (conn, db_path, dir)
+    }
+
+    fn count(conn: &Connection, sql: &str) -> i64 {
+        conn.query_row(sql, [], |row| row.get(0)).unwrap()
+    }
+
+    #[test]
+    fn test_database_health_report() {
+        let (conn, db_path, _dir) = create_test_database();
+
+        let health = get_database_health_with_connection(&conn, &db_path).unwrap();
+        assert!(health.file_size_mb > 0.0);
+        assert!(health.is_integrity_ok);
+        assert_eq!(health.last_vacuum_at_utc, None);
+
+        let commits = health
+            .table_row_counts
+            .iter()
+            .find(|table| table.name == "commits")
+            .unwrap();
+        assert_eq!(commits.row_count, 3);
+        // 全文検索のシャドウテーブルは含めない
+        assert!(health
+            .table_row_counts
+            .iter()
+            .all(|table| !table.name.starts_with("commits_fts_")));
+    }
+
+    #[test]
+    fn test_optimize_records_maintenance_and_keeps_search_index() {
+        let (conn, db_path, _dir) = create_test_database();
+        conn.execute("DELETE FROM commits WHERE sha = 'old'", [])
+            .unwrap();
+
+        let result = optimize_database_with_connection(&conn, &db_path, true, true).unwrap();
+        assert!(result.vacuumed && result.analyzed);
+
+        // VACUUM で rowid が振り直されても全文検索は正しいコミットを返す
+        let sha: String = conn
+            .query_row(
+                "SELECT c.sha FROM commits_fts f JOIN commits c ON c.rowid = f.rowid
+                 WHERE commits_fts MATCH 'search'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(sha, "new");
+        conn.execute(
+            "INSERT INTO commits_fts(commits_fts, rank) VALUES('integrity-check', 1)",
+            [],
+        )
+        .unwrap();
+
+        let health = get_database_health_with_connection(&conn, &db_path).unwrap();
+        assert!(health.last_vacuum_at_utc.is_some());
+        assert!(health.last_analyze_at_utc.is_some());
+
+        let result = optimize_database_with_connection(&conn, &db_path, false, false);
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[test]
+    fn test_purge_old_commits_dry_run_and_delete() {
+        let (conn, _db_path, dir) = create_test_database();
+        let backup_dir = dir.path().join("backups");
+        std::fs::create_dir(&backup_dir).unwrap();
+
+        // dry run は件数のみ（日時不正のコミットは対象外）
+        let result = purge_old_commits_with_connection(&conn, &backup_dir, 365, true).unwrap();
+        assert_eq!((result.matched_count, result.deleted_count), (1, 0));
+        assert_eq!(result.snapshot_path, None);
+        assert_eq!(count(&conn, "SELECT COUNT(*) FROM commits"), 3);
+
+        let result = purge_old_commits_with_connection(&conn, &backup_dir, 365, false).unwrap();
+        assert_eq!((result.matched_count, result.deleted_count), (1, 1));
+        assert!(result.snapshot_path.is_some());
+        assert_eq!(
+            count(&conn, "SELECT COUNT(*) FROM commits WHERE sha = 'old'"),
+            0
+        );
+        assert_eq!(
+            count(&conn, "SELECT COUNT(*) FROM commits WHERE sha = 'bad'"),
+            1
+        );
+
+        let result = purge_old_commits_with_connection(&conn, &backup_dir, 0, true);
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[test]
+    fn test_maintenance_rejected_while_bulk_collection_running() {
+        let (conn, db_path, dir) = create_test_database();
+        conn.execute(
+            "INSERT INTO bulk_collection_runs (run_id, started_at_utc, status, total_targets)
+             VALUES ('run-1', '2026-01-06T00:00:00Z', 'running', 1)",
+            [],
+        )
+        .unwrap();
+
+        let result = optimize_database_with_connection(&conn, &db_path, true, false);
+        assert!(matches!(result, Err(AppError::Validation(_))));
+
+        let result = purge_old_commits_with_connection(&conn, dir.path(), 365, false);
+        assert!(matches!(result, Err(AppError::Validation(_))));
+        assert_eq!(count(&conn, "SELECT COUNT(*) FROM commits"), 3);
+
+        // 件数の確認は実行中でも可能
+        let result = purge_old_commits_with_connection(&conn, dir.path(), 365, true).unwrap();
+        assert_eq!(result.matched_count, 1);
+    }
+}
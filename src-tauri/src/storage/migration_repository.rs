@@ -0,0 +1,55 @@
+//! マイグレーションの適用と既存データの補完
+//!
+//! スキーマの変更は SQL のマイグレーション（`sqlite::run_migrations`）で行い、
+//! SQL では収集時と同じ判定ができない既存データの補完（Conventional Commits の分類やボット判定）は
+//! ここでドメインのロジックを使って、列を追加したマイグレーションの適用時に 1 回だけ行う。
+//! 補完が終わる前に中断してもやり直せるよう、マイグレーションの記録と補完は同じトランザクションで確定する。
+
+use crate::error::{AppError, AppResult};
+use crate::storage::bot_repository::{
+    load_bot_detector_with_connection, reclassify_bots_with_connection,
+};
+use crate::storage::category_rule_repository::reclassify_with_saved_rules_with_connection;
+use crate::storage::sqlite;
+use rusqlite::Connection;
+use tracing::info;
+
+/// コミットのカテゴリ列を追加したスキーマバージョン
+const COMMIT_CATEGORY_VERSION: u32 = 9;
+
+/// コミットのボット判定列を追加したスキーマバージョン
+const BOT_DETECTION_VERSION: u32 = 10;
+
+/// マイグレーションを適用し、今回追加した列の既存データを補完する
+pub fn migrate_with_connection(conn: &Connection) -> AppResult<()> {
+    let tx = conn
+        .unchecked_transaction()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    let previous_version =
+        sqlite::current_schema_version(&tx).map_err(|e| AppError::Storage(e.to_string()))?;
+    sqlite::run_migrations(&tx).map_err(|e| AppError::Storage(e.to_string()))?;
+    backfill_with_connection(&tx, previous_version)?;
+
+    tx.commit().map_err(|e| AppError::Storage(e.to_string()))
+}
+
+/// マイグレーション前のスキーマバージョンに応じて既存データを補完する（新規作成時は何もしない）
+pub(crate) fn backfill_with_connection(conn: &Connection, previous_version: u32) -> AppResult<()> {
+    if previous_version == 0 {
+        return Ok(());
+    }
+
+    if previous_version < COMMIT_CATEGORY_VERSION {
+        let updated = reclassify_with_saved_rules_with_connection(conn)?;
+        info!("既存コミットのカテゴリを補完: updated={}", updated);
+    }
+
+    if previous_version < BOT_DETECTION_VERSION {
+        let detector = load_bot_detector_with_connection(conn)?;
+        let updated = reclassify_bots_with_connection(conn, &detector)?;
+        info!("既存コミットのボット判定を補完: updated={}", updated);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,145 @@
+//! マイグレーション後の既存データ補完のテスト
+
+#[cfg(test)]
+mod tests {
+    use crate::storage::migration_repository::{backfill_with_connection, migrate_with_connection};
+    use crate::storage::sqlite::{current_schema_version, run_migrations};
+    use crate::storage::CURRENT_SCHEMA_VERSION;
+    use rusqlite::Connection;
+
+    fn create_test_connection() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        run_migrations(&conn).unwrap();
+        conn.execute(
+            "INSERT INTO projects (project_id, name, path_with_namespace, web_url) VALUES (1, 'p', 'g/p', 'https://example.com/g/p')",
+            [],
+        )
+        .unwrap();
+        // カテゴリ列の追加前に保存されたコミット（既定値 other のまま）
+        for (sha, message) in [
+            ("a1", "feat : spaced colon"),
+            ("a2", "fix(api: broken scope"),
+            ("a3", "Docs(readme)!: rewrite"),
+            ("a4", "\u{3000}chore: full-width indent"),
+        ] {
+            conn.execute(
+                "INSERT INTO commits (project_id, branch_name, sha, message, author_name, author_email, committed_date_utc, additions, deletions)
+                 VALUES (1, 'main', ?1, ?2, 'alice', 'alice@example.com', '2026-01-05T00:00:00+00:00', 1, 0)",
+                [sha, message],
+            )
+            .unwrap();
+        }
+        conn
+    }
+
+    fn categories(conn: &Connection) -> Vec<(String, String)> {
+        let mut stmt = conn
+            .prepare("SELECT sha, category FROM commits ORDER BY sha")
+            .unwrap();
+        stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_backfill_categories_matches_collection() {
+        let conn = create_test_connection();
+
+        // 最新のスキーマからのマイグレーションでは補完しない
+        migrate_with_connection(&conn).unwrap();
+        assert!(categories(&conn).iter().all(|(_, c)| c == "other"));
+
+        // カテゴリ列を追加する前のデータベースは、収集時と同じ判定で分類する
+        backfill_with_connection(&conn, 8).unwrap();
+        let expected = [
+            ("a1", "feat"),
+            ("a2", "other"),
+            ("a3", "docs"),
+            ("a4", "chore"),
+        ];
+        assert_eq!(
+            categories(&conn),
+            expected
+                .iter()
+                .map(|(sha, c)| (sha.to_string(), c.to_string()))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_backfill_bot_flags_with_saved_config() {
+        let conn = create_test_connection();
+        conn.execute(
+            "INSERT INTO commits (project_id, branch_name, sha, message, author_name, author_email, committed_date_utc, additions, deletions)
+             VALUES (1, 'main', 'b1', 'chore(deps): update', 'dependabot[bot]', 'bot@example.com', '2026-01-05T00:00:00+00:00', 1, 0)",
+            [],
+        )
+        .unwrap();
+        let is_bot = |conn: &Connection, sha: &str| -> bool {
+            conn.query_row("SELECT is_bot FROM commits WHERE sha = ?1", [sha], |row| {
+                row.get(0)
+            })
+            .unwrap()
+        };
+
+        // ボット判定列を追加した後のデータベースでは再判定しない
+        backfill_with_connection(&conn, 10).unwrap();
+        assert!(!is_bot(&conn, "b1"));
+
+        // ボット判定列を追加する前のデータベースは、保存済みの設定で判定する
+        backfill_with_connection(&conn, 9).unwrap();
+        assert!(is_bot(&conn, "b1"));
+        assert!(!is_bot(&conn, "a1"));
+    }
+
+    #[test]
+    fn test_backfill_skipped_for_new_database() {
+        let conn = create_test_connection();
+
+        backfill_with_connection(&conn, 0).unwrap();
+        backfill_with_connection(&conn, CURRENT_SCHEMA_VERSION).unwrap();
+        assert!(categories(&conn).iter().all(|(_, c)| c == "other"));
+    }
+
+    #[test]
+    fn test_failed_backfill_rolls_back_migrations() {
+        // 001 のスキーマだけを適用した既存データベース
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(include_str!("sqlite/migrations/001_init.sql"))
+            .unwrap();
+        assert_eq!(current_schema_version(&conn).unwrap(), 0);
+        conn.execute_batch(
+            "INSERT INTO schema_migrations (version, applied_at_utc) VALUES (1, '2025-01-01T00:00:00Z');
+             INSERT INTO projects (project_id, name, path_with_namespace, web_url) VALUES (1, 'p', 'g/p', 'https://example.com/g/p');
+             INSERT INTO commits (project_id, branch_name, sha, author_name, author_email, committed_date_utc, additions, deletions)
+             VALUES (1, 'main', 'b1', 'dependabot[bot]', 'bot@example.com', '2026-01-05T00:00:00+00:00', 1, 0);",
+        )
+        .unwrap();
+
+        // ボット判定の補完を途中で失敗させる
+        conn.execute_batch(
+            "CREATE TEMP TRIGGER fail_backfill BEFORE UPDATE ON commits
+             WHEN NEW.is_bot <> OLD.is_bot
+             BEGIN SELECT RAISE(ABORT, 'backfill failed'); END;",
+        )
+        .unwrap();
+        assert!(migrate_with_connection(&conn).is_err());
+
+        // マイグレーションの記録も残らないため、次回の起動で補完をやり直す
+        assert_eq!(current_schema_version(&conn).unwrap(), 1);
+
+        conn.execute_batch("DROP TRIGGER fail_backfill").unwrap();
+        migrate_with_connection(&conn).unwrap();
+        assert_eq!(
+            current_schema_version(&conn).unwrap(),
+            CURRENT_SCHEMA_VERSION
+        );
+        let is_bot: bool = conn
+            .query_row("SELECT is_bot FROM commits WHERE sha = 'b1'", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert!(is_bot);
+    }
+}
@@ -3,30 +3,76 @@
 //! アプリデータの永続化を担当する。
 //! SQLite ベースのストレージを提供します。
 
+pub mod alert_repository;
+pub mod archive_repository;
+pub mod backup_repository;
+pub mod bot_repository;
 pub mod bulk_collection_repository;
+pub mod category_rule_repository;
 pub mod json_store;
+pub mod maintenance_repository;
+pub mod migration_repository;
 pub mod model;
+pub mod reporting_settings_repository;
 pub mod repository;
 pub mod schema;
+pub mod settings_repository;
 pub mod sqlite;
+pub mod team_repository;
+pub mod work_calendar_repository;
 
 // SQLite リポジトリをラップしたメインリポジトリ
 pub mod commit_repository;
 pub mod connection_repository;
 pub mod project_repository;
+pub mod project_tag_repository;
 pub mod user_filter_repository;
 
+#[cfg(test)]
+mod alert_repository_test;
+#[cfg(test)]
+mod archive_repository_test;
+#[cfg(test)]
+mod backup_repository_test;
+#[cfg(test)]
+mod bot_repository_test;
 #[cfg(test)]
 mod bulk_collection_repository_test;
+#[cfg(test)]
+mod category_rule_repository_test;
+#[cfg(test)]
+mod commit_repository_test;
+#[cfg(test)]
+mod maintenance_repository_test;
+#[cfg(test)]
+mod migration_repository_test;
+#[cfg(test)]
+mod project_tag_repository_test;
+#[cfg(test)]
+mod reporting_settings_repository_test;
+#[cfg(test)]
+mod team_repository_test;
 
+pub use alert_repository::*;
+pub use archive_repository::*;
+pub use backup_repository::*;
+pub use bot_repository::*;
 pub use bulk_collection_repository::*;
+pub use category_rule_repository::*;
 pub use commit_repository::*;
 pub use connection_repository::*;
 pub use json_store::*;
+pub use maintenance_repository::*;
+pub use migration_repository::*;
 pub use model::*;
 pub use project_repository::*;
+pub use project_tag_repository::*;
+pub use reporting_settings_repository::*;
 pub use repository::*;
 pub use schema::*;
+pub use settings_repository::*;
+pub use team_repository::*;
 pub use user_filter_repository::{
     SelectedUsers, UserFilterContextKey, UserFilterRepository, UserFilterViewType,
 };
+pub use work_calendar_repository::*;
@@ -46,6 +46,146 @@ impl AppData {
     }
 }
 
+/// データアーカイブの形式名
+pub const ARCHIVE_FORMAT: &str = "gitlab-analyzer-archive";
+
+/// データアーカイブのルート構造（別の端末への移行・共有用）
+///
+/// テーブルの行をそのまま保持するため、取り込みはスキーマバージョンが一致する場合のみ行う。
+/// 接続設定（アクセストークン）は含めない。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataArchive {
+    /// 形式名（[`ARCHIVE_FORMAT`]）
+    pub format: String,
+    /// 書き出し元のスキーマバージョン
+    pub schema: SchemaVersion,
+    /// 書き出し日時（UTC）
+    pub exported_at_utc: String,
+    /// テーブルごとの行
+    pub tables: Vec<ArchiveTable>,
+}
+
+/// アーカイブ内のテーブル
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveTable {
+    /// テーブル名
+    pub name: String,
+    /// 列名
+    pub columns: Vec<String>,
+    /// 行（列の順に値を並べる）
+    pub rows: Vec<Vec<serde_json::Value>>,
+}
+
+/// アーカイブの取り込み方法
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ArchiveImportMode {
+    /// 既存データを残し、アーカイブにしかない行を追加する（重複は既存を優先）
+    #[default]
+    Merge,
+    /// アーカイブに含まれるテーブルの既存データを削除して置き換える
+    Replace,
+}
+
+/// テーブルごとの書き出し/取り込み件数
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveTableSummary {
+    /// テーブル名
+    pub name: String,
+    /// アーカイブ内の行数
+    pub row_count: usize,
+    /// 取り込んだ行数（書き出し時は行数と同じ）
+    pub imported_count: usize,
+}
+
+/// 自動スナップショットを作成する契機
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SnapshotReason {
+    /// スキーマのマイグレーション前
+    PreMigration,
+    /// ブランチ単位のコミット削除前
+    PreDeleteBranch,
+    /// アーカイブの置き換え取り込み前
+    PreImport,
+    /// バックアップからの復元前
+    PreRestore,
+    /// 保持期間を過ぎたコミットの削除前
+    PrePurge,
+}
+
+impl SnapshotReason {
+    /// ファイル名に使う識別子
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SnapshotReason::PreMigration => "pre-migration",
+            SnapshotReason::PreDeleteBranch => "pre-delete-branch",
+            SnapshotReason::PreImport => "pre-import",
+            SnapshotReason::PreRestore => "pre-restore",
+            SnapshotReason::PrePurge => "pre-purge",
+        }
+    }
+}
+
+/// バックアップファイルの情報
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DatabaseBackup {
+    /// ファイルパス
+    pub path: String,
+    /// 自動スナップショットの契機（手動バックアップは None）
+    pub reason: Option<String>,
+    /// 作成日時（UTC）
+    pub created_at_utc: String,
+    /// ファイルサイズ（バイト）
+    pub size_bytes: u64,
+    /// スキーマバージョン（読み取れない場合は None）
+    pub schema_version: Option<u32>,
+}
+
+/// データベース保守の実行記録（app_settings に保存）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DatabaseMaintenanceLog {
+    /// 最後に VACUUM した日時（UTC）
+    pub last_vacuum_at_utc: Option<String>,
+    /// 最後に ANALYZE した日時（UTC）
+    pub last_analyze_at_utc: Option<String>,
+}
+
+/// データベース最適化の結果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DatabaseOptimizeResult {
+    /// VACUUM を実行したか
+    pub vacuumed: bool,
+    /// ANALYZE を実行したか
+    pub analyzed: bool,
+    /// 実行前のファイルサイズ（MB、WAL を含む）
+    pub size_before_mb: f64,
+    /// 実行後のファイルサイズ（MB、WAL を含む）
+    pub size_after_mb: f64,
+}
+
+/// 保持期間を過ぎたコミットの削除結果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitPurgeResult {
+    /// 削除の基準日時（これより前のコミットが対象）
+    pub cutoff_utc: String,
+    /// 件数の確認のみ（削除しない）
+    pub dry_run: bool,
+    /// 対象のコミット数
+    pub matched_count: usize,
+    /// 削除したコミット数（dry run では 0）
+    pub deleted_count: usize,
+    /// 削除前に作成したスナップショットのパス
+    pub snapshot_path: Option<String>,
+}
+
 /// 一括収集の実行状態
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -73,3 +213,20 @@ pub struct BulkCollectionTargetResult {
     pub error_message: Option<String>,
     pub processed_at: Option<String>,
 }
+
+/// 記録済みのアラート
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Alert {
+    pub id: i64,
+    pub rule_name: String,
+    pub kind: String,
+    pub subject_key: String,
+    pub subject_name: String,
+    pub period: String,
+    pub value: f64,
+    pub message: String,
+    pub run_id: Option<String>,
+    pub triggered_at: String,
+    pub acknowledged_at: Option<String>,
+}
@@ -55,6 +55,25 @@ impl ProjectRepository {
         Ok(())
     }
 
+    /// プロジェクトを 1 件保存（ローカル取り込みの合成プロジェクトに使用）
+    pub fn upsert(project: &Project) -> AppResult<()> {
+        let conn = sqlite::DatabaseConnection::create_connection()
+            .map_err(|e| AppError::Storage(e.to_string()))?;
+
+        let sqlite_project = sqlite::project_repository::Project {
+            project_id: project.project_id as i32,
+            name: project.name.clone(),
+            path_with_namespace: project.path_with_namespace.clone(),
+            web_url: project.web_url.clone(),
+            last_sync_time_utc: Some(chrono::Utc::now().to_rfc3339()),
+        };
+
+        sqlite::ProjectRepository::upsert_project(&conn, &sqlite_project)
+            .map_err(|e| AppError::Storage(e.to_string()))?;
+
+        Ok(())
+    }
+
     /// プロジェクト ID で検索
     pub fn find_by_id(project_id: i64) -> AppResult<Option<Project>> {
         let conn = sqlite::DatabaseConnection::create_connection()
@@ -0,0 +1,135 @@
+//! プロジェクトタグリポジトリ（SQLite ベース）
+//!
+//! 手動タグと、プロジェクト同期時に自動取り込みする名前空間/トピックのタグを保存する。
+
+use crate::domain::{ProjectTag, ProjectTagSource, ProjectTags};
+use crate::error::{AppError, AppResult};
+use crate::storage::sqlite;
+use rusqlite::params;
+use std::collections::BTreeMap;
+
+/// 全プロジェクトのタグを取得（プロジェクト ID 順）
+pub fn list_project_tags() -> AppResult<Vec<ProjectTags>> {
+    let conn = sqlite::DatabaseConnection::create_connection()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    list_project_tags_with_connection(&conn)
+}
+
+/// プロジェクトの手動タグを置き換え、置き換え後のタグ（自動取り込み分を含む）を返す
+pub fn set_manual_project_tags(project_id: i64, tags: &[String]) -> AppResult<Vec<ProjectTag>> {
+    let mut conn = sqlite::DatabaseConnection::create_connection()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    set_manual_project_tags_with_connection(&mut conn, project_id, tags)
+}
+
+/// 自動取り込みのタグ（名前空間/トピック）を置き換える（手動タグは保持）
+pub fn replace_auto_project_tags(tags: &[ProjectTags]) -> AppResult<()> {
+    let mut conn = sqlite::DatabaseConnection::create_connection()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    replace_auto_project_tags_with_connection(&mut conn, tags)
+}
+
+pub(crate) fn list_project_tags_with_connection(
+    conn: &rusqlite::Connection,
+) -> AppResult<Vec<ProjectTags>> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT project_id, tag, source FROM project_tags ORDER BY project_id, source, tag",
+        )
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+            ))
+        })
+        .map_err(|e| AppError::Storage(e.to_string()))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    let mut grouped: BTreeMap<i64, Vec<ProjectTag>> = BTreeMap::new();
+    for (project_id, tag, source) in rows {
+        let source = ProjectTagSource::parse(&source)
+            .ok_or_else(|| AppError::Storage(format!("Invalid project tag source: {}", source)))?;
+        grouped
+            .entry(project_id)
+            .or_default()
+            .push(ProjectTag { tag, source });
+    }
+
+    Ok(grouped
+        .into_iter()
+        .map(|(project_id, tags)| ProjectTags { project_id, tags })
+        .collect())
+}
+
+pub(crate) fn set_manual_project_tags_with_connection(
+    conn: &mut rusqlite::Connection,
+    project_id: i64,
+    tags: &[String],
+) -> AppResult<Vec<ProjectTag>> {
+    let tx = conn
+        .transaction()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    tx.execute(
+        "DELETE FROM project_tags WHERE project_id = ?1 AND source = ?2",
+        params![project_id, ProjectTagSource::Manual.as_str()],
+    )
+    .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    for tag in tags.iter().map(|t| t.trim()).filter(|t| !t.is_empty()) {
+        tx.execute(
+            "INSERT OR IGNORE INTO project_tags (project_id, tag, source) VALUES (?1, ?2, ?3)",
+            params![project_id, tag, ProjectTagSource::Manual.as_str()],
+        )
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+    }
+
+    tx.commit().map_err(|e| AppError::Storage(e.to_string()))?;
+
+    Ok(list_project_tags_with_connection(conn)?
+        .into_iter()
+        .find(|p| p.project_id == project_id)
+        .map(|p| p.tags)
+        .unwrap_or_default())
+}
+
+pub(crate) fn replace_auto_project_tags_with_connection(
+    conn: &mut rusqlite::Connection,
+    tags: &[ProjectTags],
+) -> AppResult<()> {
+    let tx = conn
+        .transaction()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    tx.execute(
+        "DELETE FROM project_tags WHERE source <> ?1",
+        [ProjectTagSource::Manual.as_str()],
+    )
+    .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    for project in tags {
+        for tag in project
+            .tags
+            .iter()
+            .filter(|t| t.source != ProjectTagSource::Manual)
+        {
+            tx.execute(
+                "INSERT OR IGNORE INTO project_tags (project_id, tag, source) VALUES (?1, ?2, ?3)",
+                params![project.project_id, tag.tag, tag.source.as_str()],
+            )
+            .map_err(|e| AppError::Storage(e.to_string()))?;
+        }
+    }
+
+    tx.commit().map_err(|e| AppError::Storage(e.to_string()))?;
+
+    Ok(())
+}
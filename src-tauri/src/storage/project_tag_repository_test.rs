@@ -0,0 +1,70 @@
+//! プロジェクトタグリポジトリのテスト
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::{ProjectTag, ProjectTagSource, ProjectTags};
+    use crate::storage::project_tag_repository::{
+        list_project_tags_with_connection, replace_auto_project_tags_with_connection,
+        set_manual_project_tags_with_connection,
+    };
+    use crate::storage::sqlite::run_migrations;
+    use rusqlite::Connection;
+
+    fn tag(tag: &str, source: ProjectTagSource) -> ProjectTag {
+        ProjectTag {
+            tag: tag.to_string(),
+            source,
+        }
+    }
+
+    #[test]
+    fn test_sync_replaces_auto_tags_and_keeps_manual_tags() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        run_migrations(&conn).unwrap();
+
+        let tags = set_manual_project_tags_with_connection(
+            &mut conn,
+            1,
+            &[" frontend ".to_string(), "".to_string()],
+        )
+        .unwrap();
+        assert_eq!(tags, vec![tag("frontend", ProjectTagSource::Manual)]);
+
+        replace_auto_project_tags_with_connection(
+            &mut conn,
+            &[ProjectTags {
+                project_id: 1,
+                tags: vec![
+                    tag("web/apps", ProjectTagSource::Namespace),
+                    tag("react", ProjectTagSource::Topic),
+                ],
+            }],
+        )
+        .unwrap();
+
+        // 再同期でトピックが外れても手動タグは残る
+        replace_auto_project_tags_with_connection(
+            &mut conn,
+            &[ProjectTags {
+                project_id: 1,
+                tags: vec![tag("web/apps", ProjectTagSource::Namespace)],
+            }],
+        )
+        .unwrap();
+
+        assert_eq!(
+            list_project_tags_with_connection(&conn).unwrap(),
+            vec![ProjectTags {
+                project_id: 1,
+                tags: vec![
+                    tag("frontend", ProjectTagSource::Manual),
+                    tag("web/apps", ProjectTagSource::Namespace),
+                ],
+            }]
+        );
+
+        // 手動タグの置き換えは自動取り込み分に影響しない
+        let tags = set_manual_project_tags_with_connection(&mut conn, 1, &[]).unwrap();
+        assert_eq!(tags, vec![tag("web/apps", ProjectTagSource::Namespace)]);
+    }
+}
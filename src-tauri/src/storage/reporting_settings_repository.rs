@@ -0,0 +1,48 @@
+//! 集計レポート設定リポジトリ（SQLite ベース）
+//!
+//! 集計レポート設定を app_settings に保存する。
+
+use crate::domain::ReportingSettings;
+use crate::error::{AppError, AppResult};
+use crate::storage::settings_repository::{
+    get_setting_with_connection, set_setting_with_connection,
+};
+use crate::storage::sqlite;
+
+/// app_settings のキー
+const REPORTING_SETTINGS_KEY: &str = "reporting_settings";
+
+/// 集計レポート設定を取得（未設定なら既定値）
+pub fn get_reporting_settings() -> AppResult<ReportingSettings> {
+    let conn = sqlite::DatabaseConnection::create_connection()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    get_reporting_settings_with_connection(&conn)
+}
+
+/// 集計レポート設定を検証して保存
+pub fn save_reporting_settings(settings: &ReportingSettings) -> AppResult<()> {
+    let conn = sqlite::DatabaseConnection::create_connection()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    save_reporting_settings_with_connection(&conn, settings)
+}
+
+pub(crate) fn get_reporting_settings_with_connection(
+    conn: &rusqlite::Connection,
+) -> AppResult<ReportingSettings> {
+    Ok(get_setting_with_connection(conn, REPORTING_SETTINGS_KEY)?.unwrap_or_default())
+}
+
+pub(crate) fn save_reporting_settings_with_connection(
+    conn: &rusqlite::Connection,
+    settings: &ReportingSettings,
+) -> AppResult<()> {
+    // 保存前にタイムゾーンを検証し、正規化した名前で保存
+    let tz = settings.tz()?;
+    let settings = ReportingSettings {
+        time_zone: tz.name().to_string(),
+        date_basis: settings.date_basis,
+    };
+    set_setting_with_connection(conn, REPORTING_SETTINGS_KEY, &settings)
+}
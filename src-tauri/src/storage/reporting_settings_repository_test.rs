@@ -0,0 +1,62 @@
+//! 集計レポート設定リポジトリのテスト
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::ReportingSettings;
+    use crate::error::AppError;
+    use crate::storage::reporting_settings_repository::{
+        get_reporting_settings_with_connection, save_reporting_settings_with_connection,
+    };
+    use crate::storage::sqlite::run_migrations;
+    use rusqlite::Connection;
+
+    fn create_test_connection() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        run_migrations(&conn).unwrap();
+        conn
+    }
+
+    #[test]
+    fn test_default_is_utc() {
+        let conn = create_test_connection();
+
+        let settings = get_reporting_settings_with_connection(&conn).unwrap();
+        assert_eq!(settings.time_zone, "UTC");
+    }
+
+    #[test]
+    fn test_save_validates_time_zone() {
+        let conn = create_test_connection();
+
+        save_reporting_settings_with_connection(
+            &conn,
+            &ReportingSettings {
+                time_zone: " Asia/Tokyo ".to_string(),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            get_reporting_settings_with_connection(&conn)
+                .unwrap()
+                .time_zone,
+            "Asia/Tokyo"
+        );
+
+        let result = save_reporting_settings_with_connection(
+            &conn,
+            &ReportingSettings {
+                time_zone: "Tokyo".to_string(),
+                ..Default::default()
+            },
+        );
+        assert!(matches!(result, Err(AppError::Validation(_))));
+        // 不正な値では上書きしない
+        assert_eq!(
+            get_reporting_settings_with_connection(&conn)
+                .unwrap()
+                .time_zone,
+            "Asia/Tokyo"
+        );
+    }
+}
@@ -0,0 +1,66 @@
+//! アプリ設定リポジトリ（SQLite ベース）
+//!
+//! キーごとに JSON で値を保存する汎用の設定ストア。
+
+use crate::error::{AppError, AppResult};
+use crate::storage::sqlite;
+use rusqlite::{params, OptionalExtension};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// 設定値を取得（未設定なら None）
+pub fn get_setting<T: DeserializeOwned>(key: &str) -> AppResult<Option<T>> {
+    let conn = sqlite::DatabaseConnection::create_connection()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    get_setting_with_connection(&conn, key)
+}
+
+/// 設定値を保存（既存値は上書き）
+pub fn set_setting<T: Serialize>(key: &str, value: &T) -> AppResult<()> {
+    let conn = sqlite::DatabaseConnection::create_connection()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    set_setting_with_connection(&conn, key, value)
+}
+
+pub(crate) fn get_setting_with_connection<T: DeserializeOwned>(
+    conn: &rusqlite::Connection,
+    key: &str,
+) -> AppResult<Option<T>> {
+    let value_json: Option<String> = conn
+        .query_row(
+            "SELECT value_json FROM app_settings WHERE key = ?1",
+            params![key],
+            |row| row.get(0),
+        )
+        .optional()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    value_json
+        .map(|json| {
+            serde_json::from_str(&json)
+                .map_err(|e| AppError::Storage(format!("Invalid setting '{}': {}", key, e)))
+        })
+        .transpose()
+}
+
+pub(crate) fn set_setting_with_connection<T: Serialize>(
+    conn: &rusqlite::Connection,
+    key: &str,
+    value: &T,
+) -> AppResult<()> {
+    let value_json = serde_json::to_string(value)
+        .map_err(|e| AppError::Internal(format!("Failed to serialize setting: {}", e)))?;
+
+    conn.execute(
+        "INSERT INTO app_settings (key, value_json, updated_at_utc) VALUES (?1, ?2, ?3)
+         ON CONFLICT(key) DO UPDATE SET
+             value_json = excluded.value_json,
+             updated_at_utc = excluded.updated_at_utc",
+        params![key, value_json, chrono::Utc::now().to_rfc3339()],
+    )
+    .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    Ok(())
+}
@@ -11,11 +11,186 @@ pub struct Commit {
     pub project_id: i32,
     pub branch_name: String,
     pub sha: String,
+    pub message: String,
     pub author_name: String,
     pub author_email: String,
     pub committed_date_utc: String,
     pub additions: i32,
     pub deletions: i32,
+    pub category: String,
+    pub is_bot: bool,
+    /// GitLab が stats を返さなかったか（additions/deletions は 0）
+    pub stats_missing: bool,
+    /// GitLab の日時が解釈できなかったか（committed_date_utc は UNIX エポック）
+    pub date_invalid: bool,
+    /// 作成日時（RFC3339、取得できなかった場合は None）
+    pub authored_date_utc: Option<String>,
+}
+
+/// SELECT 対象の列（`map_row` の列順と一致させる）
+pub const COMMIT_COLUMNS: &str =
+    "project_id, branch_name, sha, message, author_name, author_email, committed_date_utc, additions, deletions, category, is_bot, stats_missing, date_invalid, authored_date_utc";
+
+/// `COMMIT_COLUMNS` の列数（後続の追加列のインデックス）
+pub const COMMIT_COLUMN_COUNT: usize = 14;
+
+/// SELECT 結果の 1 行を Commit に変換（列順は `COMMIT_COLUMNS`）
+pub fn map_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<Commit> {
+    Ok(Commit {
+        project_id: row.get(0)?,
+        branch_name: row.get(1)?,
+        sha: row.get(2)?,
+        message: row.get(3)?,
+        author_name: row.get(4)?,
+        author_email: row.get(5)?,
+        committed_date_utc: row.get(6)?,
+        additions: row.get(7)?,
+        deletions: row.get(8)?,
+        category: row.get(9)?,
+        is_bot: row.get(10)?,
+        stats_missing: row.get(11)?,
+        date_invalid: row.get(12)?,
+        authored_date_utc: row.get(13)?,
+    })
+}
+
+/// コミット絞り込み条件（すべて AND 結合、None は条件なし）
+#[derive(Debug, Clone, Default)]
+pub struct CommitFilter {
+    pub project_id: Option<i32>,
+    pub branch_name: Option<String>,
+    /// ユーザーキー（author_email、空なら author_name）
+    pub user_key: Option<String>,
+    /// 開始日時（RFC3339、含む）
+    pub since_utc: Option<String>,
+    /// 終了日時（RFC3339、含まない）
+    pub until_utc: Option<String>,
+    /// 期間を作成日時（無ければコミット日時）で絞り込むか（false ならコミット日時）
+    pub use_authored_date: bool,
+    /// ボットのコミットを除外するか
+    pub exclude_bots: bool,
+    /// 対象カテゴリ（空の場合は全カテゴリ）
+    pub categories: Vec<String>,
+    /// メッセージの部分一致（大文字小文字を区別しない）
+    pub message_contains: Option<String>,
+    /// 合計行数（追加 + 削除）の下限
+    pub min_total_lines: Option<i64>,
+    /// 作者（メールアドレスまたは名前の完全一致、大文字小文字を区別しない）
+    pub author: Option<String>,
+}
+
+impl CommitFilter {
+    /// WHERE 句とバインド値を生成
+    fn to_where_clause(&self) -> (String, Vec<rusqlite::types::Value>) {
+        use rusqlite::types::Value;
+
+        // 作成日時が無いコミットはコミット日時で振り分ける（集計の find_by_date_range と同じ）
+        let date_column = if self.use_authored_date {
+            "COALESCE(authored_date_utc, committed_date_utc)"
+        } else {
+            "committed_date_utc"
+        };
+        let since_condition = format!("{} >= ?", date_column);
+        let until_condition = format!("{} < ?", date_column);
+        let category_condition = format!(
+            "category IN ({})",
+            vec!["?"; self.categories.len()].join(", ")
+        );
+
+        let mut conditions: Vec<&str> = Vec::new();
+        let mut values = Vec::new();
+
+        if let Some(project_id) = self.project_id {
+            conditions.push("project_id = ?");
+            values.push(Value::Integer(project_id as i64));
+        }
+        if let Some(branch_name) = &self.branch_name {
+            conditions.push("branch_name = ?");
+            values.push(Value::Text(branch_name.clone()));
+        }
+        if let Some(user_key) = &self.user_key {
+            conditions
+                .push("(CASE WHEN author_email <> '' THEN author_email ELSE author_name END) = ?");
+            values.push(Value::Text(user_key.clone()));
+        }
+        if let Some(since) = &self.since_utc {
+            conditions.push(&since_condition);
+            values.push(Value::Text(since.clone()));
+        }
+        if let Some(until) = &self.until_utc {
+            conditions.push(&until_condition);
+            values.push(Value::Text(until.clone()));
+        }
+        if self.exclude_bots {
+            conditions.push("is_bot = 0");
+        }
+        if !self.categories.is_empty() {
+            conditions.push(&category_condition);
+            values.extend(self.categories.iter().cloned().map(Value::Text));
+        }
+        if let Some(keyword) = &self.message_contains {
+            conditions.push("instr(lower(message), lower(?)) > 0");
+            values.push(Value::Text(keyword.clone()));
+        }
+        if let Some(min_total_lines) = self.min_total_lines {
+            conditions.push("(additions + deletions) >= ?");
+            values.push(Value::Integer(min_total_lines));
+        }
+        if let Some(author) = &self.author {
+            conditions.push("(lower(author_email) = lower(?) OR lower(author_name) = lower(?))");
+            values.push(Value::Text(author.clone()));
+            values.push(Value::Text(author.clone()));
+        }
+
+        let clause = if conditions.is_empty() {
+            String::new()
+        } else {
+            format!("WHERE {}", conditions.join(" AND "))
+        };
+
+        (clause, values)
+    }
+}
+
+/// 並び替え列
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CommitSortColumn {
+    #[default]
+    CommittedDate,
+    Additions,
+    Deletions,
+    TotalLines,
+}
+
+impl CommitSortColumn {
+    fn as_sql(&self) -> &'static str {
+        match self {
+            Self::CommittedDate => "committed_date_utc",
+            Self::Additions => "additions",
+            Self::Deletions => "deletions",
+            Self::TotalLines => "(additions + deletions)",
+        }
+    }
+}
+
+/// 全文検索スニペットのハイライト開始マーカー
+pub const SNIPPET_MARK_START: &str = "\u{2}";
+
+/// 全文検索スニペットのハイライト終了マーカー
+pub const SNIPPET_MARK_END: &str = "\u{3}";
+
+/// 全文検索スニペットで一致箇所の前後に残す文字数（LIKE 検索時）
+const LIKE_SNIPPET_CONTEXT_CHARS: usize = 32;
+
+/// trigram 索引を引ける最小の文字数（これより短いクエリは LIKE で部分一致検索する）
+pub const FTS_MIN_QUERY_CHARS: usize = 3;
+
+/// 全文検索のヒット
+#[derive(Debug, Clone)]
+pub struct CommitSearchHit {
+    pub commit: Commit,
+    /// 一致箇所をマーカーで囲んだメッセージ抜粋
+    pub snippet: String,
 }
 
 pub struct CommitRepository;
@@ -25,10 +200,27 @@ impl CommitRepository {
     pub fn save_commits(conn: &mut Connection, commits: Vec<Commit>) -> Result<usize> {
         let tx = conn.transaction().context("Failed to start transaction")?;
 
+        // NOTE: INSERT OR REPLACE は既存行を暗黙に削除し DELETE トリガーが発火しないため、
+        // FTS 索引との同期を保てるよう UPSERT で更新する。
+        // 再収集で stats が欠損した場合は、取得済み（再取得で補完済み）の行数を残す
         let mut stmt = tx.prepare(
-            "INSERT OR REPLACE INTO commits
-             (project_id, branch_name, sha, author_name, author_email, committed_date_utc, additions, deletions)
-             VALUES (?, ?, ?, ?, ?, ?, ?, ?)"
+            "INSERT INTO commits
+             (project_id, branch_name, sha, message, author_name, author_email, committed_date_utc, additions, deletions, category, is_bot, stats_missing, date_invalid, authored_date_utc)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+             ON CONFLICT(project_id, branch_name, sha) DO UPDATE SET
+                 message = excluded.message,
+                 author_name = excluded.author_name,
+                 author_email = excluded.author_email,
+                 committed_date_utc = excluded.committed_date_utc,
+                 additions = CASE WHEN excluded.stats_missing AND NOT commits.stats_missing
+                     THEN commits.additions ELSE excluded.additions END,
+                 deletions = CASE WHEN excluded.stats_missing AND NOT commits.stats_missing
+                     THEN commits.deletions ELSE excluded.deletions END,
+                 category = excluded.category,
+                 is_bot = excluded.is_bot,
+                 stats_missing = excluded.stats_missing AND commits.stats_missing,
+                 date_invalid = excluded.date_invalid,
+                 authored_date_utc = excluded.authored_date_utc"
         ).context("Failed to prepare insert statement")?;
 
         let mut inserted_count = 0;
@@ -39,11 +231,17 @@ impl CommitRepository {
                     commit.project_id,
                     commit.branch_name,
                     commit.sha,
+                    commit.message,
                     commit.author_name,
                     commit.author_email,
                     commit.committed_date_utc,
                     commit.additions,
                     commit.deletions,
+                    commit.category,
+                    commit.is_bot,
+                    commit.stats_missing,
+                    commit.date_invalid,
+                    commit.authored_date_utc,
                 ])
                 .context("Failed to insert commit")?;
 
@@ -62,24 +260,15 @@ impl CommitRepository {
 
     /// プロジェクト内のコミット一覧を取得
     pub fn get_commits_by_project(conn: &Connection, project_id: i32) -> Result<Vec<Commit>> {
-        let mut stmt = conn.prepare(
-            "SELECT project_id, branch_name, sha, author_name, author_email, committed_date_utc, additions, deletions
-             FROM commits WHERE project_id = ? ORDER BY committed_date_utc DESC"
-        ).context("Failed to prepare commits query")?;
+        let mut stmt = conn
+            .prepare(&format!(
+                "SELECT {COMMIT_COLUMNS}
+                 FROM commits WHERE project_id = ? ORDER BY committed_date_utc DESC"
+            ))
+            .context("Failed to prepare commits query")?;
 
         let commits = stmt
-            .query_map(rusqlite::params![project_id], |row| {
-                Ok(Commit {
-                    project_id: row.get(0)?,
-                    branch_name: row.get(1)?,
-                    sha: row.get(2)?,
-                    author_name: row.get(3)?,
-                    author_email: row.get(4)?,
-                    committed_date_utc: row.get(5)?,
-                    additions: row.get(6)?,
-                    deletions: row.get(7)?,
-                })
-            })
+            .query_map(rusqlite::params![project_id], map_row)
             .context("Failed to query commits")?
             .collect::<Result<Vec<_>, _>>()
             .context("Failed to collect commits")?;
@@ -93,24 +282,15 @@ impl CommitRepository {
         project_id: i32,
         branch_name: &str,
     ) -> Result<Vec<Commit>> {
-        let mut stmt = conn.prepare(
-            "SELECT project_id, branch_name, sha, author_name, author_email, committed_date_utc, additions, deletions
-             FROM commits WHERE project_id = ? AND branch_name = ? ORDER BY committed_date_utc DESC"
-        ).context("Failed to prepare commits by branch query")?;
+        let mut stmt = conn
+            .prepare(&format!(
+                "SELECT {COMMIT_COLUMNS}
+                 FROM commits WHERE project_id = ? AND branch_name = ? ORDER BY committed_date_utc DESC"
+            ))
+            .context("Failed to prepare commits by branch query")?;
 
         let commits = stmt
-            .query_map(rusqlite::params![project_id, branch_name], |row| {
-                Ok(Commit {
-                    project_id: row.get(0)?,
-                    branch_name: row.get(1)?,
-                    sha: row.get(2)?,
-                    author_name: row.get(3)?,
-                    author_email: row.get(4)?,
-                    committed_date_utc: row.get(5)?,
-                    additions: row.get(6)?,
-                    deletions: row.get(7)?,
-                })
-            })
+            .query_map(rusqlite::params![project_id, branch_name], map_row)
             .context("Failed to query commits by branch")?
             .collect::<Result<Vec<_>, _>>()
             .context("Failed to collect commits by branch")?;
@@ -125,26 +305,17 @@ impl CommitRepository {
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<Commit>> {
-        let mut stmt = conn.prepare(
-            "SELECT project_id, branch_name, sha, author_name, author_email, committed_date_utc, additions, deletions
-             FROM commits
-             WHERE project_id = ? AND committed_date_utc >= ? AND committed_date_utc < ?
-             ORDER BY committed_date_utc DESC"
-        ).context("Failed to prepare commits by date range query")?;
+        let mut stmt = conn
+            .prepare(&format!(
+                "SELECT {COMMIT_COLUMNS}
+                 FROM commits
+                 WHERE project_id = ? AND committed_date_utc >= ? AND committed_date_utc < ?
+                 ORDER BY committed_date_utc DESC"
+            ))
+            .context("Failed to prepare commits by date range query")?;
 
         let commits = stmt
-            .query_map(rusqlite::params![project_id, start_date, end_date], |row| {
-                Ok(Commit {
-                    project_id: row.get(0)?,
-                    branch_name: row.get(1)?,
-                    sha: row.get(2)?,
-                    author_name: row.get(3)?,
-                    author_email: row.get(4)?,
-                    committed_date_utc: row.get(5)?,
-                    additions: row.get(6)?,
-                    deletions: row.get(7)?,
-                })
-            })
+            .query_map(rusqlite::params![project_id, start_date, end_date], map_row)
             .context("Failed to query commits by date range")?
             .collect::<Result<Vec<_>, _>>()
             .context("Failed to collect commits by date range")?;
@@ -276,6 +447,238 @@ impl CommitRepository {
         Ok(deleted)
     }
 
+    /// 条件に一致するコミットをページ単位で取得
+    pub fn find_commits(
+        conn: &Connection,
+        filter: &CommitFilter,
+        sort: CommitSortColumn,
+        descending: bool,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<Commit>> {
+        let (where_clause, mut values) = filter.to_where_clause();
+        let direction = if descending { "DESC" } else { "ASC" };
+
+        let mut stmt = conn
+            .prepare(&format!(
+                "SELECT {COMMIT_COLUMNS}
+                 FROM commits
+                 {where_clause}
+                 ORDER BY {sort} {direction}, sha {direction}
+                 LIMIT ? OFFSET ?",
+                sort = sort.as_sql(),
+            ))
+            .context("Failed to prepare filtered commits query")?;
+
+        values.push(rusqlite::types::Value::Integer(limit));
+        values.push(rusqlite::types::Value::Integer(offset));
+
+        let commits = stmt
+            .query_map(rusqlite::params_from_iter(values), map_row)
+            .context("Failed to query filtered commits")?
+            .collect::<Result<Vec<_>, _>>()
+            .context("Failed to collect filtered commits")?;
+
+        Ok(commits)
+    }
+
+    /// 条件に一致するコミット件数を取得
+    pub fn count_commits(conn: &Connection, filter: &CommitFilter) -> Result<i64> {
+        let (where_clause, values) = filter.to_where_clause();
+
+        let count: i64 = conn
+            .query_row(
+                &format!("SELECT COUNT(*) FROM commits {where_clause}"),
+                rusqlite::params_from_iter(values),
+                |row| row.get(0),
+            )
+            .context("Failed to count filtered commits")?;
+
+        Ok(count)
+    }
+
+    /// コミットメッセージを全文検索（関連度順、同順位は新しい順）
+    ///
+    /// `query` は FTS5 のクエリ構文（"..." によるフレーズ、AND/OR/NOT）。索引は trigram のため
+    /// 日本語も部分一致で検索できる。3 文字未満のクエリは索引を引けないため、
+    /// メッセージの部分一致（LIKE、新しい順）で検索する。
+    pub fn search_commits(
+        conn: &Connection,
+        query: &str,
+        filter: &CommitFilter,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<CommitSearchHit>> {
+        if is_short_query(query) {
+            return Self::search_commits_like(conn, query, filter, limit, offset);
+        }
+
+        let (where_clause, filter_values) = filter.to_where_clause();
+
+        // trigram のトークンは 1 文字ずつずれるため、抜粋のトークン数は上限の 64 にする
+        let mut stmt = conn
+            .prepare(&format!(
+                "SELECT {COMMIT_COLUMNS}, hits.snippet
+                 FROM (
+                     SELECT rowid AS hit_rowid, rank AS hit_rank,
+                            snippet(commits_fts, 0, ?, ?, '…', 64) AS snippet
+                     FROM commits_fts
+                     WHERE commits_fts MATCH ?
+                 ) AS hits
+                 JOIN commits ON commits.rowid = hits.hit_rowid
+                 {where_clause}
+                 ORDER BY hits.hit_rank, committed_date_utc DESC
+                 LIMIT ? OFFSET ?"
+            ))
+            .context("Failed to prepare commit search query")?;
+
+        let mut values = vec![
+            rusqlite::types::Value::Text(SNIPPET_MARK_START.to_string()),
+            rusqlite::types::Value::Text(SNIPPET_MARK_END.to_string()),
+            rusqlite::types::Value::Text(query.to_string()),
+        ];
+        values.extend(filter_values);
+        values.push(rusqlite::types::Value::Integer(limit));
+        values.push(rusqlite::types::Value::Integer(offset));
+
+        let hits = stmt
+            .query_map(rusqlite::params_from_iter(values), |row| {
+                Ok(CommitSearchHit {
+                    commit: map_row(row)?,
+                    snippet: row.get(COMMIT_COLUMN_COUNT)?,
+                })
+            })
+            .context("Failed to search commits")?
+            .collect::<Result<Vec<_>, _>>()
+            .context("Failed to collect commit search results")?;
+
+        Ok(hits)
+    }
+
+    /// 全文検索の一致件数を取得
+    pub fn count_search_commits(
+        conn: &Connection,
+        query: &str,
+        filter: &CommitFilter,
+    ) -> Result<i64> {
+        if is_short_query(query) {
+            let (where_clause, values) = like_where_clause(query, filter);
+            return conn
+                .query_row(
+                    &format!("SELECT COUNT(*) FROM commits {where_clause}"),
+                    rusqlite::params_from_iter(values),
+                    |row| row.get(0),
+                )
+                .context("Failed to count commit search results");
+        }
+
+        let (where_clause, filter_values) = filter.to_where_clause();
+
+        let mut values = vec![rusqlite::types::Value::Text(query.to_string())];
+        values.extend(filter_values);
+
+        let count: i64 = conn
+            .query_row(
+                &format!(
+                    "SELECT COUNT(*)
+                     FROM (SELECT rowid AS hit_rowid FROM commits_fts WHERE commits_fts MATCH ?) AS hits
+                     JOIN commits ON commits.rowid = hits.hit_rowid
+                     {where_clause}"
+                ),
+                rusqlite::params_from_iter(values),
+                |row| row.get(0),
+            )
+            .context("Failed to count commit search results")?;
+
+        Ok(count)
+    }
+
+    /// 短いクエリをメッセージの部分一致で検索（新しい順）
+    fn search_commits_like(
+        conn: &Connection,
+        query: &str,
+        filter: &CommitFilter,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<CommitSearchHit>> {
+        let (where_clause, mut values) = like_where_clause(query, filter);
+        values.push(rusqlite::types::Value::Integer(limit));
+        values.push(rusqlite::types::Value::Integer(offset));
+
+        let mut stmt = conn
+            .prepare(&format!(
+                "SELECT {COMMIT_COLUMNS}
+                 FROM commits
+                 {where_clause}
+                 ORDER BY committed_date_utc DESC
+                 LIMIT ? OFFSET ?"
+            ))
+            .context("Failed to prepare commit search query")?;
+
+        let hits = stmt
+            .query_map(rusqlite::params_from_iter(values), map_row)
+            .context("Failed to search commits")?
+            .map(|row| {
+                row.map(|commit| CommitSearchHit {
+                    snippet: like_snippet(&commit.message, query),
+                    commit,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .context("Failed to collect commit search results")?;
+
+        Ok(hits)
+    }
+
+    /// stats が欠損しているコミットを (project_id, sha) 単位で取得（新しい順）
+    ///
+    /// 同じコミットが複数ブランチに保存されていても 1 件として返す。
+    pub fn find_stats_missing(
+        conn: &Connection,
+        project_id: Option<i32>,
+        limit: Option<i64>,
+    ) -> Result<Vec<(i32, String)>> {
+        let mut stmt = conn
+            .prepare(
+                "SELECT project_id, sha
+                 FROM commits
+                 WHERE stats_missing = 1 AND (?1 IS NULL OR project_id = ?1)
+                 GROUP BY project_id, sha
+                 ORDER BY MAX(committed_date_utc) DESC
+                 LIMIT ?2",
+            )
+            .context("Failed to prepare stats missing query")?;
+
+        let rows = stmt
+            .query_map(rusqlite::params![project_id, limit.unwrap_or(-1)], |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })
+            .context("Failed to query stats missing commits")?
+            .collect::<rusqlite::Result<Vec<_>>>()
+            .context("Failed to map stats missing commits")?;
+
+        Ok(rows)
+    }
+
+    /// コミットの行数を更新して欠損フラグを外し、更新行数（ブランチ数）を返す
+    pub fn update_commit_stats(
+        conn: &Connection,
+        project_id: i32,
+        sha: &str,
+        additions: i32,
+        deletions: i32,
+    ) -> Result<usize> {
+        let updated = conn
+            .execute(
+                "UPDATE commits SET additions = ?, deletions = ?, stats_missing = 0
+                 WHERE project_id = ? AND sha = ?",
+                rusqlite::params![additions, deletions, project_id, sha],
+            )
+            .context("Failed to update commit stats")?;
+
+        Ok(updated)
+    }
+
     /// 単一コミットの取得
     pub fn get_commit(
         conn: &Connection,
@@ -283,23 +686,14 @@ impl CommitRepository {
         branch_name: &str,
         sha: &str,
     ) -> Result<Option<Commit>> {
-        let mut stmt = conn.prepare(
-            "SELECT project_id, branch_name, sha, author_name, author_email, committed_date_utc, additions, deletions
-             FROM commits WHERE project_id = ? AND branch_name = ? AND sha = ?"
-        ).context("Failed to prepare commit query")?;
-
-        let result = stmt.query_row(rusqlite::params![project_id, branch_name, sha], |row| {
-            Ok(Commit {
-                project_id: row.get(0)?,
-                branch_name: row.get(1)?,
-                sha: row.get(2)?,
-                author_name: row.get(3)?,
-                author_email: row.get(4)?,
-                committed_date_utc: row.get(5)?,
-                additions: row.get(6)?,
-                deletions: row.get(7)?,
-            })
-        });
+        let mut stmt = conn
+            .prepare(&format!(
+                "SELECT {COMMIT_COLUMNS}
+                 FROM commits WHERE project_id = ? AND branch_name = ? AND sha = ?"
+            ))
+            .context("Failed to prepare commit query")?;
+
+        let result = stmt.query_row(rusqlite::params![project_id, branch_name, sha], map_row);
 
         match result {
             Ok(commit) => Ok(Some(commit)),
@@ -309,6 +703,61 @@ impl CommitRepository {
     }
 }
 
+fn is_short_query(query: &str) -> bool {
+    query.chars().count() < FTS_MIN_QUERY_CHARS
+}
+
+/// 絞り込み条件にメッセージの部分一致（LIKE）を加えた WHERE 句とバインド値を生成
+fn like_where_clause(query: &str, filter: &CommitFilter) -> (String, Vec<rusqlite::types::Value>) {
+    let (where_clause, mut values) = filter.to_where_clause();
+    let condition = "message LIKE ? ESCAPE '\\'";
+    let where_clause = if where_clause.is_empty() {
+        format!("WHERE {condition}")
+    } else {
+        format!("{where_clause} AND {condition}")
+    };
+
+    let escaped = query
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    values.push(rusqlite::types::Value::Text(format!("%{escaped}%")));
+
+    (where_clause, values)
+}
+
+/// 最初の一致箇所をマーカーで囲み、前後を切り詰めた抜粋を作る（LIKE と同じく ASCII のみ大文字小文字を区別しない）
+fn like_snippet(message: &str, query: &str) -> String {
+    let chars: Vec<char> = message.chars().collect();
+    let needle: Vec<char> = query.chars().collect();
+    let Some(hit) = (0..chars.len())
+        .filter(|&i| i + needle.len() <= chars.len())
+        .find(|&i| {
+            chars[i..i + needle.len()]
+                .iter()
+                .zip(&needle)
+                .all(|(a, b)| a.eq_ignore_ascii_case(b))
+        })
+    else {
+        return message.to_string();
+    };
+
+    let start = hit.saturating_sub(LIKE_SNIPPET_CONTEXT_CHARS);
+    let end = (hit + needle.len() + LIKE_SNIPPET_CONTEXT_CHARS).min(chars.len());
+    let text = |range: std::ops::Range<usize>| chars[range].iter().collect::<String>();
+
+    format!(
+        "{}{}{}{}{}{}{}",
+        if start > 0 { "…" } else { "" },
+        text(start..hit),
+        SNIPPET_MARK_START,
+        text(hit..hit + needle.len()),
+        SNIPPET_MARK_END,
+        text(hit + needle.len()..end),
+        if end < chars.len() { "…" } else { "" },
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -325,11 +774,17 @@ mod tests {
                 project_id INTEGER NOT NULL,
                 branch_name TEXT NOT NULL,
                 sha TEXT NOT NULL,
+                message TEXT NOT NULL DEFAULT '',
                 author_name TEXT NOT NULL,
                 author_email TEXT NOT NULL,
                 committed_date_utc TEXT NOT NULL,
                 additions INTEGER NOT NULL,
                 deletions INTEGER NOT NULL,
+                category TEXT NOT NULL DEFAULT 'other',
+                is_bot INTEGER NOT NULL DEFAULT 0,
+                stats_missing INTEGER NOT NULL DEFAULT 0,
+                date_invalid INTEGER NOT NULL DEFAULT 0,
+                authored_date_utc TEXT,
                 PRIMARY KEY (project_id, branch_name, sha)
             )",
             [],
@@ -347,11 +802,17 @@ mod tests {
             project_id: 1,
             branch_name: "main".to_string(),
             sha: "abc123".to_string(),
+            message: "test".to_string(),
             author_name: "Test Author".to_string(),
             author_email: "test@example.com".to_string(),
             committed_date_utc: "2024-01-01T00:00:00Z".to_string(),
             additions: 10,
             deletions: 5,
+            category: "other".to_string(),
+            is_bot: false,
+            stats_missing: false,
+            date_invalid: false,
+            authored_date_utc: None,
         }];
 
         let result = CommitRepository::save_commits(&mut conn, commits);
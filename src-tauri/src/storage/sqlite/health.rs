@@ -1,29 +1,51 @@
 /// SQLite データベース健全性チェック
 ///
-/// - DB ファイルサイズの監視
+/// - DB ファイルサイズ（WAL を含む）の監視
 /// - 容量警告の実装
+/// - テーブルごとの行数
 /// - インテグリティチェック
+/// - VACUUM / ANALYZE と保持期間を過ぎたコミットの削除
 use anyhow::{Context, Result};
+use chrono::SecondsFormat;
 use rusqlite::Connection;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 pub const DB_SIZE_WARNING_THRESHOLD_MB: f64 = 500.0;
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct DatabaseHealth {
     pub file_size_mb: f64,
+    /// WAL ファイルのサイズ（MB、WAL モードでない場合は 0）
+    pub wal_size_mb: f64,
     pub estimated_commit_count: i32,
+    /// テーブルごとの行数
+    pub table_row_counts: Vec<TableRowCount>,
     pub is_integrity_ok: bool,
+    /// 最後に VACUUM した日時（記録は app_settings にあるため呼び出し側で設定）
+    pub last_vacuum_at_utc: Option<String>,
+    /// 最後に ANALYZE した日時（同上）
+    pub last_analyze_at_utc: Option<String>,
     pub warning: Option<String>,
 }
 
+/// テーブルの行数
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableRowCount {
+    pub name: String,
+    pub row_count: i64,
+}
+
 pub struct DatabaseHealthChecker;
 
 impl DatabaseHealthChecker {
     /// データベースの健全性をチェック
     pub fn check_database_health(conn: &Connection, db_path: &Path) -> Result<DatabaseHealth> {
         let file_size_mb = Self::get_db_file_size(db_path)?;
+        let wal_size_mb = Self::get_wal_file_size(db_path);
         let estimated_commit_count = Self::estimate_commit_count(conn)?;
+        let table_row_counts = Self::count_table_rows(conn)?;
         let is_integrity_ok = Self::check_integrity(conn)?;
 
         let warning = if file_size_mb > DB_SIZE_WARNING_THRESHOLD_MB {
@@ -38,8 +60,12 @@ impl DatabaseHealthChecker {
 
         Ok(DatabaseHealth {
             file_size_mb,
+            wal_size_mb,
             estimated_commit_count,
+            table_row_counts,
             is_integrity_ok,
+            last_vacuum_at_utc: None,
+            last_analyze_at_utc: None,
             warning,
         })
     }
@@ -55,6 +81,49 @@ impl DatabaseHealthChecker {
         Ok(size_mb)
     }
 
+    /// データベースファイルと WAL ファイルの合計サイズ（MB 単位）
+    pub fn total_size_mb(db_path: &Path) -> f64 {
+        Self::get_db_file_size(db_path).unwrap_or(0.0) + Self::get_wal_file_size(db_path)
+    }
+
+    /// WAL ファイルサイズを取得（MB 単位、存在しない場合は 0）
+    fn get_wal_file_size(db_path: &Path) -> f64 {
+        let mut wal_path = PathBuf::from(db_path).into_os_string();
+        wal_path.push("-wal");
+
+        std::fs::metadata(wal_path)
+            .map(|metadata| metadata.len() as f64 / (1024.0 * 1024.0))
+            .unwrap_or(0.0)
+    }
+
+    /// テーブルごとの行数（SQLite の内部テーブルと全文検索のシャドウテーブルは除く）
+    fn count_table_rows(conn: &Connection) -> Result<Vec<TableRowCount>> {
+        let mut stmt = conn
+            .prepare(
+                "SELECT name FROM pragma_table_list
+                 WHERE schema = 'main' AND type = 'table' AND name NOT LIKE 'sqlite_%'
+                 ORDER BY name",
+            )
+            .context("Failed to prepare table list query")?;
+        let names = stmt
+            .query_map([], |row| row.get::<_, String>(0))
+            .context("Failed to query table list")?
+            .collect::<Result<Vec<_>, _>>()
+            .context("Failed to collect table list")?;
+
+        names
+            .into_iter()
+            .map(|name| {
+                let row_count = conn
+                    .query_row(&format!("SELECT COUNT(*) FROM \"{}\"", name), [], |row| {
+                        row.get(0)
+                    })
+                    .with_context(|| format!("Failed to count rows of {}", name))?;
+                Ok(TableRowCount { name, row_count })
+            })
+            .collect()
+    }
+
     /// コミット数の推定
     fn estimate_commit_count(conn: &Connection) -> Result<i32> {
         let count: i32 = conn
@@ -82,35 +151,74 @@ impl DatabaseHealthChecker {
     }
 
     /// データベースの VACUUM（最適化）
+    ///
+    /// commits は rowid の別名を持たないため VACUUM で rowid が振り直されることがある。
+    /// rowid で対応付けている全文検索インデックス（commits_fts）を作り直す。
     pub fn vacuum_database(conn: &Connection) -> Result<()> {
         conn.execute("VACUUM", [])
             .context("Failed to vacuum database")?;
+        conn.execute("INSERT INTO commits_fts(commits_fts) VALUES('rebuild')", [])
+            .context("Failed to rebuild commits_fts")?;
+        // WAL に残った変更をデータベースファイルに反映して切り詰める
+        conn.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |_| Ok(()))
+            .context("Failed to checkpoint WAL")?;
 
         tracing::info!("Database vacuumed successfully");
         Ok(())
     }
 
-    /// 古いコミットを削除（日数指定）
-    pub fn delete_old_commits(conn: &Connection, days_to_keep: i32) -> Result<usize> {
-        let cutoff_date = format!(
-            "{}",
-            (chrono::Utc::now() - chrono::Duration::days(days_to_keep as i64))
-                .format("%Y-%m-%dT%H:%M:%SZ")
-        );
+    /// クエリプランナー用の統計情報を更新
+    pub fn analyze_database(conn: &Connection) -> Result<()> {
+        conn.execute("ANALYZE", [])
+            .context("Failed to analyze database")?;
 
+        tracing::info!("Database analyzed successfully");
+        Ok(())
+    }
+
+    /// 保持日数から削除の基準日時を求める
+    pub fn retention_cutoff(days_to_keep: i32) -> String {
+        chrono::Utc::now()
+            .checked_sub_signed(chrono::Duration::days(days_to_keep as i64))
+            .unwrap_or(chrono::DateTime::<chrono::Utc>::MIN_UTC)
+            .to_rfc3339_opts(SecondsFormat::Secs, false)
+    }
+
+    /// 基準日時より古いコミットの件数（日時不正のコミットは対象外）
+    pub fn count_commits_before(conn: &Connection, cutoff_date: &str) -> Result<usize> {
+        let count: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM commits WHERE committed_date_utc < ?1 AND date_invalid = 0",
+                rusqlite::params![cutoff_date],
+                |row| row.get(0),
+            )
+            .context("Failed to count old commits")?;
+
+        Ok(count as usize)
+    }
+
+    /// 基準日時より古いコミットを削除（日時不正のコミットは対象外）
+    pub fn delete_commits_before(conn: &Connection, cutoff_date: &str) -> Result<usize> {
         let deleted = conn
             .execute(
-                "DELETE FROM commits WHERE committed_date_utc < ?",
+                "DELETE FROM commits WHERE committed_date_utc < ?1 AND date_invalid = 0",
                 rusqlite::params![cutoff_date],
             )
             .context("Failed to delete old commits")?;
 
+        Ok(deleted)
+    }
+
+    /// 古いコミットを削除（日数指定）
+    pub fn delete_old_commits(conn: &Connection, days_to_keep: i32) -> Result<usize> {
+        let deleted = Self::delete_commits_before(conn, &Self::retention_cutoff(days_to_keep))?;
+
         tracing::info!(
             "Deleted {} old commits (older than {} days)",
             deleted,
             days_to_keep
         );
-        Ok(deleted as usize)
+        Ok(deleted)
     }
 }
 
@@ -121,9 +229,9 @@ pub fn check_database_health(conn: &Connection, db_path: &Path) -> Result<Databa
 #[cfg(test)]
 mod tests {
     use super::*;
-    use tempfile::tempdir;
+    use tempfile::{tempdir, TempDir};
 
-    fn create_test_connection() -> (rusqlite::Connection, std::path::PathBuf) {
+    fn create_test_connection() -> (rusqlite::Connection, std::path::PathBuf, TempDir) {
         let dir = tempdir().unwrap();
         let db_path = dir.path().join("test.db");
         let conn = rusqlite::Connection::open(&db_path).unwrap();
@@ -145,17 +253,21 @@ mod tests {
         )
         .unwrap();
 
-        (conn, db_path)
+        // TempDir を返してテスト中はディレクトリを残す
+        (conn, db_path, dir)
     }
 
     #[test]
     fn test_check_database_health() {
-        let (conn, db_path) = create_test_connection();
+        let (conn, db_path, _dir) = create_test_connection();
 
         let health = DatabaseHealthChecker::check_database_health(&conn, &db_path).unwrap();
 
         assert!(health.file_size_mb > 0.0);
+        assert_eq!(health.wal_size_mb, 0.0);
         assert_eq!(health.estimated_commit_count, 0);
+        assert_eq!(health.table_row_counts.len(), 1);
+        assert_eq!(health.table_row_counts[0].name, "commits");
         assert!(health.is_integrity_ok);
         assert!(health.warning.is_none()); // サイズが小さいので警告なし
     }
@@ -180,9 +180,15 @@ impl JsonMigration {
         let deletions = data.get("deletions").and_then(|v| v.as_i64()).unwrap_or(0) as i32;
 
         conn.execute(
-            "INSERT OR REPLACE INTO commits
+            "INSERT INTO commits
              (project_id, branch_name, sha, author_name, author_email, committed_date_utc, additions, deletions)
-             VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+             ON CONFLICT(project_id, branch_name, sha) DO UPDATE SET
+                 author_name = excluded.author_name,
+                 author_email = excluded.author_email,
+                 committed_date_utc = excluded.committed_date_utc,
+                 additions = excluded.additions,
+                 deletions = excluded.deletions",
             rusqlite::params![
                 project_id, branch_name, sha, author_name, author_email, committed_date_utc, additions, deletions
             ],
@@ -26,10 +26,77 @@ pub fn run_migrations(conn: &Connection) -> Result<()> {
         record_migration(conn, 6)?;
     }
 
+    if !applied_versions.contains(&7) {
+        apply_commit_message_schema(conn)?;
+        record_migration(conn, 7)?;
+    }
+
+    if !applied_versions.contains(&8) {
+        apply_commit_message_fts_schema(conn)?;
+        record_migration(conn, 8)?;
+    }
+
+    if !applied_versions.contains(&9) {
+        apply_commit_category_schema(conn)?;
+        record_migration(conn, 9)?;
+    }
+
+    if !applied_versions.contains(&10) {
+        apply_bot_detection_schema(conn)?;
+        record_migration(conn, 10)?;
+    }
+
+    if !applied_versions.contains(&11) {
+        apply_teams_schema(conn)?;
+        record_migration(conn, 11)?;
+    }
+
+    if !applied_versions.contains(&12) {
+        apply_project_tags_schema(conn)?;
+        record_migration(conn, 12)?;
+    }
+
+    if !applied_versions.contains(&13) {
+        apply_alerts_schema(conn)?;
+        record_migration(conn, 13)?;
+    }
+
+    if !applied_versions.contains(&14) {
+        apply_commit_stats_missing_schema(conn)?;
+        record_migration(conn, 14)?;
+    }
+
+    if !applied_versions.contains(&15) {
+        apply_commit_date_invalid_schema(conn)?;
+        record_migration(conn, 15)?;
+    }
+
+    if !applied_versions.contains(&16) {
+        apply_commit_authored_date_schema(conn)?;
+        record_migration(conn, 16)?;
+    }
+
     tracing::info!("Migrations completed successfully");
     Ok(())
 }
 
+/// 適用済みの最新スキーマバージョンを取得（未初期化の場合は 0）
+pub fn current_schema_version(conn: &Connection) -> Result<u32> {
+    create_schema_migrations_table(conn)?;
+    let version: Option<u32> = conn
+        .query_row("SELECT MAX(version) FROM schema_migrations", [], |row| {
+            row.get(0)
+        })
+        .context("Failed to query schema version")?;
+    Ok(version.unwrap_or(0))
+}
+
+/// 未適用のマイグレーションがある既存データベースか（新規作成時は false）
+pub fn has_pending_migrations(conn: &Connection) -> Result<bool> {
+    let version = current_schema_version(conn)?;
+    Ok(version > 0 && version < crate::storage::CURRENT_SCHEMA_VERSION)
+}
+
 /// schema_migrations テーブルを作成
 fn create_schema_migrations_table(conn: &Connection) -> Result<()> {
     conn.execute(
@@ -123,6 +190,66 @@ fn apply_bulk_collection_schema(conn: &Connection) -> Result<()> {
     Ok(())
 }
 
+fn apply_commit_message_schema(conn: &Connection) -> Result<()> {
+    conn.execute_batch(include_str!("migrations/007_commit_message.sql"))
+        .context("Failed to execute commit message schema")?;
+    Ok(())
+}
+
+fn apply_commit_message_fts_schema(conn: &Connection) -> Result<()> {
+    conn.execute_batch(include_str!("migrations/008_commit_message_fts.sql"))
+        .context("Failed to execute commit message FTS schema")?;
+    Ok(())
+}
+
+fn apply_commit_category_schema(conn: &Connection) -> Result<()> {
+    conn.execute_batch(include_str!("migrations/009_commit_category.sql"))
+        .context("Failed to execute commit category schema")?;
+    Ok(())
+}
+
+fn apply_bot_detection_schema(conn: &Connection) -> Result<()> {
+    conn.execute_batch(include_str!("migrations/010_bot_detection.sql"))
+        .context("Failed to execute bot detection schema")?;
+    Ok(())
+}
+
+fn apply_teams_schema(conn: &Connection) -> Result<()> {
+    conn.execute_batch(include_str!("migrations/011_teams.sql"))
+        .context("Failed to execute teams schema")?;
+    Ok(())
+}
+
+fn apply_project_tags_schema(conn: &Connection) -> Result<()> {
+    conn.execute_batch(include_str!("migrations/012_project_tags.sql"))
+        .context("Failed to execute project tags schema")?;
+    Ok(())
+}
+
+fn apply_alerts_schema(conn: &Connection) -> Result<()> {
+    conn.execute_batch(include_str!("migrations/013_alerts.sql"))
+        .context("Failed to execute alerts schema")?;
+    Ok(())
+}
+
+fn apply_commit_stats_missing_schema(conn: &Connection) -> Result<()> {
+    conn.execute_batch(include_str!("migrations/014_commit_stats_missing.sql"))
+        .context("Failed to execute commit stats missing schema")?;
+    Ok(())
+}
+
+fn apply_commit_date_invalid_schema(conn: &Connection) -> Result<()> {
+    conn.execute_batch(include_str!("migrations/015_commit_date_invalid.sql"))
+        .context("Failed to execute commit date invalid schema")?;
+    Ok(())
+}
+
+fn apply_commit_authored_date_schema(conn: &Connection) -> Result<()> {
+    conn.execute_batch(include_str!("migrations/016_commit_authored_date.sql"))
+        .context("Failed to execute commit authored date schema")?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -146,4 +273,33 @@ mod tests {
 
         assert!(exists);
     }
+
+    #[test]
+    fn test_current_schema_version_matches_latest_migration() {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        assert_eq!(current_schema_version(&conn).unwrap(), 0);
+
+        run_migrations(&conn).unwrap();
+        assert_eq!(
+            current_schema_version(&conn).unwrap(),
+            crate::storage::CURRENT_SCHEMA_VERSION
+        );
+    }
+
+    #[test]
+    fn test_has_pending_migrations() {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        // 新規データベースはスナップショット不要
+        assert!(!has_pending_migrations(&conn).unwrap());
+
+        run_migrations(&conn).unwrap();
+        assert!(!has_pending_migrations(&conn).unwrap());
+
+        conn.execute(
+            "DELETE FROM schema_migrations WHERE version = ?1",
+            [crate::storage::CURRENT_SCHEMA_VERSION],
+        )
+        .unwrap();
+        assert!(has_pending_migrations(&conn).unwrap());
+    }
 }
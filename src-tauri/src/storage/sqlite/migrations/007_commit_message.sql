@@ -0,0 +1,6 @@
+-- SQLite schema migration: commit message (version 7)
+--
+-- ドリルダウン表示と検索のためにコミットメッセージを保存する。
+-- 既存行は空文字（再収集時に上書きされる）。
+
+ALTER TABLE commits ADD COLUMN message TEXT NOT NULL DEFAULT '';
@@ -0,0 +1,30 @@
+-- SQLite schema migration: commit message full-text search (version 8)
+--
+-- commits.message を外部コンテンツとする FTS5 インデックス。
+-- commits への INSERT / DELETE / message の UPDATE をトリガーで同期する
+-- （delete_by_branch やプロジェクト削除時の CASCADE も DELETE トリガーで反映される）。
+-- 日本語など空白で単語を区切らないメッセージも検索できるよう、3 文字単位で索引する trigram トークナイザを使う。
+-- 3 文字未満のクエリは索引を引けないため、検索時に LIKE で部分一致検索する。
+
+CREATE VIRTUAL TABLE IF NOT EXISTS commits_fts USING fts5(
+    message,
+    content='commits',
+    content_rowid='rowid',
+    tokenize='trigram'
+);
+
+CREATE TRIGGER IF NOT EXISTS commits_fts_after_insert AFTER INSERT ON commits BEGIN
+    INSERT INTO commits_fts(rowid, message) VALUES (new.rowid, new.message);
+END;
+
+CREATE TRIGGER IF NOT EXISTS commits_fts_after_delete AFTER DELETE ON commits BEGIN
+    INSERT INTO commits_fts(commits_fts, rowid, message) VALUES ('delete', old.rowid, old.message);
+END;
+
+CREATE TRIGGER IF NOT EXISTS commits_fts_after_update AFTER UPDATE OF message ON commits BEGIN
+    INSERT INTO commits_fts(commits_fts, rowid, message) VALUES ('delete', old.rowid, old.message);
+    INSERT INTO commits_fts(rowid, message) VALUES (new.rowid, new.message);
+END;
+
+-- 既存コミットを索引に取り込む
+INSERT INTO commits_fts(commits_fts) VALUES ('rebuild');
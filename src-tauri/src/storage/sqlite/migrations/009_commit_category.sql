@@ -0,0 +1,17 @@
+-- SQLite schema migration: commit category (version 9)
+--
+-- コミットの分類（Conventional Commits / ユーザー定義ルール）を保存する。
+
+ALTER TABLE commits ADD COLUMN category TEXT NOT NULL DEFAULT 'other';
+
+-- ユーザー定義の分類ルール（position 昇順で評価）
+CREATE TABLE IF NOT EXISTS category_rules (
+    position INTEGER PRIMARY KEY,
+    category TEXT NOT NULL,
+    target TEXT NOT NULL CHECK(target IN ('message', 'branch')),
+    pattern TEXT NOT NULL,
+    updated_at_utc TEXT NOT NULL
+);
+
+-- 既存コミットの分類は、収集時と同じ判定（conventional_category）になるよう
+-- マイグレーション後に storage::migrate_with_connection で行う
@@ -0,0 +1,20 @@
+-- SQLite schema migration: bot detection (version 10)
+--
+-- コミット単位のボット判定結果と、判定設定・GitLab で bot と判定されたユーザーを保存する。
+
+ALTER TABLE commits ADD COLUMN is_bot INTEGER NOT NULL DEFAULT 0;
+
+-- アプリ設定（キーごとに JSON で保存）
+CREATE TABLE IF NOT EXISTS app_settings (
+    key TEXT PRIMARY KEY,
+    value_json TEXT NOT NULL,
+    updated_at_utc TEXT NOT NULL
+);
+
+-- GitLab の users API で bot フラグが立っていたユーザー
+CREATE TABLE IF NOT EXISTS gitlab_bot_users (
+    user_key TEXT PRIMARY KEY,
+    gitlab_user_id INTEGER NOT NULL,
+    username TEXT NOT NULL,
+    detected_at_utc TEXT NOT NULL
+);
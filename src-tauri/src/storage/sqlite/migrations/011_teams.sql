@@ -0,0 +1,23 @@
+-- SQLite schema migration: teams (version 11)
+--
+-- ユーザーキーをまとめたチームと、期間付きのメンバー所属を保存する。
+
+CREATE TABLE IF NOT EXISTS teams (
+    team_id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL UNIQUE,
+    created_at_utc TEXT NOT NULL,
+    updated_at_utc TEXT NOT NULL
+);
+
+-- 所属期間（valid_from / valid_until は YYYY-MM-DD、両端を含む。NULL は無期限）
+-- 同じユーザーが同じチームに複数期間所属できるよう、user_key は一意にしない
+CREATE TABLE IF NOT EXISTS team_members (
+    team_id INTEGER NOT NULL REFERENCES teams(team_id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    user_key TEXT NOT NULL,
+    valid_from TEXT,
+    valid_until TEXT,
+    PRIMARY KEY (team_id, position)
+);
+
+CREATE INDEX IF NOT EXISTS idx_team_members_user_key ON team_members(user_key);
@@ -0,0 +1,13 @@
+-- SQLite schema migration: project tags (version 12)
+--
+-- 横断ビューの絞り込みに使うプロジェクトタグ。
+-- プロジェクト同期（projects の置き換え）で手動タグが消えないよう、projects への外部キーは張らない。
+
+CREATE TABLE IF NOT EXISTS project_tags (
+    project_id INTEGER NOT NULL,
+    tag TEXT NOT NULL,
+    source TEXT NOT NULL CHECK (source IN ('manual', 'namespace', 'topic')),
+    PRIMARY KEY (project_id, source, tag)
+);
+
+CREATE INDEX IF NOT EXISTS idx_project_tags_tag ON project_tags(tag COLLATE NOCASE);
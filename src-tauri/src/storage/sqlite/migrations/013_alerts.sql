@@ -0,0 +1,22 @@
+-- SQLite schema migration: alerts (version 13)
+--
+-- 一括収集後に評価したアラートルールの発火履歴。
+-- 同じルール・同じ対象（fingerprint）のアラートは 1 回だけ記録する。
+
+CREATE TABLE IF NOT EXISTS alerts (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    rule_name TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    fingerprint TEXT NOT NULL,
+    subject_key TEXT NOT NULL,
+    subject_name TEXT NOT NULL,
+    period TEXT NOT NULL,
+    value REAL NOT NULL,
+    message TEXT NOT NULL,
+    run_id TEXT,
+    triggered_at_utc TEXT NOT NULL,
+    acknowledged_at_utc TEXT,
+    UNIQUE (rule_name, fingerprint)
+);
+
+CREATE INDEX IF NOT EXISTS idx_alerts_acknowledged ON alerts(acknowledged_at_utc, triggered_at_utc);
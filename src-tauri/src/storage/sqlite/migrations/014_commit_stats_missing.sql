@@ -0,0 +1,11 @@
+-- SQLite schema migration: commit stats missing flag (version 14)
+--
+-- GitLab が stats を返さなかったコミット（行数 0 で保存）を記録し、個別に再取得できるようにする。
+-- 既存の 0 行コミットは欠損か本当に空かを区別できないため、再取得の対象としてフラグを立てておく。
+
+ALTER TABLE commits ADD COLUMN stats_missing INTEGER NOT NULL DEFAULT 0;
+
+UPDATE commits SET stats_missing = 1 WHERE additions = 0 AND deletions = 0;
+
+CREATE INDEX IF NOT EXISTS idx_commits_stats_missing
+    ON commits(project_id, sha) WHERE stats_missing = 1;
@@ -0,0 +1,6 @@
+-- SQLite schema migration: commit date invalid flag (version 15)
+--
+-- GitLab の日時が解釈できなかったコミットを記録する（committed_date_utc は UNIX エポック）。
+-- 以前は収集時刻で補っていたため、既存データは判別できず 0 のままとする。
+
+ALTER TABLE commits ADD COLUMN date_invalid INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,9 @@
+-- SQLite schema migration: commit authored date (version 16)
+--
+-- GitLab の authored_date を committed_date とは別に保持する。
+-- 集計時にどちらの日時で期間に振り分けるかを選べるようにするため。
+-- 既存データは取得していないため NULL のままとし、集計ではコミット日時で代用する。
+
+ALTER TABLE commits ADD COLUMN authored_date_utc TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_commits_authored_date ON commits(authored_date_utc);
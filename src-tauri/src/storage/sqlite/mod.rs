@@ -13,7 +13,7 @@ pub mod user_filter_repository;
 pub use commit_repository::CommitRepository;
 pub use connection_repository::ConnectionRepository;
 pub use db::DatabaseConnection;
-pub use health::check_database_health;
-pub use migrations::run_migrations;
+pub use health::{check_database_health, DatabaseHealth};
+pub use migrations::{current_schema_version, has_pending_migrations, run_migrations};
 pub use project_repository::ProjectRepository;
 pub use user_filter_repository::UserFilterRepository;
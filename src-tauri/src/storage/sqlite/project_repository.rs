@@ -108,6 +108,30 @@ impl ProjectRepository {
         }
     }
 
+    /// プロジェクトを挿入または更新（既存のコミットは削除しない）
+    pub fn upsert_project(conn: &Connection, project: &Project) -> Result<()> {
+        conn.execute(
+            "INSERT INTO projects (project_id, name, path_with_namespace, web_url, last_sync_time_utc)
+             VALUES (?, ?, ?, ?, ?)
+             ON CONFLICT(project_id) DO UPDATE SET
+                 name = excluded.name,
+                 path_with_namespace = excluded.path_with_namespace,
+                 web_url = excluded.web_url,
+                 last_sync_time_utc = excluded.last_sync_time_utc",
+            rusqlite::params![
+                project.project_id,
+                project.name,
+                project.path_with_namespace,
+                project.web_url,
+                project.last_sync_time_utc,
+            ],
+        )
+        .context("Failed to upsert project")?;
+
+        tracing::debug!("Upserted project: {}", project.project_id);
+        Ok(())
+    }
+
     /// プロジェクトの同期時刻を更新
     pub fn update_last_sync_time(
         conn: &Connection,
@@ -29,21 +29,33 @@ mod tests {
                 project_id: 1,
                 branch_name: "main".to_string(),
                 sha: "abc123".to_string(),
+                message: "test".to_string(),
                 author_name: "Author One".to_string(),
                 author_email: "author1@example.com".to_string(),
                 committed_date_utc: "2024-01-01T10:00:00Z".to_string(),
                 additions: 50,
                 deletions: 20,
+                category: "other".to_string(),
+                is_bot: false,
+                stats_missing: false,
+                date_invalid: false,
+                authored_date_utc: None,
             },
             Commit {
                 project_id: 1,
                 branch_name: "main".to_string(),
                 sha: "def456".to_string(),
+                message: "test".to_string(),
                 author_name: "Author Two".to_string(),
                 author_email: "author2@example.com".to_string(),
                 committed_date_utc: "2024-01-02T14:30:00Z".to_string(),
                 additions: 100,
                 deletions: 50,
+                category: "other".to_string(),
+                is_bot: false,
+                stats_missing: false,
+                date_invalid: false,
+                authored_date_utc: None,
             },
         ];
 
@@ -64,11 +76,17 @@ mod tests {
                 project_id: 1,
                 branch_name: "main".to_string(),
                 sha: "abc123".to_string(),
+                message: "test".to_string(),
                 author_name: "Test".to_string(),
                 author_email: "test@example.com".to_string(),
                 committed_date_utc: "2024-01-01T00:00:00Z".to_string(),
                 additions: 10,
                 deletions: 5,
+                category: "other".to_string(),
+                is_bot: false,
+                stats_missing: false,
+                date_invalid: false,
+                authored_date_utc: None,
             },
         ];
 
@@ -91,21 +109,33 @@ mod tests {
                 project_id: 1,
                 branch_name: "main".to_string(),
                 sha: "abc123".to_string(),
+                message: "test".to_string(),
                 author_name: "Test".to_string(),
                 author_email: "test@example.com".to_string(),
                 committed_date_utc: "2024-01-01T00:00:00Z".to_string(),
                 additions: 10,
                 deletions: 5,
+                category: "other".to_string(),
+                is_bot: false,
+                stats_missing: false,
+                date_invalid: false,
+                authored_date_utc: None,
             },
             Commit {
                 project_id: 1,
                 branch_name: "develop".to_string(),
                 sha: "def456".to_string(),
+                message: "test".to_string(),
                 author_name: "Test".to_string(),
                 author_email: "test@example.com".to_string(),
                 committed_date_utc: "2024-01-01T00:00:00Z".to_string(),
                 additions: 20,
                 deletions: 10,
+                category: "other".to_string(),
+                is_bot: false,
+                stats_missing: false,
+                date_invalid: false,
+                authored_date_utc: None,
             },
         ];
 
@@ -128,21 +158,33 @@ mod tests {
                 project_id: 1,
                 branch_name: "main".to_string(),
                 sha: "abc123".to_string(),
+                message: "test".to_string(),
                 author_name: "Test".to_string(),
                 author_email: "test@example.com".to_string(),
                 committed_date_utc: "2024-01-05T00:00:00Z".to_string(),
                 additions: 10,
                 deletions: 5,
+                category: "other".to_string(),
+                is_bot: false,
+                stats_missing: false,
+                date_invalid: false,
+                authored_date_utc: None,
             },
             Commit {
                 project_id: 1,
                 branch_name: "main".to_string(),
                 sha: "def456".to_string(),
+                message: "test".to_string(),
                 author_name: "Test".to_string(),
                 author_email: "test@example.com".to_string(),
                 committed_date_utc: "2024-02-05T00:00:00Z".to_string(),
                 additions: 20,
                 deletions: 10,
+                category: "other".to_string(),
+                is_bot: false,
+                stats_missing: false,
+                date_invalid: false,
+                authored_date_utc: None,
             },
         ];
 
@@ -172,21 +214,33 @@ mod tests {
                 project_id: 1,
                 branch_name: "main".to_string(),
                 sha: "abc123".to_string(),
+                message: "test".to_string(),
                 author_name: "Test".to_string(),
                 author_email: "test@example.com".to_string(),
                 committed_date_utc: "2024-01-01T00:00:00Z".to_string(),
                 additions: 10,
                 deletions: 5,
+                category: "other".to_string(),
+                is_bot: false,
+                stats_missing: false,
+                date_invalid: false,
+                authored_date_utc: None,
             },
             Commit {
                 project_id: 1,
                 branch_name: "main".to_string(),
                 sha: "def456".to_string(),
+                message: "test".to_string(),
                 author_name: "Test".to_string(),
                 author_email: "test@example.com".to_string(),
                 committed_date_utc: "2024-01-02T00:00:00Z".to_string(),
                 additions: 20,
                 deletions: 10,
+                category: "other".to_string(),
+                is_bot: false,
+                stats_missing: false,
+                date_invalid: false,
+                authored_date_utc: None,
             },
         ];
 
@@ -207,11 +261,17 @@ mod tests {
             project_id: 1,
             branch_name: "main".to_string(),
             sha: "abc123".to_string(),
+            message: "test".to_string(),
             author_name: "Test".to_string(),
             author_email: "test@example.com".to_string(),
             committed_date_utc: "2024-01-01T00:00:00Z".to_string(),
             additions: 10,
             deletions: 5,
+            category: "other".to_string(),
+            is_bot: false,
+            stats_missing: false,
+            date_invalid: false,
+            authored_date_utc: None,
         };
 
         // 同じコミットを2回保存
@@ -238,21 +298,33 @@ mod tests {
                 project_id: 1,
                 branch_name: "main".to_string(),
                 sha: "abc123".to_string(),
+                message: "test".to_string(),
                 author_name: "Test".to_string(),
                 author_email: "test@example.com".to_string(),
                 committed_date_utc: "2024-01-05T00:00:00Z".to_string(),
                 additions: 50,
                 deletions: 10,
+                category: "other".to_string(),
+                is_bot: false,
+                stats_missing: false,
+                date_invalid: false,
+                authored_date_utc: None,
             },
             Commit {
                 project_id: 1,
                 branch_name: "main".to_string(),
                 sha: "def456".to_string(),
+                message: "test".to_string(),
                 author_name: "Test".to_string(),
                 author_email: "test@example.com".to_string(),
                 committed_date_utc: "2024-02-05T00:00:00Z".to_string(),
                 additions: 100,
                 deletions: 20,
+                category: "other".to_string(),
+                is_bot: false,
+                stats_missing: false,
+                date_invalid: false,
+                authored_date_utc: None,
             },
         ];
 
@@ -0,0 +1,254 @@
+//! チームリポジトリ（SQLite ベース）
+//!
+//! チームと期間付きのメンバー所属を保存する。
+
+use crate::domain::{validate_team, Team, TeamMember};
+use crate::error::{AppError, AppResult};
+use crate::storage::sqlite;
+use chrono::NaiveDate;
+use rusqlite::{params, OptionalExtension};
+use std::collections::BTreeMap;
+
+/// 所属期間の保存形式
+const MEMBER_DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// チーム一覧を名前順に取得
+pub fn list_teams() -> AppResult<Vec<Team>> {
+    let conn = sqlite::DatabaseConnection::create_connection()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    list_teams_with_connection(&conn)
+}
+
+/// チームを作成
+pub fn create_team(name: &str, members: &[TeamMember]) -> AppResult<Team> {
+    let mut conn = sqlite::DatabaseConnection::create_connection()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    create_team_with_connection(&mut conn, name, members)
+}
+
+/// チーム名とメンバーを更新（メンバーは置き換え）
+pub fn update_team(team_id: i64, name: &str, members: &[TeamMember]) -> AppResult<Team> {
+    let mut conn = sqlite::DatabaseConnection::create_connection()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    update_team_with_connection(&mut conn, team_id, name, members)
+}
+
+/// チームを削除
+pub fn delete_team(team_id: i64) -> AppResult<()> {
+    let mut conn = sqlite::DatabaseConnection::create_connection()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    delete_team_with_connection(&mut conn, team_id)
+}
+
+pub(crate) fn list_teams_with_connection(conn: &rusqlite::Connection) -> AppResult<Vec<Team>> {
+    let mut stmt = conn
+        .prepare("SELECT team_id, name FROM teams ORDER BY name")
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    let mut teams = stmt
+        .query_map([], |row| {
+            Ok(Team {
+                id: row.get(0)?,
+                name: row.get(1)?,
+                members: Vec::new(),
+            })
+        })
+        .map_err(|e| AppError::Storage(e.to_string()))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    let mut members = load_members(conn)?;
+    for team in &mut teams {
+        team.members = members.remove(&team.id).unwrap_or_default();
+    }
+
+    Ok(teams)
+}
+
+pub(crate) fn create_team_with_connection(
+    conn: &mut rusqlite::Connection,
+    name: &str,
+    members: &[TeamMember],
+) -> AppResult<Team> {
+    let name = validate_team(name, members)?;
+    let now = chrono::Utc::now().to_rfc3339();
+
+    let tx = conn
+        .transaction()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    ensure_name_available(&tx, &name, None)?;
+    tx.execute(
+        "INSERT INTO teams (name, created_at_utc, updated_at_utc) VALUES (?1, ?2, ?2)",
+        params![name, now],
+    )
+    .map_err(|e| AppError::Storage(e.to_string()))?;
+    let team_id = tx.last_insert_rowid();
+    replace_members(&tx, team_id, members)?;
+
+    tx.commit().map_err(|e| AppError::Storage(e.to_string()))?;
+
+    Ok(Team {
+        id: team_id,
+        name,
+        members: members.to_vec(),
+    })
+}
+
+pub(crate) fn update_team_with_connection(
+    conn: &mut rusqlite::Connection,
+    team_id: i64,
+    name: &str,
+    members: &[TeamMember],
+) -> AppResult<Team> {
+    let name = validate_team(name, members)?;
+    let now = chrono::Utc::now().to_rfc3339();
+
+    let tx = conn
+        .transaction()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    ensure_name_available(&tx, &name, Some(team_id))?;
+    let updated = tx
+        .execute(
+            "UPDATE teams SET name = ?1, updated_at_utc = ?2 WHERE team_id = ?3",
+            params![name, now, team_id],
+        )
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+    if updated == 0 {
+        return Err(team_not_found(team_id));
+    }
+    replace_members(&tx, team_id, members)?;
+
+    tx.commit().map_err(|e| AppError::Storage(e.to_string()))?;
+
+    Ok(Team {
+        id: team_id,
+        name,
+        members: members.to_vec(),
+    })
+}
+
+pub(crate) fn delete_team_with_connection(
+    conn: &mut rusqlite::Connection,
+    team_id: i64,
+) -> AppResult<()> {
+    let tx = conn
+        .transaction()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    // foreign_keys が無効な接続でも残らないよう明示的に削除
+    tx.execute("DELETE FROM team_members WHERE team_id = ?1", [team_id])
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+    let deleted = tx
+        .execute("DELETE FROM teams WHERE team_id = ?1", [team_id])
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+    if deleted == 0 {
+        return Err(team_not_found(team_id));
+    }
+
+    tx.commit().map_err(|e| AppError::Storage(e.to_string()))?;
+
+    Ok(())
+}
+
+fn load_members(conn: &rusqlite::Connection) -> AppResult<BTreeMap<i64, Vec<TeamMember>>> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT team_id, user_key, valid_from, valid_until
+             FROM team_members ORDER BY team_id, position",
+        )
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, Option<String>>(2)?,
+                row.get::<_, Option<String>>(3)?,
+            ))
+        })
+        .map_err(|e| AppError::Storage(e.to_string()))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    let mut members: BTreeMap<i64, Vec<TeamMember>> = BTreeMap::new();
+    for (team_id, user_key, valid_from, valid_until) in rows {
+        members.entry(team_id).or_default().push(TeamMember {
+            user_key,
+            valid_from: parse_member_date(valid_from)?,
+            valid_until: parse_member_date(valid_until)?,
+        });
+    }
+
+    Ok(members)
+}
+
+fn replace_members(
+    tx: &rusqlite::Transaction<'_>,
+    team_id: i64,
+    members: &[TeamMember],
+) -> AppResult<()> {
+    tx.execute("DELETE FROM team_members WHERE team_id = ?1", [team_id])
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    for (position, member) in members.iter().enumerate() {
+        tx.execute(
+            "INSERT INTO team_members (team_id, position, user_key, valid_from, valid_until)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                team_id,
+                position as i64,
+                member.user_key,
+                member
+                    .valid_from
+                    .map(|d| d.format(MEMBER_DATE_FORMAT).to_string()),
+                member
+                    .valid_until
+                    .map(|d| d.format(MEMBER_DATE_FORMAT).to_string()),
+            ],
+        )
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+    }
+
+    Ok(())
+}
+
+fn ensure_name_available(
+    conn: &rusqlite::Connection,
+    name: &str,
+    exclude_team_id: Option<i64>,
+) -> AppResult<()> {
+    let existing: Option<i64> = conn
+        .query_row("SELECT team_id FROM teams WHERE name = ?1", [name], |row| {
+            row.get(0)
+        })
+        .optional()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    match existing {
+        Some(id) if Some(id) != exclude_team_id => Err(AppError::Validation(format!(
+            "チーム名「{}」は既に使われています",
+            name
+        ))),
+        _ => Ok(()),
+    }
+}
+
+fn parse_member_date(value: Option<String>) -> AppResult<Option<NaiveDate>> {
+    value
+        .map(|v| {
+            NaiveDate::parse_from_str(&v, MEMBER_DATE_FORMAT)
+                .map_err(|e| AppError::Storage(format!("Invalid team member date: {} ({})", v, e)))
+        })
+        .transpose()
+}
+
+fn team_not_found(team_id: i64) -> AppError {
+    AppError::Validation(format!("チームが見つかりません: id={}", team_id))
+}
@@ -0,0 +1,88 @@
+//! チームリポジトリのテスト
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::TeamMember;
+    use crate::error::AppError;
+    use crate::storage::sqlite::run_migrations;
+    use crate::storage::team_repository::{
+        create_team_with_connection, delete_team_with_connection, list_teams_with_connection,
+        update_team_with_connection,
+    };
+    use chrono::NaiveDate;
+    use rusqlite::Connection;
+
+    fn create_test_connection() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        run_migrations(&conn).unwrap();
+        conn
+    }
+
+    fn member(user_key: &str, from: Option<&str>) -> TeamMember {
+        TeamMember {
+            user_key: user_key.to_string(),
+            valid_from: from.map(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").unwrap()),
+            valid_until: None,
+        }
+    }
+
+    #[test]
+    fn test_team_crud() {
+        let mut conn = create_test_connection();
+
+        let members = vec![
+            member("alice@example.com", None),
+            member("bob@example.com", Some("2026-04-01")),
+        ];
+        let platform = create_team_with_connection(&mut conn, " Platform ", &members).unwrap();
+        create_team_with_connection(&mut conn, "Apps", &[]).unwrap();
+
+        let teams = list_teams_with_connection(&conn).unwrap();
+        assert_eq!(teams.len(), 2);
+        assert_eq!(teams[0].name, "Apps");
+        assert_eq!(teams[1], platform);
+        assert_eq!(teams[1].name, "Platform");
+        assert_eq!(teams[1].members, members);
+
+        let updated = update_team_with_connection(
+            &mut conn,
+            platform.id,
+            "Platform Core",
+            &[member("carol@example.com", None)],
+        )
+        .unwrap();
+        let teams = list_teams_with_connection(&conn).unwrap();
+        assert_eq!(teams[1], updated);
+        assert_eq!(teams[1].members.len(), 1);
+
+        delete_team_with_connection(&mut conn, platform.id).unwrap();
+        assert_eq!(list_teams_with_connection(&conn).unwrap().len(), 1);
+        let orphaned: i64 = conn
+            .query_row("SELECT COUNT(*) FROM team_members", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(orphaned, 0);
+    }
+
+    #[test]
+    fn test_duplicate_name_and_missing_team_are_rejected() {
+        let mut conn = create_test_connection();
+
+        let apps = create_team_with_connection(&mut conn, "Apps", &[]).unwrap();
+        let platform = create_team_with_connection(&mut conn, "Platform", &[]).unwrap();
+
+        assert!(matches!(
+            create_team_with_connection(&mut conn, "Apps", &[]),
+            Err(AppError::Validation(_))
+        ));
+        assert!(matches!(
+            update_team_with_connection(&mut conn, platform.id, "Apps", &[]),
+            Err(AppError::Validation(_))
+        ));
+        // 自分自身の名前はそのまま使える
+        assert!(update_team_with_connection(&mut conn, apps.id, "Apps", &[]).is_ok());
+        assert!(matches!(
+            delete_team_with_connection(&mut conn, 999),
+            Err(AppError::Validation(_))
+        ));
+    }
+}
@@ -0,0 +1,43 @@
+//! 稼働日カレンダーリポジトリ（SQLite ベース）
+//!
+//! 稼働日カレンダー（週末・祝日・休暇）を app_settings に保存する。
+
+use crate::domain::WorkCalendar;
+use crate::error::{AppError, AppResult};
+use crate::storage::settings_repository::{
+    get_setting_with_connection, set_setting_with_connection,
+};
+use crate::storage::sqlite;
+
+/// app_settings のキー
+const WORK_CALENDAR_KEY: &str = "work_calendar";
+
+/// 稼働日カレンダーを取得（未設定なら土日休みのみ）
+pub fn get_work_calendar() -> AppResult<WorkCalendar> {
+    let conn = sqlite::DatabaseConnection::create_connection()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    get_work_calendar_with_connection(&conn)
+}
+
+/// 稼働日カレンダーを検証して保存
+pub fn save_work_calendar(calendar: &WorkCalendar) -> AppResult<()> {
+    let conn = sqlite::DatabaseConnection::create_connection()
+        .map_err(|e| AppError::Storage(e.to_string()))?;
+
+    save_work_calendar_with_connection(&conn, calendar)
+}
+
+pub(crate) fn get_work_calendar_with_connection(
+    conn: &rusqlite::Connection,
+) -> AppResult<WorkCalendar> {
+    Ok(get_setting_with_connection(conn, WORK_CALENDAR_KEY)?.unwrap_or_default())
+}
+
+pub(crate) fn save_work_calendar_with_connection(
+    conn: &rusqlite::Connection,
+    calendar: &WorkCalendar,
+) -> AppResult<()> {
+    calendar.validate()?;
+    set_setting_with_connection(conn, WORK_CALENDAR_KEY, calendar)
+}